@@ -52,6 +52,14 @@ mod l1res {
         iter.first_err_or_else(|iter1| iter1.sum::<u64>())
     }
 
+    /// The code implemented by first_err with `fold_ok()`.
+    #[inline(never)]
+    fn first_err_fold_ok_approach(
+        iter: impl Iterator<Item = Result<u64, u64>>,
+    ) -> Result<u64, u64> {
+        iter.first_err_or_else(|iter1| iter1.fold_ok(0, |acc, n| acc + n))
+    }
+
     /// The code implemented by loop.
     #[inline(never)]
     fn loop_approach(iter: impl Iterator<Item = Result<u64, u64>>) -> Result<u64, u64> {
@@ -80,7 +88,7 @@ mod l1res {
 
         let group_name = match err_at {
             Some(err_at) => format!("l1res::err_at_{err_at:_<7}"),
-            None => format!("l1res::err_not_exists"),
+            None => "l1res::err_not_exists".to_string(),
         };
 
         // TEST: make sure answers are the same.
@@ -101,6 +109,13 @@ mod l1res {
                 ))),
                 "first_err approach test in: {group_name}",
             );
+            assert_eq!(
+                collect_ans,
+                black_box(first_err_fold_ok_approach(black_box(
+                    L1Iter::new(err_at).take(length)
+                ))),
+                "first_err fold_ok approach test in: {group_name}",
+            );
         }
 
         // benchmark conf
@@ -127,6 +142,14 @@ mod l1res {
                 })
             });
 
+            group.bench_function("__fold_ok", |b| {
+                b.iter(|| {
+                    black_box(first_err_fold_ok_approach(black_box(
+                        L1Iter::new(err_at).take(length),
+                    )))
+                })
+            });
+
             group.finish();
         }
     }
@@ -259,7 +282,7 @@ mod l2res {
             (None, Some(l2_err_at)) => {
                 format!("l2res::l1_err_not_exists_l2_err_at_{l2_err_at:_<7}")
             }
-            (None, None) => format!("l2res::l1_err_not_exists_l2_err_not_exists"),
+            (None, None) => "l2res::l1_err_not_exists_l2_err_not_exists".to_string(),
         };
 
         // TEST: make sure answers are the same.
@@ -358,6 +381,18 @@ mod l1opt {
         iter.first_none_or_else(|iter1| iter1.sum::<u64>())
     }
 
+    /// The code implemented by `first_none_or_fold()`.
+    #[inline(never)]
+    fn fold_approach(iter: impl Iterator<Item = Option<u64>>) -> Option<u64> {
+        iter.first_none_or_fold(0, |sum, n| sum + n)
+    }
+
+    /// The code implemented by `first_none_or_sum()`.
+    #[inline(never)]
+    fn sum_approach(iter: impl Iterator<Item = Option<u64>>) -> Option<u64> {
+        iter.first_none_or_sum()
+    }
+
     /// The code implemented by loop.
     #[inline(never)]
     fn loop_approach(iter: impl Iterator<Item = Option<u64>>) -> Option<u64> {
@@ -383,7 +418,7 @@ mod l1opt {
 
         let group_name = match none_at {
             Some(none_at) => format!("l1opt::none_at_{none_at:_<7}"),
-            None => format!("l1opt::none_not_exists"),
+            None => "l1opt::none_not_exists".to_string(),
         };
 
         // TEST: make sure answers are the same.
@@ -404,6 +439,16 @@ mod l1opt {
                 ))),
                 "first_err approach test in: {group_name}",
             );
+            assert_eq!(
+                collect_ans,
+                black_box(fold_approach(black_box(L1Iter::new(none_at).take(length)))),
+                "fold approach test in: {group_name}",
+            );
+            assert_eq!(
+                collect_ans,
+                black_box(sum_approach(black_box(L1Iter::new(none_at).take(length)))),
+                "sum approach test in: {group_name}",
+            );
         }
 
         // benchmark conf
@@ -430,6 +475,146 @@ mod l1opt {
                 })
             });
 
+            group.bench_function("_____fold", |b| {
+                b.iter(|| black_box(fold_approach(black_box(L1Iter::new(none_at).take(length)))))
+            });
+
+            group.bench_function("______sum", |b| {
+                b.iter(|| black_box(sum_approach(black_box(L1Iter::new(none_at).take(length)))))
+            });
+
+            group.finish();
+        }
+    }
+
+    /// The code implemented by `check_first_none()`.
+    #[inline(never)]
+    fn check_approach(iter: impl Iterator<Item = Option<u64>>) -> Option<()> {
+        iter.check_first_none()
+    }
+
+    /// The code implemented by `first_none_or(())`.
+    #[inline(never)]
+    fn first_none_or_unit_approach(iter: impl Iterator<Item = Option<u64>>) -> Option<()> {
+        iter.first_none_or(())
+    }
+
+    /// The part of group name which describes `none_at`.
+    fn none_at_name(none_at: Option<u64>) -> String {
+        match none_at {
+            Some(none_at) => format!("none_at_{none_at:_<7}"),
+            None => "none_not_exists".to_string(),
+        }
+    }
+
+    /// Set L1 `check_first_none()` benchmark group by given arguments.
+    pub fn bench_check_setup(c: &mut Criterion, none_at: Option<u64>) {
+        let length: usize = 100_000;
+
+        let group_name = format!("l1opt_check::{}", none_at_name(none_at));
+
+        // TEST: make sure answers are the same.
+        {
+            assert_eq!(
+                black_box(first_none_or_unit_approach(black_box(
+                    L1Iter::new(none_at).take(length)
+                ))),
+                black_box(check_approach(black_box(L1Iter::new(none_at).take(length)))),
+                "check approach test in: {group_name}",
+            );
+        }
+
+        // benchmark conf
+        {
+            let mut group = c.benchmark_group(group_name);
+
+            group.bench_function("first_err", |b| {
+                b.iter(|| {
+                    black_box(first_none_or_unit_approach(black_box(
+                        L1Iter::new(none_at).take(length),
+                    )))
+                })
+            });
+
+            group.bench_function("____check", |b| {
+                b.iter(|| black_box(check_approach(black_box(L1Iter::new(none_at).take(length)))))
+            });
+
+            group.finish();
+        }
+    }
+
+    /// The code implemented by `first_none_or_any()`, which matched at `match_at`.
+    #[inline(never)]
+    fn first_err_any_approach(
+        iter: impl Iterator<Item = Option<u64>>,
+        match_at: u64,
+    ) -> Option<bool> {
+        iter.first_none_or_any(|n| n == match_at)
+    }
+
+    /// The code implemented by loop, which matched at `match_at`.
+    #[inline(never)]
+    fn loop_any_approach(iter: impl Iterator<Item = Option<u64>>, match_at: u64) -> Option<bool> {
+        let mut found = false;
+        for opt in iter {
+            let n = opt?;
+            if !found && n == match_at {
+                found = true;
+            }
+        }
+
+        Some(found)
+    }
+
+    /// Set L1 `any()` benchmark group by given arguments.
+    pub fn bench_any_setup(c: &mut Criterion, none_at: Option<u64>, match_at: u64) {
+        let length: usize = 100_000;
+
+        let group_name = format!(
+            "l1opt_any::match_at_{match_at:_<7}_{}",
+            none_at_name(none_at)
+        );
+
+        // TEST: make sure answers are the same.
+        {
+            let loop_ans = black_box(loop_any_approach(
+                black_box(L1Iter::new(none_at).take(length)),
+                match_at,
+            ));
+
+            assert_eq!(
+                loop_ans,
+                black_box(first_err_any_approach(
+                    black_box(L1Iter::new(none_at).take(length)),
+                    match_at,
+                )),
+                "first_err approach test in: {group_name}",
+            );
+        }
+
+        // benchmark conf
+        {
+            let mut group = c.benchmark_group(group_name);
+
+            group.bench_function("_____loop", |b| {
+                b.iter(|| {
+                    black_box(loop_any_approach(
+                        black_box(L1Iter::new(none_at).take(length)),
+                        match_at,
+                    ))
+                })
+            });
+
+            group.bench_function("first_err", |b| {
+                b.iter(|| {
+                    black_box(first_err_any_approach(
+                        black_box(L1Iter::new(none_at).take(length)),
+                        match_at,
+                    ))
+                })
+            });
+
             group.finish();
         }
     }
@@ -562,7 +747,7 @@ mod l2opt {
             (None, Some(l2_none_at)) => {
                 format!("l2opt::l1_none_not_exists_l2_none_at_{l2_none_at:_<7}")
             }
-            (None, None) => format!("l2opt::l1_none_not_exists_l2_none_not_exists"),
+            (None, None) => "l2opt::l1_none_not_exists_l2_none_not_exists".to_string(),
         };
 
         // TEST: make sure answers are the same.
@@ -673,6 +858,15 @@ fn benchmarks(c: &mut Criterion) {
     l1opt::bench_setup(c, Some(99999));
     l1opt::bench_setup(c, None);
 
+    l1opt::bench_any_setup(c, Some(1000), 10);
+    l1opt::bench_any_setup(c, Some(99999), 10);
+    l1opt::bench_any_setup(c, None, 10);
+    l1opt::bench_any_setup(c, None, 99999);
+
+    l1opt::bench_check_setup(c, Some(1000));
+    l1opt::bench_check_setup(c, Some(99999));
+    l1opt::bench_check_setup(c, None);
+
     l2opt::bench_setup(c, Some(0), Some(1000));
     l2opt::bench_setup(c, Some(10), Some(1000));
     l2opt::bench_setup(c, Some(100), Some(1000));
@@ -171,14 +171,41 @@
 //! `benches` folder.
 
 #![no_std]
+#![cfg_attr(feature = "nightly-never", feature(never_type))]
+#![cfg_attr(feature = "nightly-try", feature(try_trait_v2, try_trait_v2_residual))]
 
-pub use option::FirstNoneIter;
-pub use result::FirstErrIter;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::{cmp::Ordering, ops::ControlFlow};
+
+pub use boolean::FirstFalseIter;
+pub use control_flow::FirstBreakIter;
+pub use decode::{
+    decode_utf8_or_else, try_decode_utf8_or_else, DecodeUtf8, OkBytes, Utf8DecodeError,
+};
+#[cfg(feature = "either")]
+pub use either::{FirstLeftIter, FirstRightIter};
+pub use fallible::{Fallible, FirstFailureOfIter};
+pub use option::{
+    AndThen, ArrayCollectGap, ChunkGap, FirstGapIter, FirstNoneChunks, FirstNoneCountedIter,
+    FirstNoneFlat, FirstNoneIter, FirstNoneOwned, FirstNonePeekable, Gap, IndexBuf, LastNoneIter,
+    NoneIf, NoneIntoErr, NonePositions, RecoverNone, RefOptions, SomeWhile, UntilNone,
+};
+#[cfg(feature = "nightly-try")]
+pub use residual::FirstResidualIter;
+pub use result::{
+    ArrayCollectError, ChunkError, ErrIntoNone, ErrsIter, FirstErrChunks, FirstErrFlat,
+    FirstErrIter, FirstErrOwned, FirstErrPeekable, FirstFailureIter, IntoOkIter, Never, ParseAll,
+    RecoverErr, RefResults, Severity, WorstErrIter,
+};
+pub use sources::{try_from_fn, try_successors, try_unfold, TryFromFn, TrySuccessors, TryUnfold};
 
 /// This trait provides some methods on any `Iterator<Item = Result<T, E>>`, which can take
 /// the first `Err` in iterators, and without allocation.
 ///
-/// `Iterator<Item = Option<T>>` version with the same logic are also supported.
+/// `Iterator<Item = Option<T>>`, `Iterator<Item = ControlFlow<B, C>>` and
+/// `Iterator<Item = bool>` versions with the same logic are also supported.
 ///
 ///
 ///
@@ -390,11 +417,57 @@ pub trait FirstErr: Iterator {
         self.first_err_or_else(|_| value)
     }
 
-    /// Returns the first `None` item in the current iterator, or an `Some` value produced
-    /// by the `f` closure.
+    /// Returns the first `Err` item in the current iterator with its index, or an `Ok` value
+    /// produced by the `f` closure with the count of all items.
     ///
-    /// The argument iterator of the `f` closure will producing the same values in `Some` sequence,
-    /// but will stop when encounter the first `None` item.
+    /// This method is the same as [`first_err_or_else()`](FirstErr::first_err_or_else), but
+    /// also reports the position in the current iterator, no matter how many items are consumed
+    /// by the `f` closure, or by the draining after the `f` closure returned. It is the final
+    /// value of [`FirstErrIter::source_position()`], or the index of the returned `Err` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .first_err_or_else_positioned(|iter| iter.next());
+    /// assert_eq!(result, Ok((Some(0), 3)));
+    ///
+    /// // Contains some `Err` values.
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Err(3)]
+    ///     .into_iter()
+    ///     .first_err_or_else_positioned(|iter| iter.next());
+    /// assert_eq!(result, Err((2, 2)));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_or_else_positioned<T, E, O, F>(self, f: F) -> Result<(O, usize), (E, usize)>
+    where
+        F: FnOnce(&mut FirstErrIter<Self, T, E>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        FirstErrIter::first_err_or_else_positioned(self, f)
+    }
+
+    /// Returns the first `Err` item in the current iterator, or an `Ok` value produced by the
+    /// `f` closure, which can look ahead one value.
+    ///
+    /// Same as [`first_err_or_else()`](FirstErr::first_err_or_else), but the argument iterator
+    /// of the `f` closure is a [`FirstErrPeekable`], which can [`peek()`](FirstErrPeekable::peek)
+    /// the next `Ok` value without consuming it.
+    ///
+    /// When the next item is the first `Err`, `peek()` just see the end of `Ok` values (returns
+    /// `None`), and the `Err` will be returned by this method.
+    ///
+    /// If a value was peeked but never consumed when the closure returns, it is treated as
+    /// consumed. It's already known as an `Ok` value, so it will just be dropped and not affect
+    /// the result. The remaining items will be checked after it as usual.
     ///
     ///
     ///
@@ -404,100 +477,209 @@ pub trait FirstErr: Iterator {
     /// use first_err::FirstErr;
     ///
     /// # fn main() {
-    /// // Everything is Some.
-    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    /// // Sum up values until meet a `0`.
+    /// let result = [Ok::<u8, u8>(1), Ok(2), Ok(0), Ok(3)]
     ///     .into_iter()
-    ///     .first_none_or_else(|iter| iter.sum::<u8>());
-    /// assert_eq!(option, Some(3));
+    ///     .first_err_or_else_peekable(|iter| {
+    ///         let mut sum = 0;
+    ///         while let Some(n) = iter.next_if(|&n| n != 0) {
+    ///             sum += n;
+    ///         }
+    ///         (sum, iter.peek().copied())
+    ///     });
+    /// assert_eq!(result, Ok((3, Some(0))));
     ///
-    /// // Contains some `None` values.
-    /// let option = [Some::<u8>(0), None, None]
+    /// // Peek at the first `Err` just see the end.
+    /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2)]
     ///     .into_iter()
-    ///     .first_none_or_else(|iter| iter.sum::<u8>());
-    /// assert_eq!(option, None);
+    ///     .first_err_or_else_peekable(|iter| {
+    ///         iter.next();
+    ///         iter.peek().copied()
+    ///     });
+    /// assert_eq!(result, Err(1));
     /// # }
     /// ```
     #[inline]
-    fn first_none_or_else<T, O, F>(self, f: F) -> Option<O>
+    fn first_err_or_else_peekable<T, E, O, F>(self, f: F) -> Result<O, E>
     where
-        F: FnOnce(&mut FirstNoneIter<Self, T>) -> O,
-        Self: Iterator<Item = Option<T>> + Sized,
+        F: FnOnce(&mut FirstErrPeekable<Self, T, E>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
     {
-        FirstNoneIter::first_none_or_else(self, f)
+        FirstErrPeekable::first_err_or_else_peekable(self, f)
     }
 
-    /// Returns the first `None` item in the current iterator, or an `Option` value produced
-    /// by the `f` closure.
+    /// Returns the first `Err` item from the back of the current iterator, or an `Ok` value
+    /// produced by the `f` closure.
     ///
-    /// The argument iterator of the `f` closure will producing the same values in `Some` sequence,
-    /// but will stop when encounter the first `None` item.
+    /// This is the mirror of [`first_err_or_else()`](FirstErr::first_err_or_else). The argument
+    /// iterator of the `f` closure will producing the `Ok` values in reverse order, and will stop
+    /// when encounter the first `Err` item from the back. In other words, the returned `Err` is
+    /// the last `Err` in forward order, and it can be found without scanning the front of the
+    /// iterator.
     ///
+    /// This method is a plain delegation to `iter.rev().first_err_or_else(f)`: the
+    /// [`Rev`](core::iter::Rev) adaptor turns every `next()` of the [`FirstErrIter`] into a
+    /// `next_back()` of the current iterator, so the same state machine drives it from the back.
     ///
+    /// The back of the iterator is driven by [`next_back()`](DoubleEndedIterator::next_back)
+    /// only, and the searching stops when it first returns `None`. If the back-end of the
+    /// iterator is not fused, the items it may produce after that are never examined.
     ///
-    /// # Examples
     ///
-    /// Basic concept:
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// use first_err::FirstErr;
     ///
     /// # fn main() {
-    /// // Everything is Some.
-    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    /// // Everything is Ok.
+    /// let result = [Ok::<u16, u16>(1), Ok(2), Ok(3)]
     ///     .into_iter()
-    ///     .first_none_or_try(|_| Some("ok"));
-    /// assert_eq!(option, Some("ok"));
+    ///     .first_err_or_else_rev(|iter| iter.fold(0, |acc, n| acc * 10 + n));
+    /// assert_eq!(result, Ok(321));
     ///
-    /// // When closure returns None.
-    /// let option: Option<&str> = [Some::<u8>(0), Some(1), Some(2)]
+    /// // Contains some `Err` values.
+    /// let result = [Ok::<u8, u8>(0), Err(1), Err(2), Ok(3)]
     ///     .into_iter()
-    ///     .first_none_or_try(|_| None);
-    /// assert_eq!(option, None);
+    ///     .first_err_or_else_rev(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(2));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_or_else_rev<T, E, O, F>(self, f: F) -> Result<O, E>
+    where
+        F: FnOnce(&mut FirstErrIter<core::iter::Rev<Self>, T, E>) -> O,
+        Self: DoubleEndedIterator<Item = Result<T, E>> + Sized,
+    {
+        self.rev().first_err_or_else(f)
+    }
+
+    /// Returns the first `Err` item in the current iterator, or an `Ok` value produced by the
+    /// `f` closure, which receives the `Ok` values chunk by chunk.
     ///
-    /// // When outer iterator contains None.
-    /// let option = [Some::<u8>(0), None, Some(2)]
+    /// The argument iterator of the `f` closure will producing `[T; N]` arrays, each of them are
+    /// built from `N` consecutive `Ok` values, and will stop when encounter the first `Err` item.
+    /// The chunks are buffered on stack, so no allocation is needed.
+    ///
+    /// The error is a [`ChunkError`], which is one of:
+    ///
+    /// - [`ChunkError::Err`]: the first `Err` item in the current iterator. If it interrupts a
+    ///   chunk, the `Ok` values already buffered in that chunk are dropped.
+    /// - [`ChunkError::Partial`]: no `Err` found, but the `Ok` values can't fill up the last
+    ///   chunk. The values in that partial chunk are dropped.
+    ///
+    /// `N` must not be zero, otherwise it is a compile error.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use first_err::{ChunkError, FirstErr};
+    ///
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(1), Ok(2), Ok(3), Ok(4)]
     ///     .into_iter()
-    ///     .first_none_or_try(|_| Some("ok"));
-    /// assert_eq!(option, None);
+    ///     .first_err_or_else_chunks(|iter| iter.map(|[a, b]| a * b).sum::<u8>());
+    /// assert_eq!(result, Ok(14));
     ///
-    /// // When both contains None.
-    /// let option: Option<&str> = [Some::<u8>(0), None, Some(2)]
+    /// // Contains some `Err` values.
+    /// let result = [Ok::<u8, u8>(1), Err(2), Ok(3), Err(4)]
     ///     .into_iter()
-    ///     .first_none_or_try(|_| None);
-    /// assert_eq!(option, None);
+    ///     .first_err_or_else_chunks(|iter| iter.map(|[a, b]| a * b).sum::<u8>());
+    /// assert_eq!(result, Err(ChunkError::Err(2)));
+    ///
+    /// // Contains a trailing partial chunk.
+    /// let result = [Ok::<u8, u8>(1), Ok(2), Ok(3)]
+    ///     .into_iter()
+    ///     .first_err_or_else_chunks(|iter| iter.map(|[a, b]| a * b).sum::<u8>());
+    /// assert_eq!(result, Err(ChunkError::Partial(1)));
+    ///
+    /// // Chunk size can also be given explicitly.
+    /// let result = [Ok::<u8, u8>(1), Ok(2), Ok(3)]
+    ///     .into_iter()
+    ///     .first_err_or_else_chunks::<3, _, _, _, _>(|iter| iter.count());
+    /// assert_eq!(result, Ok(1));
     /// # }
     /// ```
+    #[inline]
+    fn first_err_or_else_chunks<const N: usize, T, E, O, F>(self, f: F) -> Result<O, ChunkError<E>>
+    where
+        F: FnOnce(&mut FirstErrChunks<Self, T, E, N>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        FirstErrChunks::first_err_or_else_chunks(self, f)
+    }
+
+    /// Returns the first `Err` item in the current iterator or `other`, or an `Ok(bool)` which
+    /// indicates whether the `Ok` values of them are equal.
     ///
-    /// Use the `iter` argument of the `f` closure:
+    /// Both iterators are walked in lockstep, and at each position, the item of the current
+    /// iterator is checked before the item of `other`. The walking stops as soon as:
+    ///
+    /// - An `Err` item is found: returns it. So if both iterators have an `Err` at the same
+    ///   position, the one from the current iterator wins.
+    /// - A pair of `Ok` values are not equal, or only one of the iterators is exhausted: returns
+    ///   `Ok(false)`.
+    /// - Both iterators are exhausted: returns `Ok(true)`.
+    ///
+    /// In other words, an `Err` takes priority only if it is at or before the position where
+    /// the equality is decided. The items after that position are never examined.
+    ///
+    ///
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use first_err::FirstErr;
     /// #
     /// # fn main() {
-    /// let admin_id: u32 = 1;
-    /// let user_ids_in_conf = ["32", "5", "8", "19"];
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(0), Ok(1)]
+    ///     .into_iter()
+    ///     .first_err_or_eq([Ok(0), Ok(1)]);
+    /// assert_eq!(result, Ok(true));
     ///
-    /// let admin_index = user_ids_in_conf
+    /// // Mismatch at position 1, the `Err` at position 2 will not be examined.
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2)]
     ///     .into_iter()
-    ///     .map(|s| s.parse::<u32>().ok())
-    ///     .first_none_or_try(|user_ids_iter| {
-    ///         user_ids_iter
-    ///             .position(|user_id| user_id == admin_id)
-    ///     });
+    ///     .first_err_or_eq([Ok(0), Ok(42), Ok(2)]);
+    /// assert_eq!(result, Ok(false));
     ///
-    /// assert_eq!(admin_index, None);
+    /// // `Err` at position 1 is at the mismatch position.
+    /// let result = [Ok::<u8, u8>(0), Ok(1)]
+    ///     .into_iter()
+    ///     .first_err_or_eq([Ok(0), Err(42)]);
+    /// assert_eq!(result, Err(42));
     /// # }
     /// ```
     #[inline]
-    fn first_none_or_try<T, O, F>(self, f: F) -> Option<O>
+    fn first_err_or_eq<T, E, I>(self, other: I) -> Result<bool, E>
     where
-        F: FnOnce(&mut FirstNoneIter<Self, T>) -> Option<O>,
-        Self: Iterator<Item = Option<T>> + Sized,
+        I: IntoIterator<Item = Result<T, E>>,
+        T: PartialEq,
+        Self: Iterator<Item = Result<T, E>> + Sized,
     {
-        self.first_none_or_else(f).and_then(|opt| opt)
+        result::iter_compare(self, other.into_iter(), |a, b| {
+            if a == b {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        })
+        .map(|flow| flow == ControlFlow::Continue(Ordering::Equal))
     }
 
-    /// Returns the first `None` item in the current iterator, or an `Some(value)`.
+    /// Returns the first `Err` item in the current iterator or `other`, or an `Ok(Ordering)`
+    /// which [lexicographically](Ord#lexicographical-comparison) compares the `Ok` values of
+    /// them.
+    ///
+    /// Use the same positional rule as [`first_err_or_eq()`](FirstErr::first_err_or_eq): an `Err`
+    /// takes priority only if it is at or before the position where the ordering is decided, and
+    /// at the same position, the `Err` from the current iterator wins. The items after that
+    /// position are never examined.
     ///
     ///
     ///
@@ -505,330 +687,13987 @@ pub trait FirstErr: Iterator {
     ///
     /// ```rust
     /// # use first_err::FirstErr;
+    /// # use std::cmp::Ordering;
     /// #
     /// # fn main() {
-    /// // Everything is Some.
-    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(0), Ok(1)]
     ///     .into_iter()
-    ///     .first_none_or("foo");
-    /// assert_eq!(option, Some("foo"));
+    ///     .first_err_or_cmp([Ok(0), Ok(2)]);
+    /// assert_eq!(result, Ok(Ordering::Less));
     ///
-    /// // Contains some `None` values.
-    /// let option = [Some::<u8>(0), None, None]
+    /// // Ordering is decided at position 1, the `Err` at position 2 will not be examined.
+    /// let result = [Ok::<u8, u8>(0), Ok(3), Err(2)]
     ///     .into_iter()
-    ///     .first_none_or("foo");
-    /// assert_eq!(option, None);
+    ///     .first_err_or_cmp([Ok(0), Ok(2), Ok(2)]);
+    /// assert_eq!(result, Ok(Ordering::Greater));
+    ///
+    /// // `Err` appears before the ordering is decided.
+    /// let result = [Ok::<u8, u8>(0), Ok(1)]
+    ///     .into_iter()
+    ///     .first_err_or_cmp([Err(42), Ok(1)]);
+    /// assert_eq!(result, Err(42));
     /// # }
     /// ```
     #[inline]
-    fn first_none_or<T, O>(self, value: O) -> Option<O>
+    fn first_err_or_cmp<T, E, I>(self, other: I) -> Result<Ordering, E>
     where
-        Self: Iterator<Item = Option<T>> + Sized,
+        I: IntoIterator<Item = Result<T, E>>,
+        T: Ord,
+        Self: Iterator<Item = Result<T, E>> + Sized,
     {
-        self.first_none_or_else(|_| value)
+        result::iter_compare(self, other.into_iter(), |a, b| match a.cmp(&b) {
+            Ordering::Equal => ControlFlow::Continue(()),
+            non_eq => ControlFlow::Break(non_eq),
+        })
+        .map(|flow| match flow {
+            ControlFlow::Continue(ord) => ord,
+            ControlFlow::Break(ord) => ord,
+        })
     }
-}
-
-impl<I> FirstErr for I where I: Iterator {}
 
-mod result {
-    use core::iter::FusedIterator;
-
-    /// An `Iterator` can take first `Err` from another iterator.
+    /// Returns the first `Err` item in the current iterator, or an `Ok(bool)` which indicates
+    /// whether the `Ok` values are sorted.
     ///
-    /// See [`FirstErr::first_err_or_else()`](crate::FirstErr::first_err_or_else) for more details.
-    #[derive(Debug)]
-    pub struct FirstErrIter<I, T, E>
+    /// The items are checked one by one, and the checking stops as soon as:
+    ///
+    /// - An `Err` item is found: returns it.
+    /// - An `Ok` value is less than the previous one: returns `Ok(false)`. The items after it
+    ///   (include `Err`s) are never examined.
+    ///
+    /// If neither happens until the iterator is exhausted, returns `Ok(true)`.
+    ///
+    /// In other words, an `Err` takes priority only if it is at or before the position where
+    /// the sortedness is decided. Because `Ok(true)` can only be decided at the end, any `Err`
+    /// wins in that case.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .first_err_or_is_sorted();
+    /// assert_eq!(result, Ok(true));
+    ///
+    /// // Unsorted pair at position 1 and 2, the `Err` after them will not be examined.
+    /// let result = [Ok::<u8, u8>(0), Ok(2), Ok(1), Err(3)]
+    ///     .into_iter()
+    ///     .first_err_or_is_sorted();
+    /// assert_eq!(result, Ok(false));
+    ///
+    /// // `Err` appears before the unsorted pair.
+    /// let result = [Ok::<u8, u8>(0), Err(3), Ok(2), Ok(1)]
+    ///     .into_iter()
+    ///     .first_err_or_is_sorted();
+    /// assert_eq!(result, Err(3));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_or_is_sorted<T, E>(self) -> Result<bool, E>
     where
-        I: Iterator<Item = Result<T, E>>,
+        T: PartialOrd,
+        Self: Iterator<Item = Result<T, E>> + Sized,
     {
-        state: State<I, T, E>,
+        self.first_err_or_is_sorted_by(|a, b| a <= b)
     }
 
-    impl<I, T, E> FirstErrIter<I, T, E>
+    /// Returns the first `Err` item in the current iterator, or an `Ok(bool)` which indicates
+    /// whether the `Ok` values are sorted by the given `compare` function.
+    ///
+    /// `compare` takes two consecutive `Ok` values and returns `true` if they are in order, just
+    /// like the [`Iterator::is_sorted_by()`]. The positional rule is the same as
+    /// [`first_err_or_is_sorted()`](FirstErr::first_err_or_is_sorted), and `compare` will not be
+    /// called after the first out of order pair is found.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let result = [Ok::<u8, u8>(2), Ok(1), Ok(0)]
+    ///     .into_iter()
+    ///     .first_err_or_is_sorted_by(|a, b| a >= b);
+    /// assert_eq!(result, Ok(true));
+    ///
+    /// let result = [Ok::<u8, u8>(2), Ok(1), Err(0)]
+    ///     .into_iter()
+    ///     .first_err_or_is_sorted_by(|a, b| a >= b);
+    /// assert_eq!(result, Err(0));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_or_is_sorted_by<T, E, F>(mut self, mut compare: F) -> Result<bool, E>
     where
-        I: Iterator<Item = Result<T, E>>,
+        F: FnMut(&T, &T) -> bool,
+        Self: Iterator<Item = Result<T, E>> + Sized,
     {
-        #[inline]
-        pub(super) fn first_err_or_else<O, F>(inner: I, f: F) -> Result<O, E>
-        where
-            F: FnOnce(&mut Self) -> O,
-        {
-            let mut me = Self {
-                state: State::Active(inner),
-            };
-
-            let output = f(&mut me);
+        let mut last = match self.next() {
+            Some(res) => res?,
+            None => return Ok(true),
+        };
 
-            // Take first err, if not found and not exhausted yet, find it.
-            // If just not found finally, return output.
-            match me.state {
-                State::Active(inner) => {
-                    for res in inner {
-                        let _ = res?;
-                    }
-                    Ok(output)
-                }
-                State::Exhausted => Ok(output),
-                State::FoundFirstErr(e) => Err(e),
+        for res in self {
+            let curr = res?;
+            if !compare(&last, &curr) {
+                return Ok(false);
             }
+            last = curr;
         }
+
+        Ok(true)
     }
 
-    impl<I, T, E> Iterator for FirstErrIter<I, T, E>
+    /// Returns the first unrecoverable `Err` item in the current iterator, or an `Ok` value
+    /// produced by the `f` closure.
+    ///
+    /// Every `Err` item is first offered to the `recover` function. If it returns `Ok(value)`,
+    /// the `value` will be treated as an `Ok` item, and if it returns `Err(e)`, the `e` will
+    /// become the first `Err` and stop the iteration as usual.
+    ///
+    /// The `recover` function is also applied on the `Err` items which are found after the `f`
+    /// closure returned, so the result is the same no matter how many items the `f` closure
+    /// consumed.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Missing,
+    ///     Broken,
+    /// }
+    ///
+    /// // `Missing` can be recovered by a default value.
+    /// let recover = |e| match e {
+    ///     Error::Missing => Ok(0),
+    ///     e => Err(e),
+    /// };
+    ///
+    /// let result = [Ok::<u8, Error>(1), Err(Error::Missing), Ok(2)]
+    ///     .into_iter()
+    ///     .first_err_or_else_recover(recover, |iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// let result = [Ok::<u8, Error>(1), Err(Error::Missing), Err(Error::Broken)]
+    ///     .into_iter()
+    ///     .first_err_or_else_recover(recover, |iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(Error::Broken));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_or_else_recover<T, E, O, R, F>(self, recover: R, f: F) -> Result<O, E>
     where
-        I: Iterator<Item = Result<T, E>>,
+        R: FnMut(E) -> Result<T, E>,
+        F: FnOnce(&mut FirstErrIter<RecoverErr<Self, R>, T, E>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
     {
-        type Item = T;
+        RecoverErr::new(self, recover).first_err_or_else(f)
+    }
 
-        #[inline]
-        fn next(&mut self) -> Option<Self::Item> {
-            match &mut self.state {
-                State::Active(inner) => match inner.next() {
+    /// Returns the first and the last `Err` items in the current iterator, or an `Ok` value
+    /// produced by the `f` closure.
+    ///
+    /// The argument iterator of the `f` closure is the same as
+    /// [`first_err_or_else()`](FirstErr::first_err_or_else), which will stop when encounter the
+    /// first `Err` item.
+    ///
+    /// The error is a tuple of the first `Err` and `Some(last_err)` if there is more than one
+    /// `Err`, or `None` if the first `Err` is the only one.
+    ///
+    /// Unlike other methods, if an `Err` is found, this method will keep scanning until the
+    /// current iterator is exhausted to find the last `Err`, rather than stop immediately.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // Contains only one `Err` value.
+    /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+    ///     .into_iter()
+    ///     .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err((1, None)));
+    ///
+    /// // Contains many `Err` values.
+    /// let result = [Ok::<u8, u8>(0), Err(1), Err(2), Ok(3), Err(4), Ok(5)]
+    ///     .into_iter()
+    ///     .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err((1, Some(4))));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_and_last_err_or_else<T, E, O, F>(mut self, f: F) -> Result<O, (E, Option<E>)>
+    where
+        F: FnOnce(&mut FirstErrIter<&mut Self, T, E>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        // The current iterator is not exhausted if the first `Err` found, keep scanning.
+        (&mut self)
+            .first_err_or_else(f)
+            .map_err(|first_err| (first_err, self.filter_map(Result::err).last()))
+    }
+
+    /// Returns the first `Err` item in the current iterator, or an `Ok` value produced by the
+    /// `f` closure, which receives the flattened `Ok` values.
+    ///
+    /// The `Ok` values of the current iterator are iterables, and the argument iterator of the
+    /// `f` closure will producing each element of them in order, and will stop when encounter
+    /// the first `Err` item.
+    ///
+    /// After the `f` closure returned, only the remaining items of the current iterator will be
+    /// checked, the remaining elements inside the `Ok` values are never iterated.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let result = [Ok::<&[u8], u8>(&[0, 1]), Ok(&[]), Ok(&[2])]
+    ///     .into_iter()
+    ///     .first_err_or_else_flat(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // Contains some `Err` values.
+    /// let result = [Ok::<&[u8], u8>(&[0, 1]), Err(1), Ok(&[2])]
+    ///     .into_iter()
+    ///     .first_err_or_else_flat(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_or_else_flat<T, E, II, O, F>(self, f: F) -> Result<O, E>
+    where
+        II: IntoIterator<Item = T>,
+        F: FnOnce(&mut FirstErrFlat<Self, II, E>) -> O,
+        Self: Iterator<Item = Result<II, E>> + Sized,
+    {
+        FirstErrFlat::first_err_or_else_flat(self, f)
+    }
+
+    /// Pushes every `Err` item in the current iterator into `sink`, and returns the count of them,
+    /// or an `Ok` value produced by the `f` closure if no `Err` found.
+    ///
+    /// Unlike [`first_err_or_else()`](FirstErr::first_err_or_else), the argument iterator of the
+    /// `f` closure will not stop when encounter an `Err` item. It just skips the `Err` items (and
+    /// pushes them into `sink`), and producing all the `Ok` values in the current iterator.
+    ///
+    /// The `Err` items are pushed into `sink` in order, no matter they are found when the `f`
+    /// closure is consuming its iterator, or after the `f` closure returned. So this method always
+    /// scans the whole current iterator.
+    ///
+    /// No allocation is required by this method itself, `sink` can be any container which
+    /// implemented [`Extend`].
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let mut errs = vec![];
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .errs_or_else(&mut errs, |iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    /// assert_eq!(errs, vec![]);
+    ///
+    /// // Contains some `Err` values.
+    /// let mut errs = vec![];
+    /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+    ///     .into_iter()
+    ///     .errs_or_else(&mut errs, |iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(2));
+    /// assert_eq!(errs, vec![1, 3]);
+    /// # }
+    /// ```
+    #[inline]
+    fn errs_or_else<'a, T, E, X, O, F>(self, sink: &'a mut X, f: F) -> Result<O, usize>
+    where
+        X: Extend<E>,
+        F: FnOnce(&mut ErrsIter<'a, Self, X>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        ErrsIter::errs_or_else(self, sink, f)
+    }
+
+    /// Returns the first `Err` item reference in the current iterator, or an `Ok` value produced
+    /// by the `f` closure.
+    ///
+    /// This method is the same as [`first_err_or_else()`](FirstErr::first_err_or_else), but works
+    /// on an iterator of `&Result<T, E>`, which commonly produced by `slice.iter()`. The argument
+    /// iterator of the `f` closure producing `&T` values, and the returned `&E` is borrowed from
+    /// the source data (not the iterator), so it can outlive this call.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use std::io;
+    /// #
+    /// # fn main() {
+    /// let results: [Result<String, io::Error>; 3] = [
+    ///     Ok("foo".to_string()),
+    ///     Err(io::Error::other("bar")),
+    ///     Err(io::Error::other("baz")),
+    /// ];
+    ///
+    /// let ans: Result<usize, &io::Error> = results
+    ///     .iter()
+    ///     .first_err_ref_or_else(|iter| iter.map(|s| s.len()).sum());
+    ///
+    /// assert_eq!(ans.unwrap_err().to_string(), "bar");
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_ref_or_else<'a, T, E, O, F>(self, f: F) -> Result<O, &'a E>
+    where
+        F: FnOnce(&mut FirstErrIter<RefResults<Self>, &'a T, &'a E>) -> O,
+        Self: Iterator<Item = &'a Result<T, E>> + Sized,
+        T: 'a,
+        E: 'a,
+    {
+        RefResults::new(self).first_err_or_else(f)
+    }
+
+    /// Returns a copy of the first `Err` item in the current iterator, or an `Ok` value produced
+    /// by the `f` closure.
+    ///
+    /// This method is the same as [`first_err_ref_or_else()`](FirstErr::first_err_ref_or_else),
+    /// but the argument iterator of the `f` closure producing copied `T` values, and the returned
+    /// error is copied too.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let results = [Ok::<u8, u8>(0), Err(1), Err(2)];
+    ///
+    /// let ans = results
+    ///     .iter()
+    ///     .first_err_copied_or_else(|iter| iter.sum::<u8>());
+    ///
+    /// assert_eq!(ans, Err(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_copied_or_else<'a, T, E, O, F>(self, f: F) -> Result<O, E>
+    where
+        F: FnOnce(&mut FirstErrOwned<'a, Self, T, E>) -> O,
+        Self: Iterator<Item = &'a Result<T, E>> + Sized,
+        T: 'a + Copy,
+        E: 'a + Copy,
+    {
+        FirstErrOwned::first_err_or_else_owned(self, f).map_err(|e| *e)
+    }
+
+    /// Returns a clone of the first `Err` item in the current iterator, or an `Ok` value produced
+    /// by the `f` closure.
+    ///
+    /// This method is the same as [`first_err_ref_or_else()`](FirstErr::first_err_ref_or_else),
+    /// but the argument iterator of the `f` closure producing cloned `T` values, and the returned
+    /// error is cloned too.
+    ///
+    /// Only the values actually consumed by the `f` closure and the returned error are cloned.
+    /// Items skipped during the draining are never cloned.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let results = [
+    ///     Ok::<String, String>("foo".to_string()),
+    ///     Err("bar".to_string()),
+    ///     Err("baz".to_string()),
+    /// ];
+    ///
+    /// let ans = results
+    ///     .iter()
+    ///     .first_err_cloned_or_else(|iter| iter.collect::<Vec<String>>());
+    ///
+    /// assert_eq!(ans, Err("bar".to_string()));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_err_cloned_or_else<'a, T, E, O, F>(self, f: F) -> Result<O, E>
+    where
+        F: FnOnce(&mut FirstErrOwned<'a, Self, T, E>) -> O,
+        Self: Iterator<Item = &'a Result<T, E>> + Sized,
+        T: 'a + Clone,
+        E: 'a + Clone,
+    {
+        FirstErrOwned::first_err_or_else_owned(self, f).map_err(E::clone)
+    }
+
+    /// Unwraps every item of an iterator whose `Err` type can never exist, such as
+    /// `Result<T, Infallible>`.
+    ///
+    /// The `Err` branch is statically unreachable, so no runtime check is needed to extract the
+    /// `Ok` values. This is useful after some steps have already converted errors away.
+    ///
+    /// With the `nightly-never` feature enabled, `Result<T, !>` is also supported.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use core::convert::Infallible;
+    /// #
+    /// # fn main() {
+    /// let sum = [Ok::<u8, Infallible>(0), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .into_ok_iter()
+    ///     .sum::<u8>();
+    ///
+    /// assert_eq!(sum, 3);
+    /// # }
+    /// ```
+    #[inline]
+    fn into_ok_iter<T, N>(self) -> IntoOkIter<Self>
+    where
+        N: Never,
+        Self: Iterator<Item = Result<T, N>> + Sized,
+    {
+        IntoOkIter::new(self)
+    }
+
+    /// Collects exactly `N` `Ok` values into an array, or returns an error if the first `N` items
+    /// contain an `Err`, or the iterator is too short.
+    ///
+    /// This method only consumes the first `N` items (or less, if failed early). The remaining
+    /// items are untouched, use [`by_ref()`](Iterator::by_ref) to keep using the current iterator
+    /// after this call.
+    ///
+    /// No allocation is required. Values already collected are dropped when this method failed.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::{ArrayCollectError, FirstErr};
+    /// #
+    /// # fn main() {
+    /// // Collect the first two values.
+    /// let mut iter = [Ok::<u8, u8>(0), Ok(1), Ok(2)].into_iter();
+    /// let result = iter.by_ref().try_collect_array::<2, _, _>();
+    /// assert_eq!(result, Ok([0, 1]));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    ///
+    /// // Contains an `Err` value.
+    /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+    ///     .into_iter()
+    ///     .try_collect_array::<3, _, _>();
+    /// assert_eq!(result, Err(ArrayCollectError::Err { index: 1, error: 1 }));
+    ///
+    /// // Too short.
+    /// let result = [Ok::<u8, u8>(0), Ok(1)]
+    ///     .into_iter()
+    ///     .try_collect_array::<3, _, _>();
+    /// assert_eq!(result, Err(ArrayCollectError::TooShort { got: 2 }));
+    /// # }
+    /// ```
+    #[inline]
+    fn try_collect_array<const N: usize, T, E>(mut self) -> Result<[T; N], ArrayCollectError<E>>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        let mut err = None;
+
+        crate::array::try_from_fn(|index| match self.next()? {
+            Ok(value) => Some(value),
+            Err(error) => {
+                err = Some(ArrayCollectError::Err { index, error });
+                None
+            }
+        })
+        .map_err(|got| err.unwrap_or(ArrayCollectError::TooShort { got }))
+    }
+
+    /// Parses every `&str` item in the current iterator, and returns the index and the error of
+    /// the first failing one, or an `Ok` value produced by the `f` closure.
+    ///
+    /// This method is a shortcut of:
+    ///
+    /// ```txt
+    /// iter.enumerate()
+    ///     .map(|(i, s)| s.parse::<T>().map_err(|e| (i, e)))
+    ///     .first_err_or_else(f)
+    /// ```
+    ///
+    /// The argument iterator of the `f` closure producing the parsed values.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let line = "42, 7, 13";
+    ///
+    /// // Everything is Ok.
+    /// let result = line
+    ///     .split(',')
+    ///     .map(str::trim)
+    ///     .parse_all_or_first_err::<u32, _, _>(|iter| iter.sum::<u32>());
+    /// assert_eq!(result, Ok(62));
+    ///
+    /// // The second column is invalid.
+    /// let line = "42, seven, 13, x";
+    ///
+    /// let result = line
+    ///     .split(',')
+    ///     .map(str::trim)
+    ///     .parse_all_or_first_err::<u32, _, _>(|iter| iter.sum::<u32>());
+    /// let (index, err) = result.unwrap_err();
+    /// assert_eq!(index, 1);
+    /// assert_eq!(err.to_string(), "invalid digit found in string");
+    /// # }
+    /// ```
+    #[inline]
+    fn parse_all_or_first_err<'a, T, O, F>(self, f: F) -> Result<O, (usize, T::Err)>
+    where
+        T: core::str::FromStr,
+        F: FnOnce(&mut FirstErrIter<ParseAll<Self, T>, T, (usize, T::Err)>) -> O,
+        Self: Iterator<Item = &'a str> + Sized,
+    {
+        ParseAll::new(self).first_err_or_else(f)
+    }
+
+    /// Returns the first `Err` item in the current iterator as a boxed error, or an `Ok` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as [`first_err_or_else()`](FirstErr::first_err_or_else), but
+    /// converts the returned error into `Box<dyn Error + Send + Sync>`, which is convenient when
+    /// different layers produce different error types. Only the single returned error will be
+    /// boxed.
+    ///
+    /// This method requires the `alloc` feature.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() {
+    /// fn sum(s: &str) -> Result<u32, Box<dyn Error + Send + Sync>> {
+    ///     let layer1 = s.split(',').map(|t| t.parse::<u32>());
+    ///
+    ///     layer1.first_err_boxed_or_else(|iter| {
+    ///         iter.map(|n| n.checked_mul(2).ok_or("overflow"))
+    ///             .first_err_boxed_or_else(|iter| iter.sum::<u32>())
+    ///     })?
+    /// }
+    ///
+    /// assert_eq!(sum("1,2,3").unwrap(), 12);
+    /// assert!(sum("1,x,3").is_err());
+    /// assert_eq!(sum("1,4000000000").unwrap_err().to_string(), "overflow");
+    /// # }
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn first_err_boxed_or_else<T, E, O, F>(
+        self,
+        f: F,
+    ) -> Result<O, alloc::boxed::Box<dyn core::error::Error + Send + Sync>>
+    where
+        E: Into<alloc::boxed::Box<dyn core::error::Error + Send + Sync>>,
+        F: FnOnce(&mut FirstErrIter<Self, T, E>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        self.first_err_or_else(f).map_err(Into::into)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced
+    /// by the `f` closure.
+    ///
+    /// The argument iterator of the `f` closure will producing the same values in `Some` sequence,
+    /// but will stop when encounter the first `None` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use first_err::FirstErr;
+    ///
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(3));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, None]
+    ///     .into_iter()
+    ///     .first_none_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNoneIter::first_none_or_else(self, f)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Option` value produced
+    /// by the `f` closure.
+    ///
+    /// The argument iterator of the `f` closure will producing the same values in `Some` sequence,
+    /// but will stop when encounter the first `None` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Basic concept:
+    ///
+    /// ```rust
+    /// use first_err::FirstErr;
+    ///
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_try(|_| Some("ok"));
+    /// assert_eq!(option, Some("ok"));
+    ///
+    /// // When closure returns None.
+    /// let option: Option<&str> = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_try(|_| None);
+    /// assert_eq!(option, None);
+    ///
+    /// // When outer iterator contains None.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_try(|_| Some("ok"));
+    /// assert_eq!(option, None);
+    ///
+    /// // When both contains None.
+    /// let option: Option<&str> = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_try(|_| None);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    ///
+    /// Use the `iter` argument of the `f` closure:
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let admin_id: u32 = 1;
+    /// let user_ids_in_conf = ["32", "5", "8", "19"];
+    ///
+    /// let admin_index = user_ids_in_conf
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<u32>().ok())
+    ///     .first_none_or_try(|user_ids_iter| {
+    ///         user_ids_iter
+    ///             .position(|user_id| user_id == admin_id)
+    ///     });
+    ///
+    /// assert_eq!(admin_index, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_try<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<Self, T>) -> Option<O>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(f).and_then(|opt| opt)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some(value)`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or("foo");
+    /// assert_eq!(option, Some("foo"));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, None]
+    ///     .into_iter()
+    ///     .first_none_or("foo");
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or<T, O>(self, value: O) -> Option<O>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|_| value)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some(O::default())`.
+    ///
+    /// The default value is constructed lazily, only when no `None` found.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option: Option<String> = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_default();
+    /// assert_eq!(option, Some(String::new()));
+    ///
+    /// // Contains some `None` values.
+    /// let option: Option<String> = [Some::<u8>(0), None, None]
+    ///     .into_iter()
+    ///     .first_none_or_default();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_default<T, O>(self) -> Option<O>
+    where
+        O: Default,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or(()).map(|()| O::default())
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but the
+    /// argument iterator of the `f` closure producing `(index, value)` pairs, where `index` is the
+    /// position of this value in the current iterator.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let option = [10u8.checked_mul(2), 20u8.checked_mul(3), 30u8.checked_mul(4)]
+    ///     .into_iter()
+    ///     .first_none_or_else_indexed(|iter| {
+    ///         iter.max_by_key(|&(_, n)| n).map(|(i, _)| i)
+    ///     });
+    ///
+    /// assert_eq!(option, Some(Some(2)));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_indexed<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut core::iter::Enumerate<&mut FirstNoneIter<Self, T>>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| f(&mut iter.enumerate()))
+    }
+
+    /// Returns the position of the first `None` item in the current iterator, or an `Ok` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but
+    /// reports where the first `None` occurred, no matter it is found when the `f` closure is
+    /// consuming its iterator, or when draining the rest items after the `f` closure returned.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let result = [10u8.checked_mul(2), 20u8.checked_mul(3)]
+    ///     .into_iter()
+    ///     .first_none_position_or_else(|iter| iter.max());
+    /// assert_eq!(result, Ok(Some(60)));
+    ///
+    /// // The third multiplication overflowed.
+    /// let result = [10u8.checked_mul(2), 20u8.checked_mul(3), 30u8.checked_mul(40)]
+    ///     .into_iter()
+    ///     .first_none_position_or_else(|iter| iter.max());
+    /// assert_eq!(result, Err(2));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_position_or_else<T, O, F>(self, f: F) -> Result<O, usize>
+    where
+        F: FnOnce(&mut FirstErrIter<NonePositions<Self>, T, usize>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        NonePositions::new(self).first_err_or_else(f)
+    }
+
+    /// Returns the count of `None` items in the current iterator, or an `Ok` value produced by
+    /// the `f` closure if no `None` found.
+    ///
+    /// Unlike [`first_none_or_else()`](FirstErr::first_none_or_else), the argument iterator of
+    /// the `f` closure will not stop when encounter a `None` item. It just skips all the `None`
+    /// items (the gaps), and producing every `Some` value in the current iterator. So this method
+    /// always scans the whole current iterator.
+    ///
+    /// Any `None` still voids the output of the `f` closure, but the closure can still see the
+    /// values after the gaps.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut total = 0;
+    ///
+    /// let result = [Some::<u8>(1), None, Some(2), None, Some(3)]
+    ///     .into_iter()
+    ///     .last_none_or_else(|iter| total = iter.sum::<u8>());
+    ///
+    /// assert_eq!(result, Err(2));
+    /// assert_eq!(total, 6);
+    /// # }
+    /// ```
+    #[inline]
+    fn last_none_or_else<T, O, F>(self, f: F) -> Result<O, usize>
+    where
+        F: FnOnce(&mut LastNoneIter<Self>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        LastNoneIter::last_none_or_else(self, f)
+    }
+
+    /// Folds every `Some` value in the current iterator, or returns `None` if any `None` item
+    /// found.
+    ///
+    /// This method is a shortcut of `first_none_or_else(|iter| iter.fold(init, f))`, but uses
+    /// internal iteration over the current iterator, which may be faster. The iteration stops
+    /// at the first `None` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_fold(0, |acc, n| acc * 10 + n);
+    /// assert_eq!(option, Some(123));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_fold(0, |acc, n| acc * 10 + n);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_fold<T, B, F>(mut self, init: B, mut f: F) -> Option<B>
+    where
+        F: FnMut(B, T) -> B,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.try_fold(init, |acc, opt| opt.map(|t| f(acc, t)))
+    }
+
+    /// Folds every `Some` value in the current iterator by a fallible function, or returns `None`
+    /// if any `None` item found, or the function returned `None`.
+    ///
+    /// This method is the same as `first_none_or_try(|iter| iter.try_fold(init, f))`.
+    ///
+    /// A `None` from the current iterator and a `None` from the `f` function both lead to a
+    /// `None` result. Whichever comes first stops the folding:
+    ///
+    /// - If a `None` item found in the current iterator, the iteration stops immediately.
+    /// - If the `f` function returned `None`, the rest of the current iterator will be drained
+    ///   until the first `None` item found, as [`first_none_or_else()`](FirstErr::first_none_or_else)
+    ///   does after its closure returned.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [100u8.checked_add(1), 50u8.checked_add(2)]
+    ///     .into_iter()
+    ///     .first_none_or_try_fold(0u8, |acc, n| acc.checked_add(n));
+    /// assert_eq!(option, Some(153));
+    ///
+    /// // An input overflowed.
+    /// let option = [200u8.checked_add(100), 50u8.checked_add(2)]
+    ///     .into_iter()
+    ///     .first_none_or_try_fold(0u8, |acc, n| acc.checked_add(n));
+    /// assert_eq!(option, None);
+    ///
+    /// // The accumulation overflowed.
+    /// let option = [200u8.checked_add(1), 50u8.checked_add(50)]
+    ///     .into_iter()
+    ///     .first_none_or_try_fold(0u8, |acc, n| acc.checked_add(n));
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_try_fold<T, B, F>(self, init: B, f: F) -> Option<B>
+    where
+        F: FnMut(B, T) -> Option<B>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.try_fold(init, f))
+    }
+
+    /// Calls the `f` function on every `Some` value in the current iterator, or returns `None`
+    /// if any `None` item found.
+    ///
+    /// This method is a shortcut of `first_none_or_else(|iter| iter.for_each(f))`, but uses
+    /// internal iteration over the current iterator. The iteration stops at the first `None`
+    /// item, and the `f` function will never be called after that.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut rendered = 0;
+    ///
+    /// let option = [Some("foo"), Some("bar"), None, Some("baz")]
+    ///     .into_iter()
+    ///     .first_none_or_for_each(|_row| rendered += 1);
+    ///
+    /// assert_eq!(option, None);
+    /// assert_eq!(rendered, 2);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_for_each<T, F>(mut self, mut f: F) -> Option<()>
+    where
+        F: FnMut(T),
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.try_for_each(|opt| opt.map(&mut f))
+    }
+
+    /// Calls the fallible `f` function on every `Some` value in the current iterator, or returns
+    /// `None` if any `None` item found, or the function returned `None`.
+    ///
+    /// The iteration stops at the first failure by position: if the current iterator produced
+    /// a `None` item before the `f` function failed, the `f` function will never see the rest
+    /// values; if the `f` function failed first, the rest items will not be consumed. Either
+    /// way, the result is `None`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut queue = [0u8; 2];
+    /// let mut len = 0;
+    ///
+    /// let mut push = |n| {
+    ///     *queue.get_mut(len)? = n;
+    ///     len += 1;
+    ///     Some(())
+    /// };
+    ///
+    /// // The queue is full when pushing `3`.
+    /// let option = [Some(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_try_for_each(&mut push);
+    ///
+    /// assert_eq!(option, None);
+    /// assert_eq!(queue, [1, 2]);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_try_for_each<T, F>(mut self, mut f: F) -> Option<()>
+    where
+        F: FnMut(T) -> Option<()>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.try_for_each(|opt| f(opt?))
+    }
+
+    /// Sums every `Some` value in the current iterator, or returns `None` if any `None` item
+    /// found.
+    ///
+    /// This method is a shortcut of `first_none_or_else(|iter| iter.sum())`, but uses internal
+    /// iteration over the current iterator. The iteration stops at the first `None` item.
+    ///
+    /// Overflow behavior is the same as [`Iterator::sum()`].
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_sum::<_, u8>();
+    /// assert_eq!(option, Some(3));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_sum::<_, u8>();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_sum<T, S>(self) -> Option<S>
+    where
+        S: core::iter::Sum<T>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.sum()
+    }
+
+    /// Multiplies every `Some` value in the current iterator, or returns `None` if any `None`
+    /// item found.
+    ///
+    /// This method is a shortcut of `first_none_or_else(|iter| iter.product())`, but uses
+    /// internal iteration over the current iterator. The iteration stops at the first `None`
+    /// item.
+    ///
+    /// Overflow behavior is the same as [`Iterator::product()`].
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<f32>(0.5), Some(2.0), Some(3.0)]
+    ///     .into_iter()
+    ///     .first_none_or_product::<_, f32>();
+    /// assert_eq!(option, Some(3.0));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<f32>(0.5), Some(2.0), None]
+    ///     .into_iter()
+    ///     .first_none_or_product::<_, f32>();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_product<T, P>(self) -> Option<P>
+    where
+        P: core::iter::Product<T>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.product()
+    }
+
+    /// Counts the items in the current iterator, or returns `None` if any `None` item found.
+    ///
+    /// The iteration stops at the first `None` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_count();
+    /// assert_eq!(option, Some(3));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_count();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_count<T>(mut self) -> Option<usize>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.try_fold(0, |count, opt| opt.map(|_| count + 1))
+    }
+
+    /// Returns the minimum `Some` value in the current iterator, or `None` if any `None` item
+    /// found.
+    ///
+    /// If the current iterator is empty, `None` is also returned. Use
+    /// `first_none_or_else(|iter| iter.min())` instead when need to distinguish these two cases.
+    ///
+    /// If several elements are equally minimum, the first element is returned, as
+    /// [`Iterator::min()`] does.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(2), Some(1), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_min();
+    /// assert_eq!(option, Some(1));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(2), Some(1), None]
+    ///     .into_iter()
+    ///     .first_none_or_min();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_min<T>(self) -> Option<T>
+    where
+        T: Ord,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.min())
+    }
+
+    /// Returns the maximum `Some` value in the current iterator, or `None` if any `None` item
+    /// found.
+    ///
+    /// If the current iterator is empty, `None` is also returned. Use
+    /// `first_none_or_else(|iter| iter.max())` instead when need to distinguish these two cases.
+    ///
+    /// If several elements are equally maximum, the last element is returned, as
+    /// [`Iterator::max()`] does.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(2), Some(3), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_max();
+    /// assert_eq!(option, Some(3));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(2), Some(3), None]
+    ///     .into_iter()
+    ///     .first_none_or_max();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_max<T>(self) -> Option<T>
+    where
+        T: Ord,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.max())
+    }
+
+    /// Returns the `Some` value with the minimum key in the current iterator, or `None` if any
+    /// `None` item found.
+    ///
+    /// If the current iterator is empty, `None` is also returned. If several elements are equally
+    /// minimum, the first element is returned, as [`Iterator::min_by_key()`] does.
+    ///
+    /// The `f` function will never be called after the first `None` item found.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let target = 10i32;
+    ///
+    /// // Everything is Some.
+    /// let option = [Some::<i32>(3), Some(12), Some(7)]
+    ///     .into_iter()
+    ///     .first_none_or_min_by_key(|n| (n - target).abs());
+    /// assert_eq!(option, Some(12));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<i32>(3), None, Some(7)]
+    ///     .into_iter()
+    ///     .first_none_or_min_by_key(|n| (n - target).abs());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_min_by_key<T, K, F>(self, f: F) -> Option<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.min_by_key(f))
+    }
+
+    /// Returns the `Some` value with the maximum key in the current iterator, or `None` if any
+    /// `None` item found.
+    ///
+    /// If the current iterator is empty, `None` is also returned. If several elements are equally
+    /// maximum, the last element is returned, as [`Iterator::max_by_key()`] does.
+    ///
+    /// The `f` function will never be called after the first `None` item found.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<i32>(-3), Some(12), Some(-7)]
+    ///     .into_iter()
+    ///     .first_none_or_max_by_key(|n| n.abs());
+    /// assert_eq!(option, Some(12));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<i32>(-3), Some(12), None]
+    ///     .into_iter()
+    ///     .first_none_or_max_by_key(|n| n.abs());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_max_by_key<T, K, F>(self, f: F) -> Option<T>
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.max_by_key(f))
+    }
+
+    /// Returns the last `Some` value in the current iterator, or `None` if any `None` item found.
+    ///
+    /// If the current iterator is empty, `None` is also returned. The iteration stops at the
+    /// first `None` item, values after that can never change the result, even if the current
+    /// iterator is not fused.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_last();
+    /// assert_eq!(option, Some(2));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_last();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_last<T>(self) -> Option<T>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.last())
+    }
+
+    /// Returns the `n`th `Some` value in the current iterator, or `None` if any `None` item
+    /// found.
+    ///
+    /// Unlike [`Iterator::nth()`], this method always checks the whole current iterator. Even if
+    /// the `n`th value is found, a `None` item after it still voids the result. If the current
+    /// iterator does not have enough values, `None` is returned.
+    ///
+    /// This method is the same as `first_none_or_try(|iter| iter.nth(n))`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_nth(1);
+    /// assert_eq!(option, Some(1));
+    ///
+    /// // A `None` after the `n`th value.
+    /// let option = [Some::<u8>(0), Some(1), Some(2), None]
+    ///     .into_iter()
+    ///     .first_none_or_nth(1);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_nth<T>(self, n: usize) -> Option<T>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.nth(n))
+    }
+
+    /// Searches for the first `Some` value that satisfies the `predicate`, or returns `None` if
+    /// any `None` item found.
+    ///
+    /// The result is `Some(None)` if no value satisfies the `predicate`. The `predicate` will not
+    /// be called after a value matched, but the rest of the current iterator will still be checked
+    /// for `None` items.
+    ///
+    /// This method is the same as `first_none_or_else(|iter| iter.find(predicate))`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_find(|&n| n > 1);
+    /// assert_eq!(option, Some(Some(2)));
+    ///
+    /// // A `None` after the matched value.
+    /// let option = [Some::<u8>(1), Some(2), None]
+    ///     .into_iter()
+    ///     .first_none_or_find(|&n| n > 1);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_find<T, P>(self, predicate: P) -> Option<Option<T>>
+    where
+        P: FnMut(&T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.find(predicate))
+    }
+
+    /// Searches for the position of the first `Some` value that satisfies the `predicate`, or
+    /// returns `None` if any `None` item found.
+    ///
+    /// The result is `Some(None)` if no value satisfies the `predicate`. The position is counted
+    /// over the `Some` values only, but since any `None` item voids the result, it is also the
+    /// absolute position in the current iterator whenever a position is returned.
+    ///
+    /// The `predicate` will not be called after a value matched, but the rest of the current
+    /// iterator will still be checked for `None` items.
+    ///
+    /// This method is the same as `first_none_or_else(|iter| iter.position(predicate))`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_position_of(|n| n > 1);
+    /// assert_eq!(option, Some(Some(1)));
+    ///
+    /// // A `None` after the matched value.
+    /// let option = [Some::<u8>(1), Some(2), None]
+    ///     .into_iter()
+    ///     .first_none_or_position_of(|n| n > 1);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_position_of<T, P>(self, predicate: P) -> Option<Option<usize>>
+    where
+        P: FnMut(T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.position(predicate))
+    }
+
+    /// Tests if any `Some` value satisfies the `predicate`, or returns `None` if any `None` item
+    /// found.
+    ///
+    /// The `predicate` will not be called after it returned `true`. But no matter the answer is
+    /// known or not, the whole current iterator will still be checked for `None` items, and a
+    /// `None` item always voids the answer. So the cost of this method is always a full scan
+    /// unless a `None` item found.
+    ///
+    /// This method is the same as `first_none_or_else(|iter| iter.any(predicate))`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_any(|n| n > 2);
+    /// assert_eq!(option, Some(true));
+    ///
+    /// // A `None` after the matched value.
+    /// let option = [Some::<u8>(1), Some(3), None]
+    ///     .into_iter()
+    ///     .first_none_or_any(|n| n > 2);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_any<T, P>(self, predicate: P) -> Option<bool>
+    where
+        P: FnMut(T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.any(predicate))
+    }
+
+    /// Tests if every `Some` value satisfies the `predicate`, or returns `None` if any `None` item
+    /// found.
+    ///
+    /// The `predicate` will not be called after it returned `false`. But the whole current
+    /// iterator will still be checked for `None` items, and a `None` item always voids the answer.
+    ///
+    /// This method is the same as `first_none_or_else(|iter| iter.all(predicate))`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_all(|n| n > 0);
+    /// assert_eq!(option, Some(true));
+    ///
+    /// // A `None` after the unmatched value.
+    /// let option = [Some::<u8>(1), Some(0), None]
+    ///     .into_iter()
+    ///     .first_none_or_all(|n| n > 0);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_all<T, P>(self, predicate: P) -> Option<bool>
+    where
+        P: FnMut(T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.all(predicate))
+    }
+
+    /// Reduces every `Some` value in the current iterator to a single one, or returns `None` if
+    /// any `None` item found.
+    ///
+    /// If the current iterator is empty, `None` is also returned. Use
+    /// `first_none_or_else(|iter| iter.reduce(f))` instead when need to distinguish these two
+    /// cases.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // (min, max) bounding ranges.
+    /// let merge = |a: (u8, u8), b: (u8, u8)| (a.0.min(b.0), a.1.max(b.1));
+    ///
+    /// // Everything is Some.
+    /// let option = [Some((3, 5)), Some((1, 2)), Some((4, 8))]
+    ///     .into_iter()
+    ///     .first_none_or_reduce(merge);
+    /// assert_eq!(option, Some((1, 8)));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some((3, 5)), None, Some((4, 8))]
+    ///     .into_iter()
+    ///     .first_none_or_reduce(merge);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_reduce<T, F>(self, f: F) -> Option<T>
+    where
+        F: FnMut(T, T) -> T,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_try(|iter| iter.reduce(f))
+    }
+
+    /// Collects every `Some` value in the current iterator into a collection, or returns `None`
+    /// if any `None` item found.
+    ///
+    /// The `size_hint()` of the current iterator is forwarded to the collection, so collections
+    /// such as `Vec` or `String` can pre-allocate their storage.
+    ///
+    /// This method is the same as `first_none_or_else(|iter| iter.collect())`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some('f'), Some('o'), Some('o')]
+    ///     .into_iter()
+    ///     .first_none_or_collect::<_, String>();
+    /// assert_eq!(option, Some("foo".to_string()));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some('f'), None, Some('o')]
+    ///     .into_iter()
+    ///     .first_none_or_collect::<_, String>();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_collect<T, C>(self) -> Option<C>
+    where
+        C: FromIterator<T>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.collect())
+    }
+
+    /// Pushes every `Some` value in the current iterator into `sink`, or returns `None` if any
+    /// `None` item found.
+    ///
+    /// The iteration stops at the first `None` item, and the values before it are already pushed
+    /// into `sink`. In this case, `sink` is left partially filled, and the caller should discard
+    /// or roll back it when needed.
+    ///
+    /// No allocation is required by this method itself, `sink` can be any container which
+    /// implemented [`Extend`], such as a fixed-capacity buffer owned by the caller.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let mut sink = vec![];
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_extend_into(&mut sink);
+    /// assert_eq!(option, Some(()));
+    /// assert_eq!(sink, vec![0, 1, 2]);
+    ///
+    /// // Contains some `None` values, the sink is partially filled.
+    /// let mut sink = vec![];
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_extend_into(&mut sink);
+    /// assert_eq!(option, None);
+    /// assert_eq!(sink, vec![0]);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_extend_into<T, X>(self, sink: &mut X) -> Option<()>
+    where
+        X: Extend<T>,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_for_each(|t| sink.extend(core::iter::once(t)))
+    }
+
+    /// Pushes every `Some` value in the current iterator into `a` or `b` by the `predicate`, and
+    /// returns the count of values pushed into each, or returns `None` if any `None` item found.
+    ///
+    /// The values satisfied the `predicate` are pushed into `a`, others are pushed into `b`.
+    ///
+    /// The iteration stops at the first `None` item, and the values before it are already pushed
+    /// into the sinks. In this case, both sinks are left partially filled, and the caller should
+    /// discard or roll back them when needed.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut evens = vec![];
+    /// let mut odds = vec![];
+    ///
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_partition_into(|n| n % 2 == 0, &mut evens, &mut odds);
+    ///
+    /// assert_eq!(option, Some((2, 1)));
+    /// assert_eq!(evens, vec![0, 2]);
+    /// assert_eq!(odds, vec![1]);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_partition_into<T, A, B, P>(
+        mut self,
+        mut predicate: P,
+        a: &mut A,
+        b: &mut B,
+    ) -> Option<(usize, usize)>
+    where
+        A: Extend<T>,
+        B: Extend<T>,
+        P: FnMut(&T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.try_fold((0, 0), |(a_count, b_count), opt| {
+            let t = opt?;
+
+            if predicate(&t) {
+                a.extend(core::iter::once(t));
+                Some((a_count + 1, b_count))
+            } else {
+                b.extend(core::iter::once(t));
+                Some((a_count, b_count + 1))
+            }
+        })
+    }
+
+    /// Returns the index of the first `None` item in the current iterator, or an `Ok` value
+    /// produced by the `f` closure with the count of `Some` items.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but
+    /// also reports how many `Some` items the current iterator produced before exhausted, no
+    /// matter how many of them are consumed by the `f` closure, or by the draining after the `f`
+    /// closure returned. When a `None` item found, the count of `Some` items before it is also
+    /// its index.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let result = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else_counted(|iter| iter.next());
+    /// assert_eq!(result, Ok((Some(0), 3)));
+    ///
+    /// // Contains some `None` values.
+    /// let result = [Some::<u8>(0), Some(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_counted(|iter| iter.next());
+    /// assert_eq!(result, Err(2));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_counted<T, O, F>(self, f: F) -> Result<(O, usize), usize>
+    where
+        F: FnOnce(&mut FirstNoneCountedIter<Self>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNoneCountedIter::first_none_or_else_counted(self, f)
+    }
+
+    /// Returns the count of `None` items in the current iterator, or the count of all items if
+    /// no `None` found.
+    ///
+    /// Unlike other `first_none_*` methods, this method does not stop at the first `None` item.
+    /// It always scans the whole current iterator, to count all the `None` items.
+    ///
+    /// This method is the same as `last_none_or_else(|iter| iter.count())`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let result = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_counted();
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // Some samples are missing.
+    /// let result = [Some::<u8>(0), None, Some(2), None, Some(4)]
+    ///     .into_iter()
+    ///     .first_none_counted();
+    /// assert_eq!(result, Err(2));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_counted<T>(self) -> Result<usize, usize>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.last_none_or_else(|iter| iter.count())
+    }
+
+    /// Returns an `Ok` value produced by the `f` closure if no `None` found, or returns the same
+    /// value as `Err` if any `None` item found.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but
+    /// keeps the output of the `f` closure even if it is invalid. When a `None` item found, the
+    /// output is computed only from the `Some` values before the first `None`, which is useful
+    /// for "best effort" results.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let result = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else_partial(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // Contains some `None` values, only sum the values before the first `None`.
+    /// let result = [Some::<u8>(1), Some(2), None, Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_partial(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(3));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_partial<T, O, F>(self, f: F) -> Result<O, O>
+    where
+        F: FnOnce(&mut FirstNoneIter<Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNoneIter::first_none_or_else_partial(self, f)
+    }
+
+    /// Returns the indices of the first `N` `None` items in the current iterator, or an `Ok`
+    /// value produced by the `f` closure if no `None` found.
+    ///
+    /// The argument iterator of the `f` closure is the same as
+    /// [`first_none_position_or_else()`](FirstErr::first_none_position_or_else), which stops at
+    /// the first `None` item. But when a `None` item found, this method scans the whole current
+    /// iterator to record the positions of the gaps, at most `N` of them are kept in the returned
+    /// [`IndexBuf`], and the total count is also reported.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let result = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_indices_or_else::<2, _, _, _>(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // Contains three `None` values.
+    /// let result = [Some::<u8>(0), None, Some(2), None, None]
+    ///     .into_iter()
+    ///     .first_none_indices_or_else::<2, _, _, _>(|iter| iter.sum::<u8>());
+    /// let gaps = result.unwrap_err();
+    /// assert_eq!(gaps.as_slice(), &[1, 3]);
+    /// assert_eq!(gaps.total(), 3);
+    /// assert!(gaps.is_truncated());
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_indices_or_else<const N: usize, T, O, F>(self, f: F) -> Result<O, IndexBuf<N>>
+    where
+        F: FnOnce(&mut FirstErrIter<NonePositions<Self>, T, usize>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        let mut iter = FirstErrIter::new(NonePositions::new(self));
+
+        let output = f(&mut iter);
+
+        let first = match iter.check_rest() {
+            Ok(()) => return Ok(output),
+            Err(&first) => first,
+        };
+
+        let mut buf = IndexBuf::new();
+        buf.push(first);
+
+        // The `None` found from the back (if any) is after all the rest items.
+        let (rest, _, back) = iter.into_parts();
+        for i in rest
+            .into_iter()
+            .flatten()
+            .filter_map(Result::err)
+            .chain(back)
+        {
+            buf.push(i);
+        }
+
+        Err(buf)
+    }
+
+    /// Returns `None` if any layer of the nested `Option` items is `None`, or a `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as nesting [`first_none_or_else()`](FirstErr::first_none_or_else)
+    /// twice, but the argument iterator of the `f` closure producing the inner `T` values
+    /// directly, and the result is flattened. So a `None` in the inner layer produces `None`,
+    /// rather than `Some(None)` as the manual nesting does.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some(Some::<u8>(0)), Some(Some(1)), Some(Some(2))]
+    ///     .into_iter()
+    ///     .first_none2_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(3));
+    ///
+    /// // Contains `None` in the inner layer.
+    /// let option = [Some(Some::<u8>(0)), Some(None), Some(Some(2))]
+    ///     .into_iter()
+    ///     .first_none2_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none2_or_else<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<&mut FirstNoneIter<Self, Option<T>>, T>) -> O,
+        Self: Iterator<Item = Option<Option<T>>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.first_none_or_else(f))
+            .flatten()
+    }
+
+    /// Returns `None` if any layer of the triple-nested `Option` items is `None`, or a `Some`
+    /// value produced by the `f` closure.
+    ///
+    /// This method is the same as nesting [`first_none_or_else()`](FirstErr::first_none_or_else)
+    /// three times, but the argument iterator of the `f` closure producing the innermost `T`
+    /// values directly, and the result is flattened.
+    ///
+    /// Like the manual nesting, the iteration stops immediately at a `None` in the outermost
+    /// layer. But a `None` in an inner layer only stops the `f` closure, the rest of the current
+    /// iterator will still be checked for `None` in the outer layers.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some(Some(Some::<u8>(0))), Some(Some(Some(1)))]
+    ///     .into_iter()
+    ///     .first_none3_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(1));
+    ///
+    /// // Contains `None` in the innermost layer.
+    /// let option = [Some(Some(Some::<u8>(0))), Some(Some(None))]
+    ///     .into_iter()
+    ///     .first_none3_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none3_or_else<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(
+            &mut FirstNoneIter<
+                &mut FirstNoneIter<&mut FirstNoneIter<Self, Option<Option<T>>>, Option<T>>,
+                T,
+            >,
+        ) -> O,
+        Self: Iterator<Item = Option<Option<Option<T>>>> + Sized,
+    {
+        self.first_none_or_else(|iter| iter.first_none2_or_else(f))
+            .flatten()
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, without consuming the current iterator.
+    ///
+    /// Unlike [`first_none_or_else()`](FirstErr::first_none_or_else), this method only borrows
+    /// the current iterator, and never drains the remaining items after the `f` closure returned.
+    /// So only the items consumed by the `f` closure are checked, and the rest items (after the
+    /// last consumed one, or after the first `None` item) are left in the current iterator for the
+    /// next call. This is useful to process a long stream in batches.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut iter = [Some::<u8>(0), Some(1), Some(2), None, Some(4)].into_iter();
+    ///
+    /// // First batch.
+    /// let option = iter.first_none_or_else_resumable(|iter| iter.take(2).sum::<u8>());
+    /// assert_eq!(option, Some(1));
+    ///
+    /// // Second batch, encounter a `None`.
+    /// let option = iter.first_none_or_else_resumable(|iter| iter.take(2).sum::<u8>());
+    /// assert_eq!(option, None);
+    ///
+    /// // The rest items are still there.
+    /// assert_eq!(iter.next(), Some(Some(4)));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_resumable<T, O, F>(&mut self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<&mut Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNoneIter::first_none_or_else_no_drain(self, f)
+    }
+
+    /// Returns the first `None` item in the items consumed by the `f` closure, or an `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but
+    /// never drains the remaining items after the `f` closure returned. So it works with infinite
+    /// iterators, as long as the `f` closure itself terminates.
+    ///
+    /// This is a weaker guarantee: a `None` item not consumed by the `f` closure will never be
+    /// found, and the result can be `Some` even if the current iterator contains `None` items.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut n = 0u8;
+    ///
+    /// let option = core::iter::repeat_with(|| {
+    ///     n += 1;
+    ///     n.checked_mul(100)
+    /// })
+    /// .first_none_or_else_no_drain(|iter| iter.take(2).max());
+    ///
+    /// // The third item is `None`, but never consumed.
+    /// assert_eq!(option, Some(Some(200)));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_no_drain<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNoneIter::first_none_or_else_no_drain(self, f)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, and always consumes the whole current iterator.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but
+    /// keeps pulling the current iterator to exhaustion even after the first `None` item found.
+    /// This is useful when the source must be fully drained anyway, such as a hardware FIFO.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut orig_iter_next_count = 0;
+    ///
+    /// let option = [Some::<u8>(0), None, Some(2), None]
+    ///     .into_iter()
+    ///     .inspect(|_| orig_iter_next_count += 1)
+    ///     .first_none_or_else_drain_all(|iter| iter.sum::<u8>());
+    ///
+    /// assert_eq!(option, None);
+    /// assert_eq!(orig_iter_next_count, 4);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_drain_all<T, O, F>(mut self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<&mut Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        let output = (&mut self).first_none_or_else(f);
+
+        // If no `None` found, the current iterator already exhausted.
+        if output.is_none() {
+            self.for_each(drop);
+        }
+
+        output
+    }
+
+    /// Returns the first `None` item before the cutoff in the current iterator, or an `Some`
+    /// value produced by the `f` closure.
+    ///
+    /// The current iterator is cut off at the first `Some` value which not satisfies the
+    /// `predicate`. This value and all the items after it are ignored, neither produced by the
+    /// argument iterator of the `f` closure, nor checked for `None`. A `None` item before the
+    /// cutoff still voids the result.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // (timestamp, sample)
+    /// let samples = [Some((0, 10u8)), Some((1, 20)), Some((5, 30)), None];
+    ///
+    /// // Only care about the samples within the time window.
+    /// let option = samples
+    ///     .into_iter()
+    ///     .first_none_or_else_while(|&(ts, _)| ts < 3, |iter| {
+    ///         iter.map(|(_, sample)| sample).sum::<u8>()
+    ///     });
+    ///
+    /// assert_eq!(option, Some(30));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_while<T, O, P, F>(self, predicate: P, f: F) -> Option<O>
+    where
+        P: FnMut(&T) -> bool,
+        F: FnOnce(&mut FirstNoneIter<SomeWhile<Self, P>, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        SomeWhile::new(self, predicate).first_none_or_else(f)
+    }
+
+    /// Creates an iterator which turns every `Some` value satisfying the `predicate` into `None`,
+    /// and passes other items through.
+    ///
+    /// This is useful to treat some invalid values as missing before searching the first `None`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let valid = 0..100;
+    ///
+    /// // Everything is valid.
+    /// let option = [Some::<u8>(10), Some(20), Some(30)]
+    ///     .into_iter()
+    ///     .none_if(|n| !valid.contains(n))
+    ///     .first_none_or_else(|iter| iter.max());
+    /// assert_eq!(option, Some(Some(30)));
+    ///
+    /// // Contains an out-of-range value.
+    /// let option = [Some::<u8>(10), Some(200), Some(30)]
+    ///     .into_iter()
+    ///     .none_if(|n| !valid.contains(n))
+    ///     .first_none_or_else(|iter| iter.max());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn none_if<T, P>(self, predicate: P) -> NoneIf<Self, P>
+    where
+        P: FnMut(&T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        NoneIf::new(self, predicate)
+    }
+
+    /// Maps every `Some` value in the current iterator by a fallible function, and returns the
+    /// first `None` item from the current iterator or the function, or an `Some` value produced
+    /// by the `f` closure.
+    ///
+    /// This method is the same as `map(|opt| opt.and_then(map)).first_none_or_else(f)`. A `None`
+    /// from the current iterator and a `None` from the `map` function are treated the same, the
+    /// first one by position stops the argument iterator of the `f` closure.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let table = ["zero", "one", "two"];
+    ///
+    /// // Everything is Some.
+    /// let option = [Some::<usize>(0), Some(2)]
+    ///     .into_iter()
+    ///     .map_first_none_or_else(|i| table.get(i), |iter| iter.map(|s| s.len()).sum::<usize>());
+    /// assert_eq!(option, Some(7));
+    ///
+    /// // The lookup failed.
+    /// let option = [Some::<usize>(0), Some(5)]
+    ///     .into_iter()
+    ///     .map_first_none_or_else(|i| table.get(i), |iter| iter.map(|s| s.len()).sum::<usize>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn map_first_none_or_else<T, U, O, M, F>(self, map: M, f: F) -> Option<O>
+    where
+        M: FnMut(T) -> Option<U>,
+        F: FnOnce(&mut FirstNoneIter<AndThen<Self, M>, U>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        AndThen::new(self, map).first_none_or_else(f)
+    }
+
+    /// Pairs the items of the current iterator with the items of the `other` iterator, and
+    /// returns `None` if any item in either iterator is `None`, or a `Some` value produced by the
+    /// `f` closure.
+    ///
+    /// The argument iterator of the `f` closure producing `(T, T2)` pairs, and stops at the first
+    /// `None` in either iterator, or at the end of the shorter one. The current iterator is
+    /// always pulled before the `other` iterator at the same position.
+    ///
+    /// After the `f` closure returned, the rest of both iterators will be drained to check for
+    /// `None`, even if their lengths are mismatched. So the `other` iterator must be finite.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_zip([Some::<u8>(4), Some(5)].into_iter(), |iter| {
+    ///         iter.map(|(a, b)| a * b).sum::<u8>()
+    ///     });
+    /// assert_eq!(option, Some(14));
+    ///
+    /// // Contains `None` in the remainder of the longer iterator.
+    /// let option = [Some::<u8>(1), Some(2), None]
+    ///     .into_iter()
+    ///     .first_none_or_else_zip([Some::<u8>(4), Some(5)].into_iter(), |iter| {
+    ///         iter.map(|(a, b)| a * b).sum::<u8>()
+    ///     });
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_zip<T, T2, I2, O, F>(self, other: I2, f: F) -> Option<O>
+    where
+        I2: Iterator<Item = Option<T2>>,
+        F: FnOnce(
+            &mut core::iter::Zip<&mut FirstNoneIter<Self, T>, &mut FirstNoneIter<I2, T2>>,
+        ) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| other.first_none_or_else(|other| f(&mut iter.zip(other))))
+            .flatten()
+    }
+
+    /// Concatenates the current iterator with the `second` iterator, and returns the first
+    /// `None` item in the concatenated order, or an `Some` value produced by the `f` closure.
+    ///
+    /// This method is the same as `chain(second).first_none_or_else(f)`. The `second` iterator
+    /// will not be touched if a `None` is found in the current iterator.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_else_chain([Some(2), Some(3)], |iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(6));
+    ///
+    /// // Contains `None` in the second iterator.
+    /// let option = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_else_chain([Some(2), None], |iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_chain<T, I2, O, F>(self, second: I2, f: F) -> Option<O>
+    where
+        I2: IntoIterator<Item = Option<T>>,
+        F: FnOnce(&mut FirstNoneIter<core::iter::Chain<Self, I2::IntoIter>, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.chain(second).first_none_or_else(f)
+    }
+
+    /// Folds the two components of every `Some((A, B))` value in the current iterator by two
+    /// separate functions, or returns `None` if any `None` item found.
+    ///
+    /// This method is the single pass version of `unzip()` followed by two `fold()`, but without
+    /// allocation. The `fold_a` function folds the `A` components into the first accumulator
+    /// starting from `init.0`, and the `fold_b` function folds the `B` components into the second
+    /// accumulator starting from `init.1`. For each item, `fold_a` is always called before
+    /// `fold_b`.
+    ///
+    /// The iteration stops at the first `None` item, and both partial accumulators are dropped.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some((1u8, 'a')), Some((2, 'b')), Some((3, 'c'))]
+    ///     .into_iter()
+    ///     .first_none_or_else_unzip(
+    ///         (0, 0),
+    ///         |acc, n| acc + n,
+    ///         |acc, c| if c == 'b' { acc + 1 } else { acc },
+    ///     );
+    /// assert_eq!(option, Some((6, 1)));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some((1u8, 'a')), None, Some((3, 'c'))]
+    ///     .into_iter()
+    ///     .first_none_or_else_unzip(
+    ///         (0, 0),
+    ///         |acc, n| acc + n,
+    ///         |acc, c| if c == 'b' { acc + 1 } else { acc },
+    ///     );
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_unzip<A, B, AccA, AccB, FA, FB>(
+        mut self,
+        init: (AccA, AccB),
+        mut fold_a: FA,
+        mut fold_b: FB,
+    ) -> Option<(AccA, AccB)>
+    where
+        FA: FnMut(AccA, A) -> AccA,
+        FB: FnMut(AccB, B) -> AccB,
+        Self: Iterator<Item = Option<(A, B)>> + Sized,
+    {
+        self.try_fold(init, |(acc_a, acc_b), opt| {
+            opt.map(|(a, b)| {
+                let acc_a = fold_a(acc_a, a);
+                (acc_a, fold_b(acc_b, b))
+            })
+        })
+    }
+
+    /// Returns `None` if any `None` item found in the current iterator, or `Some(())`.
+    ///
+    /// This method is the same as `first_none_or(())`, but uses internal iteration over the
+    /// current iterator without the [`FirstNoneIter`] machinery, which may be faster. The
+    /// iteration stops at the first `None` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .check_first_none();
+    /// assert_eq!(option, Some(()));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .check_first_none();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn check_first_none<T>(mut self) -> Option<()>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.try_for_each(|opt| opt.map(drop))
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, which can look ahead one value.
+    ///
+    /// Same as [`first_none_or_else()`](FirstErr::first_none_or_else), but the argument iterator
+    /// of the `f` closure is a [`FirstNonePeekable`], which can
+    /// [`peek()`](FirstNonePeekable::peek) the next `Some` value without consuming it.
+    ///
+    /// When the next item is the first `None`, `peek()` just see the end of `Some` values, and
+    /// this method will return `None`.
+    ///
+    /// If a value was peeked but never consumed when the closure returns, it is treated as
+    /// consumed. It's already known as a `Some` value, so it will just be dropped and not affect
+    /// the result. The remaining items will be checked after it as usual.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Sum up values until meet a `0`.
+    /// let option = [Some::<u8>(1), Some(2), Some(0), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_peekable(|iter| {
+    ///         let mut sum = 0;
+    ///         while let Some(n) = iter.next_if(|&n| n != 0) {
+    ///             sum += n;
+    ///         }
+    ///         (sum, iter.peek().copied())
+    ///     });
+    /// assert_eq!(option, Some((3, Some(0))));
+    ///
+    /// // Peek at the first `None` just see the end.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else_peekable(|iter| {
+    ///         iter.next();
+    ///         iter.peek().copied()
+    ///     });
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_peekable<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNonePeekable<Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNonePeekable::first_none_or_else_peekable(self, f)
+    }
+
+    /// Returns the first `None` item from the back of the current iterator, or an `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// This is the mirror of [`first_none_or_else()`](FirstErr::first_none_or_else). The argument
+    /// iterator of the `f` closure will producing the `Some` values in reverse order, and will
+    /// stop when encounter the first `None` item from the back. So the `None` can be found
+    /// without scanning the front of the iterator.
+    ///
+    /// It behaves exactly the same as `iter.rev().first_none_or_else(f)`.
+    ///
+    /// The back of the iterator is driven by [`next_back()`](DoubleEndedIterator::next_back)
+    /// only, and the searching stops when it first returns `None`. If the back-end of the
+    /// iterator is not fused, the items it may produce after that are never examined.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u16>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_rev(|iter| iter.fold(0, |acc, n| acc * 10 + n));
+    /// assert_eq!(option, Some(321));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [None, Some::<u8>(1), None, Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_rev(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_rev<T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<core::iter::Rev<Self>, T>) -> O,
+        Self: DoubleEndedIterator<Item = Option<T>> + Sized,
+    {
+        self.rev().first_none_or_else(f)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, which receives the `Some` values chunk by chunk.
+    ///
+    /// This is the `Option` version of
+    /// [`first_err_or_else_chunks()`](FirstErr::first_err_or_else_chunks). The argument iterator
+    /// of the `f` closure producing `[T; N]` arrays, which are filled by consecutive `Some`
+    /// values. The chunks are buffered on stack, so no allocation is needed.
+    ///
+    /// The error is a [`ChunkGap`], which is one of:
+    ///
+    /// - [`ChunkGap::None`]: a `None` item found in the current iterator. If it interrupts a
+    ///   chunk, the `Some` values already buffered in that chunk are dropped.
+    /// - [`ChunkGap::Partial`]: no `None` found, but the `Some` values can't fill up the last
+    ///   chunk. The values in that partial chunk are dropped.
+    ///
+    /// `N` must not be zero, otherwise it is a compile error.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use first_err::{ChunkGap, FirstErr};
+    ///
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let result = [Some::<u8>(1), Some(2), Some(3), Some(4)]
+    ///     .into_iter()
+    ///     .first_none_or_else_chunks(|iter| iter.map(|[a, b]| a * b).sum::<u8>());
+    /// assert_eq!(result, Ok(14));
+    ///
+    /// // Contains some `None` values.
+    /// let result = [Some::<u8>(1), None, Some(3), None]
+    ///     .into_iter()
+    ///     .first_none_or_else_chunks(|iter| iter.map(|[a, b]| a * b).sum::<u8>());
+    /// assert_eq!(result, Err(ChunkGap::None));
+    ///
+    /// // Contains a trailing partial chunk.
+    /// let result = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_chunks(|iter| iter.map(|[a, b]| a * b).sum::<u8>());
+    /// assert_eq!(result, Err(ChunkGap::Partial(1)));
+    ///
+    /// // Chunk size can also be given explicitly.
+    /// let result = [Some::<u8>(1), Some(2), Some(3)]
+    ///     .into_iter()
+    ///     .first_none_or_else_chunks::<3, _, _, _>(|iter| iter.count());
+    /// assert_eq!(result, Ok(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_chunks<const N: usize, T, O, F>(self, f: F) -> Result<O, ChunkGap>
+    where
+        F: FnOnce(&mut FirstNoneChunks<Self, T, N>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        FirstNoneChunks::first_none_or_else_chunks(self, f)
+    }
+
+    /// Returns `None` if any `None` item found in the current iterator or `other` before the
+    /// equality is decided, or an `Some(bool)` which indicates whether the `Some` values of them
+    /// are equal.
+    ///
+    /// Both iterators are walked in lockstep, and at each position, the item of the current
+    /// iterator is checked before the item of `other`. The walking stops as soon as:
+    ///
+    /// - A `None` item is found: returns `None`. If the current iterator has a `None`, `other`
+    ///   will not be pulled at the same position.
+    /// - A pair of `Some` values are not equal, or only one of the iterators is exhausted:
+    ///   returns `Some(false)`.
+    /// - Both iterators are exhausted: returns `Some(true)`.
+    ///
+    /// In other words, a `None` takes priority only if it is at or before the position where
+    /// the equality is decided. The items after that position are never examined.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_eq([Some(0), Some(1)]);
+    /// assert_eq!(option, Some(true));
+    ///
+    /// // Mismatch at position 1, the `None` at position 2 will not be examined.
+    /// let option = [Some::<u8>(0), Some(1), None]
+    ///     .into_iter()
+    ///     .first_none_or_eq([Some(0), Some(42), Some(2)]);
+    /// assert_eq!(option, Some(false));
+    ///
+    /// // `None` at position 1 is at the mismatch position.
+    /// let option = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_eq([Some(0), None]);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_eq<T, I>(self, other: I) -> Option<bool>
+    where
+        I: IntoIterator<Item = Option<T>>,
+        T: PartialEq,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        option::iter_compare(self, other.into_iter(), |a, b| {
+            if a == b {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        })
+        .map(|flow| flow == ControlFlow::Continue(Ordering::Equal))
+    }
+
+    /// Returns `None` if any `None` item found in the current iterator or `other` before the
+    /// ordering is decided, or an `Some(Ordering)` which
+    /// [lexicographically](Ord#lexicographical-comparison) compares the `Some` values of them.
+    ///
+    /// Use the same positional rule as [`first_none_or_eq()`](FirstErr::first_none_or_eq), which
+    /// is also the rule of [`first_err_or_cmp()`](FirstErr::first_err_or_cmp): a `None` takes
+    /// priority only if it is at or before the position where the ordering is decided. The items
+    /// after that position are never examined.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use std::cmp::Ordering;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_cmp([Some(0), Some(2)]);
+    /// assert_eq!(option, Some(Ordering::Less));
+    ///
+    /// // Ordering is decided at position 1, the `None` at position 2 will not be examined.
+    /// let option = [Some::<u8>(0), Some(3), None]
+    ///     .into_iter()
+    ///     .first_none_or_cmp([Some(0), Some(2), Some(2)]);
+    /// assert_eq!(option, Some(Ordering::Greater));
+    ///
+    /// // `None` appears before the ordering is decided.
+    /// let option = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_cmp([None, Some(1)]);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_cmp<T, I>(self, other: I) -> Option<Ordering>
+    where
+        I: IntoIterator<Item = Option<T>>,
+        T: Ord,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        option::iter_compare(self, other.into_iter(), |a, b| match a.cmp(&b) {
+            Ordering::Equal => ControlFlow::Continue(()),
+            non_eq => ControlFlow::Break(non_eq),
+        })
+        .map(|flow| match flow {
+            ControlFlow::Continue(ord) => ord,
+            ControlFlow::Break(ord) => ord,
+        })
+    }
+
+    /// Returns `None` if any `None` item found in the current iterator before the sortedness is
+    /// decided, or an `Some(bool)` which indicates whether the `Some` values are sorted.
+    ///
+    /// The items are checked one by one, and the checking stops as soon as:
+    ///
+    /// - A `None` item is found: returns `None`.
+    /// - A `Some` value is less than the previous one: returns `Some(false)`. The items after it
+    ///   (include `None`s) are never examined.
+    ///
+    /// If neither happens until the iterator is exhausted, returns `Some(true)`.
+    ///
+    /// In other words, a `None` takes priority only if it is at or before the position where
+    /// the sortedness is decided. Because `Some(true)` can only be decided at the end, any `None`
+    /// wins in that case.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_is_sorted();
+    /// assert_eq!(option, Some(true));
+    ///
+    /// // Unsorted pair at position 1 and 2, the `None` after them will not be examined.
+    /// let option = [Some::<u8>(0), Some(2), Some(1), None]
+    ///     .into_iter()
+    ///     .first_none_or_is_sorted();
+    /// assert_eq!(option, Some(false));
+    ///
+    /// // `None` appears before the unsorted pair.
+    /// let option = [Some::<u8>(0), None, Some(2), Some(1)]
+    ///     .into_iter()
+    ///     .first_none_or_is_sorted();
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_is_sorted<T>(self) -> Option<bool>
+    where
+        T: PartialOrd,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_is_sorted_by(|a, b| a <= b)
+    }
+
+    /// Returns `None` if any `None` item found in the current iterator before the sortedness is
+    /// decided, or an `Some(bool)` which indicates whether the `Some` values are sorted by the
+    /// given `compare` function.
+    ///
+    /// `compare` takes two consecutive `Some` values and returns `true` if they are in order,
+    /// just like the [`Iterator::is_sorted_by()`]. The positional rule is the same as
+    /// [`first_none_or_is_sorted()`](FirstErr::first_none_or_is_sorted), and `compare` will not
+    /// be called after the first out of order pair is found.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let option = [Some::<u8>(2), Some(1), Some(0)]
+    ///     .into_iter()
+    ///     .first_none_or_is_sorted_by(|a, b| a >= b);
+    /// assert_eq!(option, Some(true));
+    ///
+    /// let option = [Some::<u8>(2), Some(1), None]
+    ///     .into_iter()
+    ///     .first_none_or_is_sorted_by(|a, b| a >= b);
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_is_sorted_by<T, F>(mut self, mut compare: F) -> Option<bool>
+    where
+        F: FnMut(&T, &T) -> bool,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        let mut last = match self.next() {
+            Some(opt) => opt?,
+            None => return Some(true),
+        };
+
+        for opt in self {
+            let curr = opt?;
+            if !compare(&last, &curr) {
+                return Some(false);
+            }
+            last = curr;
+        }
+
+        Some(true)
+    }
+
+    /// Returns the first unrecoverable `None` item in the current iterator, or an `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// Every `None` item is first offered to the `recover` function. If it returns `Some(value)`,
+    /// the `value` will be treated as a `Some` item, and if it returns `None`, it will become the
+    /// first `None` and stop the iteration as usual. The `recover` function can track its own
+    /// state, e.g., a budget of how many `None` items can be recovered.
+    ///
+    /// The `recover` function is also applied on the `None` items which are found after the `f`
+    /// closure returned, so the result is the same no matter how many items the `f` closure
+    /// consumed.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Fill up at most one missing value by `0`.
+    /// let mut budget = 1;
+    /// let option = [Some::<u8>(1), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else_recover(
+    ///         || {
+    ///             if budget > 0 {
+    ///                 budget -= 1;
+    ///                 Some(0)
+    ///             } else {
+    ///                 None
+    ///             }
+    ///         },
+    ///         |iter| iter.sum::<u8>(),
+    ///     );
+    /// assert_eq!(option, Some(3));
+    ///
+    /// let mut budget = 1;
+    /// let option = [Some::<u8>(1), None, None]
+    ///     .into_iter()
+    ///     .first_none_or_else_recover(
+    ///         || {
+    ///             if budget > 0 {
+    ///                 budget -= 1;
+    ///                 Some(0)
+    ///             } else {
+    ///                 None
+    ///             }
+    ///         },
+    ///         |iter| iter.sum::<u8>(),
+    ///     );
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_recover<T, O, R, F>(self, recover: R, f: F) -> Option<O>
+    where
+        R: FnMut() -> Option<T>,
+        F: FnOnce(&mut FirstNoneIter<RecoverNone<Self, R>, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        RecoverNone::new(self, recover).first_none_or_else(f)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, which receives the flattened `Some` values.
+    ///
+    /// The `Some` values of the current iterator are iterables, and the argument iterator of the
+    /// `f` closure will producing each element of them in order, and will stop when encounter
+    /// the first `None` item.
+    ///
+    /// After the `f` closure returned, only the remaining items of the current iterator will be
+    /// checked, the remaining elements inside the `Some` values are never iterated.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Some.
+    /// let option = [Some::<&[u8]>(&[0, 1]), Some(&[]), Some(&[2])]
+    ///     .into_iter()
+    ///     .first_none_or_else_flat(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(3));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<&[u8]>(&[0, 1]), None, Some(&[2])]
+    ///     .into_iter()
+    ///     .first_none_or_else_flat(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_flat<T, II, O, F>(self, f: F) -> Option<O>
+    where
+        II: IntoIterator<Item = T>,
+        F: FnOnce(&mut FirstNoneFlat<Self, II>) -> O,
+        Self: Iterator<Item = Option<II>> + Sized,
+    {
+        FirstNoneFlat::first_none_or_else_flat(self, f)
+    }
+
+    /// Returns the first `None` item in the current iterator of `&Option<T>`, or an `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as [`first_none_or_else()`](FirstErr::first_none_or_else), but
+    /// works on an iterator of `&Option<T>`, which commonly produced by `slice.iter()`. The
+    /// argument iterator of the `f` closure producing `&T` values, which are borrowed from the
+    /// source data (not the iterator), so they can outlive this call.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let names: &[Option<String>] = &[Some("foo".to_string()), Some("quux".to_string())];
+    ///
+    /// // The `&String` is borrowed from `names`, no clone is needed.
+    /// let longest: Option<Option<&String>> = names
+    ///     .iter()
+    ///     .first_none_ref_or_else(|iter| iter.max_by_key(|s| s.len()));
+    ///
+    /// assert_eq!(longest, Some(Some(&"quux".to_string())));
+    ///
+    /// let names: &[Option<String>] = &[Some("foo".to_string()), None];
+    ///
+    /// let longest = names
+    ///     .iter()
+    ///     .first_none_ref_or_else(|iter| iter.max_by_key(|s| s.len()));
+    ///
+    /// assert_eq!(longest, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_ref_or_else<'a, T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneIter<RefOptions<Self>, &'a T>) -> O,
+        Self: Iterator<Item = &'a Option<T>> + Sized,
+        T: 'a,
+    {
+        RefOptions::new(self).first_none_or_else(f)
+    }
+
+    /// Returns the first `None` item in the current iterator of `&Option<T>`, or an `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as [`first_none_ref_or_else()`](FirstErr::first_none_ref_or_else),
+    /// but the argument iterator of the `f` closure producing copied `T` values.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let options = [Some::<u8>(0), Some(1), Some(2)];
+    ///
+    /// let ans = options
+    ///     .iter()
+    ///     .first_none_copied_or_else(|iter| iter.sum::<u8>());
+    ///
+    /// assert_eq!(ans, Some(3));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_copied_or_else<'a, T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneOwned<'a, Self, T>) -> O,
+        Self: Iterator<Item = &'a Option<T>> + Sized,
+        T: 'a + Copy,
+    {
+        FirstNoneOwned::first_none_or_else_owned(self, f)
+    }
+
+    /// Returns the first `None` item in the current iterator of `&Option<T>`, or an `Some` value
+    /// produced by the `f` closure.
+    ///
+    /// This method is the same as [`first_none_ref_or_else()`](FirstErr::first_none_ref_or_else),
+    /// but the argument iterator of the `f` closure producing cloned `T` values.
+    ///
+    /// Only the values actually consumed by the `f` closure are cloned. Items skipped during the
+    /// draining are never cloned.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let options = [Some("foo".to_string()), Some("bar".to_string())];
+    ///
+    /// let ans = options
+    ///     .iter()
+    ///     .first_none_cloned_or_else(|iter| iter.collect::<Vec<String>>());
+    ///
+    /// assert_eq!(ans, Some(vec!["foo".to_string(), "bar".to_string()]));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_cloned_or_else<'a, T, O, F>(self, f: F) -> Option<O>
+    where
+        F: FnOnce(&mut FirstNoneOwned<'a, Self, T>) -> O,
+        Self: Iterator<Item = &'a Option<T>> + Sized,
+        T: 'a + Clone,
+    {
+        FirstNoneOwned::first_none_or_else_owned(self, f)
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, which receives an explicit `state` value.
+    ///
+    /// This method is the same as `first_none_or_else(|iter| f(state, iter))`. Because the
+    /// `state` is moved into `f` as an argument rather than captured, the `f` can be a plain `fn`
+    /// item or a non-capturing closure, which can be coerced to a `fn` pointer.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// fn scaled_sum(scale: u8, iter: &mut dyn Iterator<Item = u8>) -> u8 {
+    ///     iter.map(|n| n * scale).sum()
+    /// }
+    ///
+    /// // Everything is Some.
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else_with(10, |scale, iter| scaled_sum(scale, iter));
+    /// assert_eq!(option, Some(30));
+    ///
+    /// // Contains some `None` values.
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_none_or_else_with(10, |scale, iter| scaled_sum(scale, iter));
+    /// assert_eq!(option, None);
+    /// # }
+    /// ```
+    #[inline]
+    fn first_none_or_else_with<S, T, O, F>(self, state: S, f: F) -> Option<O>
+    where
+        F: FnOnce(S, &mut FirstNoneIter<Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        self.first_none_or_else(|iter| f(state, iter))
+    }
+
+    /// Returns the first `None` item in the current iterator, or an `Some` value produced by the
+    /// `f` closure, and calls the `hook` if `None` is returned.
+    ///
+    /// The `hook` is called exactly once if a `None` item is found, no matter it was found by
+    /// the `f` closure or by the draining after it, and never called if the result is `Some`.
+    /// It's called after the `f` closure returned and the draining finished, just before this
+    /// method returns.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut missing = 0;
+    ///
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .inspect_first_none_or_else(|| missing += 1, |iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(3));
+    ///
+    /// let option = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .inspect_first_none_or_else(|| missing += 1, |iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    ///
+    /// assert_eq!(missing, 1);
+    /// # }
+    /// ```
+    #[inline]
+    fn inspect_first_none_or_else<T, O, H, F>(self, hook: H, f: F) -> Option<O>
+    where
+        H: FnOnce(),
+        F: FnOnce(&mut FirstNoneIter<Self, T>) -> O,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        let output = self.first_none_or_else(f);
+        if output.is_none() {
+            hook();
+        }
+
+        output
+    }
+
+    /// Returns an iterator producing the `Some` values in the current iterator until the first
+    /// `None`, which sets the `flag` to `true` when the `None` is hit.
+    ///
+    /// Unlike [`first_none_or_else()`](FirstErr::first_none_or_else), the returned
+    /// [`UntilNone`] is a plain iterator which can be passed by value to any API accepting an
+    /// `impl Iterator<Item = T>`. The `flag` is reset to `false` when this method is called.
+    ///
+    /// Note that nothing drains the current iterator for you. The `flag` only tells whether a
+    /// `None` was hit by the consumed part, so it must be checked after the returned iterator
+    /// is fully consumed. If the consumer stops early, a `None` after that point is never seen
+    /// and the `flag` stays `false`.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// fn total(iter: impl Iterator<Item = u8>) -> u8 {
+    ///     iter.sum()
+    /// }
+    ///
+    /// let mut found_none = false;
+    ///
+    /// let sum = total([Some::<u8>(0), Some(1), Some(2)].into_iter().until_none(&mut found_none));
+    /// assert_eq!((sum, found_none), (3, false));
+    ///
+    /// let sum = total([Some::<u8>(0), None, Some(2)].into_iter().until_none(&mut found_none));
+    /// assert_eq!((sum, found_none), (0, true));
+    /// # }
+    /// ```
+    #[inline]
+    fn until_none<T>(self, flag: &mut bool) -> UntilNone<'_, Self>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        UntilNone::new(self, flag)
+    }
+
+    /// Collects exactly `N` `Some` values into an array, or returns an error if the first `N`
+    /// items contain a `None`, or the iterator is too short.
+    ///
+    /// This is the `Option` version of [`try_collect_array()`](FirstErr::try_collect_array).
+    /// This method only consumes the first `N` items (or less, if failed early). The remaining
+    /// items are untouched, use [`by_ref()`](Iterator::by_ref) to keep using the current iterator
+    /// after this call.
+    ///
+    /// No allocation is required. Values already collected are dropped when this method failed.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::{ArrayCollectGap, FirstErr};
+    /// #
+    /// # fn main() {
+    /// // Collect the first two values.
+    /// let mut iter = [Some::<u8>(0), Some(1), Some(2)].into_iter();
+    /// let result = iter.by_ref().collect_array_or_none::<2, _>();
+    /// assert_eq!(result, Ok([0, 1]));
+    /// assert_eq!(iter.next(), Some(Some(2)));
+    ///
+    /// // Contains a `None` value.
+    /// let result = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .collect_array_or_none::<3, _>();
+    /// assert_eq!(result, Err(ArrayCollectGap::None { index: 1 }));
+    ///
+    /// // Too short.
+    /// let result = [Some::<u8>(0), Some(1)]
+    ///     .into_iter()
+    ///     .collect_array_or_none::<3, _>();
+    /// assert_eq!(result, Err(ArrayCollectGap::TooShort { got: 2 }));
+    /// # }
+    /// ```
+    #[inline]
+    fn collect_array_or_none<const N: usize, T>(mut self) -> Result<[T; N], ArrayCollectGap>
+    where
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        let mut found_none = false;
+
+        crate::array::try_from_fn(|_| match self.next()? {
+            Some(value) => Some(value),
+            None => {
+                found_none = true;
+                None
+            }
+        })
+        .map_err(|got| {
+            if found_none {
+                ArrayCollectGap::None { index: got }
+            } else {
+                ArrayCollectGap::TooShort { got }
+            }
+        })
+    }
+
+    /// Returns the first `Break` item in the current iterator, or a `Continue` value produced by
+    /// the `f` closure.
+    ///
+    /// This is the [`ControlFlow`] version of [`first_err_or_else()`](FirstErr::first_err_or_else).
+    /// The argument iterator of the `f` closure will producing the values in `Continue`
+    /// sequence, but will stop when encounter the first `Break` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use std::ops::ControlFlow;
+    /// #
+    /// # fn main() {
+    /// // Everything is Continue.
+    /// let flow = [ControlFlow::<u8, u8>::Continue(0), ControlFlow::Continue(1)]
+    ///     .into_iter()
+    ///     .first_break_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(flow, ControlFlow::Continue(1));
+    ///
+    /// // Contains some `Break` values.
+    /// let flow = [
+    ///     ControlFlow::<u8, u8>::Continue(0),
+    ///     ControlFlow::Break(1),
+    ///     ControlFlow::Break(2),
+    /// ]
+    /// .into_iter()
+    /// .first_break_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(flow, ControlFlow::Break(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_break_or_else<C, B, O, F>(self, f: F) -> ControlFlow<B, O>
+    where
+        F: FnOnce(&mut FirstBreakIter<Self, C, B>) -> O,
+        Self: Iterator<Item = ControlFlow<B, C>> + Sized,
+    {
+        FirstBreakIter::first_break_or_else(self, f)
+    }
+
+    /// Returns the index of the first `false` item in the current iterator, or an `Ok` value
+    /// produced by the `f` closure.
+    ///
+    /// This is the `bool` version of [`first_err_or_else()`](FirstErr::first_err_or_else). The
+    /// argument iterator of the `f` closure will producing the indices of the `true` items, but
+    /// will stop when encounter the first `false` item.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let names = ["foo", "bar", "baz"];
+    ///
+    /// // Everything is true.
+    /// let result = names
+    ///     .iter()
+    ///     .map(|name| name.len() == 3)
+    ///     .first_false_or_else(|iter| iter.last());
+    /// assert_eq!(result, Ok(Some(2)));
+    ///
+    /// // Contains some `false` values.
+    /// let result = names
+    ///     .iter()
+    ///     .map(|name| name.starts_with('b'))
+    ///     .first_false_or_else(|iter| iter.last());
+    /// assert_eq!(result, Err(0));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_false_or_else<O, F>(self, f: F) -> Result<O, usize>
+    where
+        F: FnOnce(&mut FirstFalseIter<Self>) -> O,
+        Self: Iterator<Item = bool> + Sized,
+    {
+        FirstFalseIter::first_false_or_else(self, f)
+    }
+
+    /// Returns the first failure in the current iterator, or an `Ok` value produced by the `f`
+    /// closure, for the items of any [`Fallible`] type.
+    ///
+    /// This is the generic version of [`first_err_or_else()`](FirstErr::first_err_or_else), works
+    /// on stable Rust. The argument iterator of the `f` closure will producing the success
+    /// values, but will stop when encounter the first failure.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use std::ops::ControlFlow;
+    /// #
+    /// # fn main() {
+    /// let result = [Ok::<u8, u8>(0), Err(1), Err(2)]
+    ///     .into_iter()
+    ///     .first_failure_of_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(1));
+    ///
+    /// let result = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .first_failure_of_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(()));
+    ///
+    /// let result = [ControlFlow::<u8, u8>::Continue(0), ControlFlow::Continue(1)]
+    ///     .into_iter()
+    ///     .first_failure_of_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_failure_of_or_else<O, F>(self, f: F) -> Result<O, <Self::Item as Fallible>::Failure>
+    where
+        F: FnOnce(&mut FirstFailureOfIter<Self>) -> O,
+        Self: Iterator + Sized,
+        Self::Item: Fallible,
+    {
+        FirstFailureOfIter::first_failure_of_or_else(self, f)
+    }
+
+    /// Returns the most severe `Err` item in the current iterator, or an `Ok` value produced by
+    /// the `f` closure.
+    ///
+    /// The severity is decided by the [`Severity`] trait. If several `Err` items have the same
+    /// highest severity, the earliest one wins. The argument iterator of the `f` closure will not
+    /// stop when encounter an `Err` item, it just skips the `Err` items, and producing all the
+    /// `Ok` values in the current iterator.
+    ///
+    /// Notice: unlike [`first_err_or_else()`](FirstErr::first_err_or_else), which can stop on the
+    /// first `Err`, this method always scans the whole current iterator, because a later `Err`
+    /// may be more severe.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::{FirstErr, Severity};
+    /// #
+    /// # fn main() {
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Warning(u8),
+    ///     Fatal(u8),
+    /// }
+    ///
+    /// impl Severity for Error {
+    ///     type Level = u8;
+    ///
+    ///     fn severity(&self) -> u8 {
+    ///         match self {
+    ///             Error::Warning(_) => 1,
+    ///             Error::Fatal(_) => 2,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let result = [Ok(0), Err(Error::Warning(1)), Ok(2), Err(Error::Fatal(3))]
+    ///     .into_iter()
+    ///     .worst_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(Error::Fatal(3)));
+    /// # }
+    /// ```
+    #[inline]
+    fn worst_err_or_else<T, E, O, F>(self, f: F) -> Result<O, E>
+    where
+        E: Severity,
+        F: FnOnce(&mut WorstErrIter<Self, E>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        WorstErrIter::worst_err_or_else(self, f)
+    }
+
+    /// Returns the first `Break` item in the current iterator, or a `ControlFlow` value
+    /// produced by the `f` closure.
+    ///
+    /// This is the [`ControlFlow`] version of [`first_err_or_try()`](FirstErr::first_err_or_try).
+    /// The first `Break` in the current iterator takes priority over the `Break` returned by the
+    /// `f` closure.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use std::ops::ControlFlow;
+    /// #
+    /// # fn main() {
+    /// // When closure returns Break.
+    /// let flow = [ControlFlow::<u8, u8>::Continue(0), ControlFlow::Continue(1)]
+    ///     .into_iter()
+    ///     .first_break_or_try(|_| ControlFlow::<u8, u8>::Break(42));
+    /// assert_eq!(flow, ControlFlow::Break(42));
+    ///
+    /// // When both contains Break.
+    /// let flow = [ControlFlow::<u8, u8>::Continue(0), ControlFlow::Break(1)]
+    ///     .into_iter()
+    ///     .first_break_or_try(|_| ControlFlow::<u8, u8>::Break(42));
+    /// assert_eq!(flow, ControlFlow::Break(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_break_or_try<C, B, O, F>(self, f: F) -> ControlFlow<B, O>
+    where
+        F: FnOnce(&mut FirstBreakIter<Self, C, B>) -> ControlFlow<B, O>,
+        Self: Iterator<Item = ControlFlow<B, C>> + Sized,
+    {
+        self.first_break_or_else(f)?
+    }
+
+    /// Returns the first `Left` item in the current iterator, or a `Right` value produced by the
+    /// `f` closure.
+    ///
+    /// This is the [`Either`](::either::Either) version of
+    /// [`first_err_or_else()`](FirstErr::first_err_or_else), which treat `Left` as the failure
+    /// channel. The argument iterator of the `f` closure will producing the values in `Right`
+    /// sequence, but will stop when encounter the first `Left` item.
+    ///
+    /// This method requires the `either` feature.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use either::Either;
+    /// #
+    /// # fn main() {
+    /// // Everything is Right.
+    /// let either = [Either::<u8, u8>::Right(0), Either::Right(1), Either::Right(2)]
+    ///     .into_iter()
+    ///     .first_left_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(either, Either::Right(3));
+    ///
+    /// // Contains some `Left` values.
+    /// let either = [Either::<u8, u8>::Right(0), Either::Left(1), Either::Left(2)]
+    ///     .into_iter()
+    ///     .first_left_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(either, Either::Left(1));
+    /// # }
+    /// ```
+    #[cfg(feature = "either")]
+    #[inline]
+    fn first_left_or_else<L, R, O, F>(self, f: F) -> ::either::Either<L, O>
+    where
+        F: FnOnce(&mut FirstLeftIter<Self, L, R>) -> O,
+        Self: Iterator<Item = ::either::Either<L, R>> + Sized,
+    {
+        FirstLeftIter::first_left_or_else(self, f)
+    }
+
+    /// Returns the first `Right` item in the current iterator, or a `Left` value produced by the
+    /// `f` closure.
+    ///
+    /// This is the mirror of [`first_left_or_else()`](FirstErr::first_left_or_else), which
+    /// treat `Right` as the failure channel. The argument iterator of the `f` closure will
+    /// producing the values in `Left` sequence, but will stop when encounter the first `Right`
+    /// item.
+    ///
+    /// This method requires the `either` feature.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// # use either::Either;
+    /// #
+    /// # fn main() {
+    /// // Everything is Left.
+    /// let either = [Either::<u8, u8>::Left(0), Either::Left(1), Either::Left(2)]
+    ///     .into_iter()
+    ///     .first_right_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(either, Either::Left(3));
+    ///
+    /// // Contains some `Right` values.
+    /// let either = [Either::<u8, u8>::Left(0), Either::Right(1), Either::Right(2)]
+    ///     .into_iter()
+    ///     .first_right_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(either, Either::Right(1));
+    /// # }
+    /// ```
+    #[cfg(feature = "either")]
+    #[inline]
+    fn first_right_or_else<L, R, O, F>(self, f: F) -> ::either::Either<O, R>
+    where
+        F: FnOnce(&mut FirstRightIter<Self, L, R>) -> O,
+        Self: Iterator<Item = ::either::Either<L, R>> + Sized,
+    {
+        FirstRightIter::first_right_or_else(self, f)
+    }
+
+    /// Returns the first residual in the current iterator, or a value produced by the `f`
+    /// closure, wrapped in the same [`Try`](core::ops::Try) family as the items.
+    ///
+    /// This is the generic version of [`first_err_or_else()`](FirstErr::first_err_or_else),
+    /// [`first_none_or_else()`](FirstErr::first_none_or_else) and
+    /// [`first_break_or_else()`](FirstErr::first_break_or_else), which works on any `Try` type,
+    /// including user-defined ones, and things like `Poll<Result<T, E>>`. The argument iterator
+    /// of the `f` closure will producing the output values of the items, but will stop when
+    /// encounter the first residual, e.g., an `Err` or a `None`.
+    ///
+    /// The per-type methods are not rebuilt on this method when this feature is enabled. Their
+    /// argument iterators, such as [`FirstErrIter`], are part of their signatures and have their
+    /// own methods (e.g., [`FirstErrIter::take_err()`]) and `DoubleEndedIterator` support, which
+    /// a generic `Try` iterator can't offer. Swapping them would make this feature break the code
+    /// using them, but a cargo feature must only add things.
+    ///
+    /// This method requires the `nightly-try` feature and a nightly compiler.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let result = [Ok::<u8, u8>(0), Err(1), Err(2)]
+    ///     .into_iter()
+    ///     .first_residual_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(1));
+    ///
+    /// let option = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .first_residual_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(3));
+    /// # }
+    /// ```
+    #[cfg(feature = "nightly-try")]
+    #[inline]
+    fn first_residual_or_else<R, O, F>(
+        self,
+        f: F,
+    ) -> <R::Residual as core::ops::Residual<O>>::TryType
+    where
+        R: core::ops::Try,
+        R::Residual: core::ops::Residual<O>,
+        F: FnOnce(&mut FirstResidualIter<Self, R>) -> O,
+        Self: Iterator<Item = R> + Sized,
+    {
+        FirstResidualIter::first_residual_or_else(self, f)
+    }
+
+    /// Returns the first failure in the current iterator, or a value produced by the `f`
+    /// closure, for any `Iterator<Item = Result<Option<T>, E>>`.
+    ///
+    /// A failure is an `Err(e)` or an `Ok(None)`, and the earliest one by position wins. The
+    /// result will be:
+    ///
+    /// - `Err(e)`: if the first failure is an `Err(e)`.
+    /// - `Ok(None)`: if the first failure is an `Ok(None)`.
+    /// - `Ok(Some(output))`: if no failure be found.
+    ///
+    /// The argument iterator of the `f` closure will producing the `T` values in `Ok(Some(T))`
+    /// sequence, but will stop when encounter the first failure.
+    ///
+    /// Notice: this is NOT the same as nesting [`first_err_or_else()`](FirstErr::first_err_or_else)
+    /// and [`first_none_or_else()`](FirstErr::first_none_or_else), which will let an `Err` after
+    /// the first `Ok(None)` win.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is fine.
+    /// let result = [Ok::<Option<u8>, u8>(Some(0)), Ok(Some(1)), Ok(Some(2))]
+    ///     .into_iter()
+    ///     .first_failure_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(Some(3)));
+    ///
+    /// // A missing value comes before an error.
+    /// let result = [Ok::<Option<u8>, u8>(Some(0)), Ok(None), Err(2)]
+    ///     .into_iter()
+    ///     .first_failure_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(None));
+    ///
+    /// // An error comes before a missing value.
+    /// let result = [Ok::<Option<u8>, u8>(Some(0)), Err(1), Ok(None)]
+    ///     .into_iter()
+    ///     .first_failure_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(1));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_failure_or_else<T, E, O, F>(self, f: F) -> Result<Option<O>, E>
+    where
+        F: FnOnce(&mut FirstFailureIter<Self, T, E>) -> O,
+        Self: Iterator<Item = Result<Option<T>, E>> + Sized,
+    {
+        FirstFailureIter::first_failure_or_else(self, f)
+    }
+
+    /// Returns the first gap in the current iterator, or a value produced by the `f` closure,
+    /// for any `Iterator<Item = Option<Result<T, E>>>`.
+    ///
+    /// A gap is a `None` or a `Some(Err(e))`, and the earliest one by position wins. This is the
+    /// mirror of [`first_failure_or_else()`](FirstErr::first_failure_or_else), the result will
+    /// be:
+    ///
+    /// - `Err(Gap::None)`: if the first gap is a `None`.
+    /// - `Err(Gap::Err(e))`: if the first gap is a `Some(Err(e))`.
+    /// - `Ok(output)`: if no gap be found.
+    ///
+    /// The argument iterator of the `f` closure will producing the `T` values in `Some(Ok(T))`
+    /// sequence, but will stop when encounter the first gap.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::{FirstErr, Gap};
+    /// #
+    /// # fn main() {
+    /// // Everything is fine.
+    /// let result = [Some(Ok::<u8, u8>(0)), Some(Ok(1)), Some(Ok(2))]
+    ///     .into_iter()
+    ///     .first_gap_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // A missing value comes before an error.
+    /// let result = [Some(Ok::<u8, u8>(0)), None, Some(Err(2))]
+    ///     .into_iter()
+    ///     .first_gap_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(Gap::None));
+    ///
+    /// // An error comes before a missing value.
+    /// let result = [Some(Ok::<u8, u8>(0)), Some(Err(1)), None]
+    ///     .into_iter()
+    ///     .first_gap_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(Gap::Err(1)));
+    /// # }
+    /// ```
+    #[inline]
+    fn first_gap_or_else<T, E, O, F>(self, f: F) -> Result<O, Gap<E>>
+    where
+        F: FnOnce(&mut FirstGapIter<Self, T, E>) -> O,
+        Self: Iterator<Item = Option<Result<T, E>>> + Sized,
+    {
+        FirstGapIter::first_gap_or_else(self, f)
+    }
+
+    /// Creates an iterator which turns every `Err` item into `None`, and every `Ok(t)` item into
+    /// `Some(t)`.
+    ///
+    /// This is useful to move from the `Result` world to the `Option` world in the middle of a
+    /// pipeline. The dropped errors will be lost, except the first one, which will be put into
+    /// the `slot` if it was given.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut first_err = None;
+    ///
+    /// let option = [Ok::<u8, &str>(0), Err("e1"), Err("e2")]
+    ///     .into_iter()
+    ///     .err_into_none(Some(&mut first_err))
+    ///     .first_none_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, None);
+    /// assert_eq!(first_err, Some("e1"));
+    ///
+    /// // Without a slot, the errors are just dropped.
+    /// let option = [Ok::<u8, &str>(0), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .err_into_none(None)
+    ///     .first_none_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(option, Some(3));
+    /// # }
+    /// ```
+    #[inline]
+    fn err_into_none<T, E>(self, slot: Option<&mut Option<E>>) -> ErrIntoNone<'_, Self, E>
+    where
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        ErrIntoNone::new(self, slot)
+    }
+
+    /// Creates an iterator which turns every `None` item into an `Err` produced by `make_err`,
+    /// and every `Some(t)` item into `Ok(t)`.
+    ///
+    /// This is useful to move from the `Option` world to the `Result` world in the middle of a
+    /// pipeline.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let result = [Some::<u8>(0), None, Some(2)]
+    ///     .into_iter()
+    ///     .none_into_err(|| "missing")
+    ///     .first_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err("missing"));
+    ///
+    /// let result = [Some::<u8>(0), Some(1), Some(2)]
+    ///     .into_iter()
+    ///     .none_into_err(|| "missing")
+    ///     .first_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    /// # }
+    /// ```
+    #[inline]
+    fn none_into_err<T, E, M>(self, make_err: M) -> NoneIntoErr<Self, M>
+    where
+        M: FnMut() -> E,
+        Self: Iterator<Item = Option<T>> + Sized,
+    {
+        NoneIntoErr::new(self, make_err)
+    }
+
+    /// Returns all the `Err` items in the current iterator collected into `E2`, or an `Ok` value
+    /// produced by the `f` closure if no `Err` found.
+    ///
+    /// This is a shortcut of [`errs_or_else()`](FirstErr::errs_or_else) which creates the sink
+    /// by [`Default`] and returns it as the error. The argument iterator of the `f` closure will
+    /// producing all the `Ok` values in the current iterator, and skip the `Err` items.
+    ///
+    /// Notice: this method abandons the early-stop guarantee of
+    /// [`first_err_or_else()`](FirstErr::first_err_or_else). It always scans the whole current
+    /// iterator, even the first `Err` has been found.
+    ///
+    /// No allocation is required by this method itself, `E2` can be any type which implemented
+    /// [`Default`] and [`Extend`].
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Everything is Ok.
+    /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+    ///     .into_iter()
+    ///     .collect_errs_or_else::<_, _, Vec<u8>, _, _>(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(3));
+    ///
+    /// // Contains some `Err` values.
+    /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+    ///     .into_iter()
+    ///     .collect_errs_or_else::<_, _, Vec<u8>, _, _>(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err(vec![1, 3]));
+    /// # }
+    /// ```
+    #[inline]
+    fn collect_errs_or_else<T, E, E2, O, F>(self, f: F) -> Result<O, E2>
+    where
+        E2: Default + Extend<E>,
+        F: for<'a> FnOnce(&mut ErrsIter<'a, Self, E2>) -> O,
+        Self: Iterator<Item = Result<T, E>> + Sized,
+    {
+        let mut errs = E2::default();
+
+        match self.errs_or_else(&mut errs, f) {
+            Ok(output) => Ok(output),
+            Err(_) => Err(errs),
+        }
+    }
+}
+
+impl<I> FirstErr for I where I: Iterator {}
+
+mod result {
+    use core::{
+        cmp::Ordering,
+        fmt,
+        iter::{Enumerate, FusedIterator},
+        marker::PhantomData,
+        mem,
+        ops::ControlFlow,
+        str::FromStr,
+    };
+
+    /// An `Iterator` can take first `Err` from another iterator.
+    ///
+    /// See [`FirstErr::first_err_or_else()`](crate::FirstErr::first_err_or_else) for more details.
+    pub struct FirstErrIter<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        state: State<I, T, E>,
+
+        /// The number of `Ok` values yielded.
+        ok_count: usize,
+
+        /// The number of items pulled from the inner iterator but not yielded, i.e. the `Err` items
+        /// (the taken ones included) and the `Ok` items drained by `check_rest()`.
+        skipped: usize,
+
+        /// The first `Err` item from the back, waiting for the items before it to be checked.
+        back_err: Option<E>,
+
+        /// The number of items pulled from the back.
+        back_count: usize,
+    }
+
+    impl<I, T, E> FirstErrIter<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        #[inline]
+        pub(super) fn first_err_or_else<O, F>(inner: I, f: F) -> Result<O, E>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self::new(inner);
+
+            let output = f(&mut me);
+
+            me.finish(output)
+        }
+
+        /// Creates a new `FirstErrIter` from the `inner` iterator, to use it outside of
+        /// [`FirstErr::first_err_or_else()`](crate::FirstErr::first_err_or_else).
+        ///
+        /// When used standalone, nothing checks the remaining items after this iterator is
+        /// dropped, so an `Err` item not yet consumed will be silently ignored. Use
+        /// [`check_rest()`](Self::check_rest) or [`into_parts()`](Self::into_parts) to handle them
+        /// explicitly.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErrIter;
+        /// #
+        /// # fn main() {
+        /// let mut iter = FirstErrIter::new([Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)].into_iter());
+        ///
+        /// assert_eq!(iter.by_ref().sum::<u8>(), 1);
+        /// assert_eq!(iter.peek_err(), Some(&2));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn new(inner: I) -> Self {
+            Self {
+                state: State::Active(inner),
+                ok_count: 0,
+                skipped: 0,
+                back_err: None,
+                back_count: 0,
+            }
+        }
+
+        /// Consumes this iterator, and returns the inner iterator with the found `Err` items.
+        ///
+        /// The returned parts are:
+        ///
+        /// 1. The inner iterator, `None` if it is exhausted or dropped by
+        ///    [`abort()`](Self::abort). If an `Err` item was found (or taken), it continues with
+        ///    the items after it.
+        /// 2. The first `Err` item found from the front, if not taken.
+        /// 3. The `Err` item found from the back by
+        ///    [`next_back()`](DoubleEndedIterator::next_back), if the front has not reached it
+        ///    yet. The inner iterator ends right before it.
+        ///
+        /// So the remaining items of the source are the inner iterator followed by the `Err` item
+        /// from the back.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErrIter;
+        /// #
+        /// # fn main() {
+        /// let mut iter = FirstErrIter::new([Ok::<u8, u8>(0), Err(1), Ok(2)].into_iter());
+        /// assert_eq!(iter.by_ref().sum::<u8>(), 0);
+        ///
+        /// let (inner, err, back_err) = iter.into_parts();
+        /// assert_eq!(err, Some(1));
+        /// assert_eq!(back_err, None);
+        ///
+        /// // Resume with the rest items.
+        /// let mut iter = FirstErrIter::new(inner.unwrap());
+        /// assert_eq!(iter.by_ref().sum::<u8>(), 2);
+        /// assert_eq!(iter.into_parts().1, None);
+        /// # }
+        /// ```
+        #[inline]
+        pub fn into_parts(self) -> (Option<I>, Option<E>, Option<E>) {
+            match self.state {
+                State::Active(inner) | State::ErrTaken(inner) => (Some(inner), None, self.back_err),
+                State::FoundFirstErr(inner, e) => (Some(inner), Some(e), self.back_err),
+                State::Exhausted | State::Aborted => (None, None, self.back_err),
+            }
+        }
+
+        /// Returns the number of `Ok` values yielded by this iterator so far.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         iter.for_each(drop);
+        ///         iter.ok_count()
+        ///     });
+        /// assert_eq!(result, Err(2));
+        ///
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         iter.for_each(drop);
+        ///         let ok_count = iter.ok_count();
+        ///         iter.take_err();
+        ///         (ok_count, iter.ok_count())
+        ///     });
+        /// assert_eq!(result, Ok((2, 2)));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn ok_count(&self) -> usize {
+            self.ok_count
+        }
+
+        /// Returns the number of items pulled from the inner iterator so far, both `Ok` and `Err`
+        /// items included.
+        ///
+        /// The items pulled from the back are also included. When nothing pulled from the back,
+        /// and this iterator has just stopped because of an `Err` item, the index of this `Err`
+        /// item in the inner iterator is `source_position() - 1`.
+        ///
+        /// The remaining items drained after the closure returned also advance the position, but
+        /// it can't be observed in the closure. Use
+        /// [`FirstErr::first_err_or_else_positioned()`](crate::FirstErr::first_err_or_else_positioned)
+        /// to get the final position.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         iter.for_each(drop);
+        ///         assert_eq!(iter.ok_count(), 2);
+        ///         assert_eq!(iter.source_position(), 3);
+        ///
+        ///         iter.take_err();
+        ///         assert_eq!(iter.check_rest(), Ok(()));
+        ///         iter.source_position()
+        ///     });
+        /// assert_eq!(result, Ok(4));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn source_position(&self) -> usize {
+            self.ok_count + self.skipped
+        }
+
+        /// Returns `true` if this iterator has stopped because of the first `Err` item.
+        ///
+        /// This is only meaningful after this iterator returned `None`. Before that, it is always
+        /// `false`.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         let sum = iter.sum::<u8>();
+        ///         (sum, iter.found_err())
+        ///     });
+        /// assert_eq!(result, Err(1));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn found_err(&self) -> bool {
+            matches!(self.state, State::FoundFirstErr(_, _))
+        }
+
+        /// Returns `true` if this iterator has stopped because the inner iterator ended, without
+        /// any `Err` item.
+        ///
+        /// This is only meaningful after this iterator returned `None`. Before that, it is always
+        /// `false`.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         let sum = iter.sum::<u8>();
+        ///         (sum, iter.is_exhausted())
+        ///     });
+        /// assert_eq!(result, Ok((3, true)));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn is_exhausted(&self) -> bool {
+            matches!(self.state, State::Exhausted)
+        }
+
+        /// Returns a reference to the first `Err` item, if this iterator has stopped because of it.
+        ///
+        /// The error stays in place, so the outer method still returns it.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         let sum = iter.sum::<u8>();
+        ///         assert_eq!(iter.peek_err(), Some(&2));
+        ///         sum
+        ///     });
+        /// assert_eq!(result, Err(2));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn peek_err(&self) -> Option<&E> {
+            match &self.state {
+                State::FoundFirstErr(_, e) => Some(e),
+                State::Active(_) | State::ErrTaken(_) => None,
+                State::Exhausted | State::Aborted => None,
+            }
+        }
+
+        /// Folds every remaining `Ok` value into an accumulator, until the first `Err` item or the
+        /// end.
+        ///
+        /// This is the same as [`Iterator::fold()`] but works on `&mut self`, and drives the
+        /// internal iteration of the inner iterator, so it is faster than calling `.next()` one
+        /// by one. The first `Err` item will still be reported by
+        /// [`FirstErr::first_err_or_else()`](crate::FirstErr::first_err_or_else).
+        ///
+        /// The other consuming methods of this iterator, such as `sum()` or `find()`, are driven
+        /// by `.next()`. Prefer this method when the inner iterator has a fast internal iteration.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| iter.fold_ok(0, |acc, n| acc + n));
+        /// assert_eq!(result, Err(2));
+        ///
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| iter.fold_ok(0, |acc, n| acc + n));
+        /// assert_eq!(result, Ok(6));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn fold_ok<B, F>(&mut self, init: B, f: F) -> B
+        where
+            F: FnMut(B, T) -> B,
+        {
+            let State::Active(inner) = &mut self.state else {
+                return init;
+            };
+
+            // Stop on the first `Err` item and stash it.
+            let mut first_err = None;
+            let mut ok_count = 0;
+            let acc = inner
+                .map_while(|res| match res {
+                    Ok(t) => {
+                        ok_count += 1;
+                        Some(t)
+                    }
+                    Err(e) => {
+                        first_err = Some(e);
+                        None
+                    }
+                })
+                .fold(init, f);
+
+            self.ok_count += ok_count;
+            self.stop(first_err);
+
+            acc
+        }
+
+        /// Takes the first `Err` item out, so it will not be returned by the outer method.
+        ///
+        /// Returns `None` if this iterator has not stopped because of an `Err` item, and nothing
+        /// changed in this case.
+        ///
+        /// This iterator stays stopped after the `Err` taken, it never produces the `Ok` values
+        /// after the taken one. But the remaining items will still be checked after the closure
+        /// returned, so the next `Err` item (if any) will be returned instead, otherwise the
+        /// output of the closure.
+        ///
+        /// It doesn't continue with the remaining items, because it has already returned `None`
+        /// at the taken `Err`, and it is a [`FusedIterator`]: producing values again would break
+        /// any `Fuse` or `by_ref()` adaptor built on it. To iterate the items after an `Err`, use
+        /// a standalone iterator by [`new()`](Self::new) and [`into_parts()`](Self::into_parts)
+        /// instead.
+        ///
+        /// To support this, the inner iterator is kept (not dropped) after the first `Err` item
+        /// found, until the outer method returned.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// // Handle the first `Err` inside the closure.
+        /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         let sum = iter.sum::<u8>();
+        ///         assert_eq!(iter.take_err(), Some(1));
+        ///         assert_eq!(iter.next(), None);
+        ///         sum
+        ///     });
+        /// assert_eq!(result, Ok(0));
+        ///
+        /// // The next `Err` will still be returned.
+        /// let result = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         let sum = iter.sum::<u8>();
+        ///         assert_eq!(iter.take_err(), Some(1));
+        ///         sum
+        ///     });
+        /// assert_eq!(result, Err(3));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn take_err(&mut self) -> Option<E> {
+            match mem::replace(&mut self.state, State::Exhausted) {
+                State::FoundFirstErr(inner, e) => {
+                    self.state = State::ErrTaken(inner);
+                    Some(e)
+                }
+                state => {
+                    self.state = state;
+                    None
+                }
+            }
+        }
+
+        /// Checks all the remaining items right now, and returns the first `Err` item by reference.
+        ///
+        /// The remaining `Ok` values are dropped. After it returns `Ok(())`, this iterator is
+        /// exhausted. After it returns `Err`, this iterator has stopped because of this `Err`
+        /// item, which will still be returned by the outer method.
+        ///
+        /// This is useful to do the expensive work only after the whole source is known to be
+        /// clean.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| match iter.check_rest() {
+        ///         Ok(()) => "expensive work",
+        ///         Err(_) => "unused",
+        ///     });
+        /// assert_eq!(result, Err(2));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn check_rest(&mut self) -> Result<(), &E> {
+            if let State::Active(inner) | State::ErrTaken(inner) = &mut self.state {
+                let mut drained = 0;
+                let first_err = inner.find_map(|res| match res {
+                    Ok(_) => {
+                        drained += 1;
+                        None
+                    }
+                    Err(e) => Some(e),
+                });
+
+                self.skipped += drained;
+                self.stop(first_err);
+            }
+
+            match &self.state {
+                State::FoundFirstErr(_, e) => Err(e),
+                State::Active(_) | State::ErrTaken(_) => Ok(()),
+                State::Exhausted | State::Aborted => Ok(()),
+            }
+        }
+
+        /// Stops this iterator, and skips checking the remaining items after the closure returned.
+        ///
+        /// The remaining items are dropped with the inner iterator without being checked, so the
+        /// outer method returns the `Ok` output even if an `Err` item lies beyond this point. This
+        /// explicitly weakens the guarantee of finding the first `Err` item anywhere in the
+        /// source, in exchange for not paying for the draining of a long source.
+        ///
+        /// The `Err` items already found are not affected. If this iterator has already stopped
+        /// because of an `Err` item, nothing changed and this `Err` will still be returned. But an
+        /// `Err` item found from the back by [`next_back()`](DoubleEndedIterator::next_back) is
+        /// dropped if the items before it are not checked yet, because it can't be known as the
+        /// first one without checking them.
+        ///
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```rust
+        /// # use first_err::FirstErr;
+        /// #
+        /// # fn main() {
+        /// let result = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+        ///     .into_iter()
+        ///     .first_err_or_else(|iter| {
+        ///         if iter.next() == Some(0) {
+        ///             iter.abort();
+        ///             return "pointless";
+        ///         }
+        ///         "unused"
+        ///     });
+        /// assert_eq!(result, Ok("pointless"));
+        /// # }
+        /// ```
+        #[inline]
+        pub fn abort(&mut self) {
+            if let State::Active(_) | State::ErrTaken(_) = self.state {
+                self.state = State::Aborted;
+                self.back_err = None;
+            }
+        }
+
+        /// Stop this iterator at the `first_err` from the front, or when the front is exhausted if
+        /// it is `None`.
+        ///
+        /// The inner iterator is kept with the `Err` item, so the remaining items can still be
+        /// checked after [`take_err()`](Self::take_err).
+        #[inline]
+        fn stop(&mut self, first_err: Option<E>) {
+            self.state = match (mem::replace(&mut self.state, State::Exhausted), first_err) {
+                (State::Active(inner) | State::ErrTaken(inner), Some(e)) => {
+                    self.skipped += 1;
+                    State::FoundFirstErr(inner, e)
+                }
+                // The front reached the `Err` found from the back, so it is the first one. Count it
+                // as pulled from the front, to keep the position of it.
+                (State::Active(inner) | State::ErrTaken(inner), None) => match self.back_err.take()
+                {
+                    Some(e) => {
+                        self.back_count -= 1;
+                        State::FoundFirstErr(inner, e)
+                    }
+                    None => State::Exhausted,
+                },
+                (_, _) => State::Exhausted,
+            };
+        }
+
+        #[inline]
+        pub(super) fn first_err_or_else_positioned<O, F>(
+            inner: I,
+            f: F,
+        ) -> Result<(O, usize), (E, usize)>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self::new(inner);
+
+            let output = f(&mut me);
+
+            // Same as `finish()`, but keep advancing the position from the front.
+            let mut front = me.source_position() - me.back_count;
+            match me.state {
+                State::Active(inner) | State::ErrTaken(inner) => {
+                    for res in inner {
+                        front += 1;
+                        if let Err(e) = res {
+                            return Err((e, front - 1));
+                        }
+                    }
+                    match me.back_err {
+                        Some(e) => Err((e, front)),
+                        None => Ok((output, front + me.back_count)),
+                    }
+                }
+                State::Exhausted | State::Aborted => Ok((output, front + me.back_count)),
+                State::FoundFirstErr(_, e) => Err((e, front - 1)),
+            }
+        }
+
+        /// Consume the remaining items, then return the first `Err` or the `output`.
+        #[inline]
+        fn finish<O>(self, output: O) -> Result<O, E> {
+            // Take first err, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match self.state {
+                State::Active(inner) | State::ErrTaken(inner) => {
+                    for res in inner {
+                        let _ = res?;
+                    }
+                    // No `Err` before the one found from the back.
+                    match self.back_err {
+                        Some(e) => Err(e),
+                        None => Ok(output),
+                    }
+                }
+                State::Exhausted | State::Aborted => Ok(output),
+                State::FoundFirstErr(_, e) => Err(e),
+            }
+        }
+    }
+
+    /// Only print the name of the current state, so no `Debug` bound is required on `I`, `T` or
+    /// `E`.
+    impl<I, T, E> fmt::Debug for FirstErrIter<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let state = match &self.state {
+                State::Active(_) => "Active",
+                State::FoundFirstErr(_, _) => "FoundFirstErr",
+                State::ErrTaken(_) => "ErrTaken",
+                State::Exhausted => "Exhausted",
+                State::Aborted => "Aborted",
+            };
+
+            f.debug_struct("FirstErrIter")
+                .field("state", &format_args!("{state}"))
+                .field("ok_count", &self.ok_count)
+                .finish()
+        }
+    }
+
+    impl<I, T, E> Iterator for FirstErrIter<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next() {
+                    Some(Ok(t)) => {
+                        self.ok_count += 1;
+                        Some(t)
+                    }
+                    Some(Err(e)) => {
+                        self.stop(Some(e));
+                        None
+                    }
+                    None => {
+                        self.stop(None);
+                        None
+                    }
+                },
+                State::FoundFirstErr(_, _) | State::ErrTaken(_) => None,
+                State::Exhausted | State::Aborted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => inner.size_hint(),
+                State::FoundFirstErr(_, _) | State::ErrTaken(_) => (0, Some(0)),
+                State::Exhausted | State::Aborted => (0, Some(0)),
+            }
+        }
+
+        /// Skip `n` values by the internal iteration of the inner iterator.
+        ///
+        /// The inner `nth()` can't be used directly, because the skipped items must still be
+        /// checked, an `Err` item in the skipped range will stop this iterator as `next()` does.
+        #[inline]
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            let State::Active(inner) = &mut self.state else {
+                return None;
+            };
+
+            let flow = inner.try_fold(n, |remaining, res| match res {
+                Ok(t) if remaining == 0 => ControlFlow::Break(Ok(t)),
+                Ok(_) => ControlFlow::Continue(remaining - 1),
+                Err(e) => ControlFlow::Break(Err((remaining, e))),
+            });
+
+            match flow {
+                ControlFlow::Break(Ok(t)) => {
+                    self.ok_count += n + 1;
+                    Some(t)
+                }
+                ControlFlow::Break(Err((remaining, e))) => {
+                    self.ok_count += n - remaining;
+                    self.stop(Some(e));
+                    None
+                }
+                ControlFlow::Continue(remaining) => {
+                    self.ok_count += n - remaining;
+                    self.stop(None);
+                    None
+                }
+            }
+        }
+    }
+
+    /// Yields the `Ok` values from the back, and stops at the first `Err` item from the back.
+    ///
+    /// The first `Err` reported by the outer method is still the earliest one in forward order.
+    /// The `Err` item found from the back is kept aside, and the front keeps yielding the `Ok`
+    /// values before it. It becomes the first `Err` only when the items before it are checked
+    /// without any other `Err` item (by `next()` reaching it, by
+    /// [`check_rest()`](FirstErrIter::check_rest) or by the draining after the closure returned).
+    /// Before that, [`found_err()`](FirstErrIter::found_err) and
+    /// [`peek_err()`](FirstErrIter::peek_err) don't report it.
+    ///
+    /// Note that `next_back()` may have produced some `Ok` values after the earliest `Err` item,
+    /// because the items before them are not checked yet.
+    impl<I, T, E> DoubleEndedIterator for FirstErrIter<I, T, E>
+    where
+        I: DoubleEndedIterator<Item = Result<T, E>>,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            let State::Active(inner) = &mut self.state else {
+                return None;
+            };
+            // The back has stopped at an `Err` item.
+            if self.back_err.is_some() {
+                return None;
+            }
+
+            match inner.next_back() {
+                Some(Ok(t)) => {
+                    self.ok_count += 1;
+                    self.back_count += 1;
+                    Some(t)
+                }
+                Some(Err(e)) => {
+                    self.skipped += 1;
+                    self.back_count += 1;
+                    self.back_err = Some(e);
+                    None
+                }
+                None => {
+                    self.stop(None);
+                    None
+                }
+            }
+        }
+    }
+
+    /// Clones the current state, including the inner iterator and a found `Err`.
+    ///
+    /// The clone is independent: consuming it does not affect the original one. And the result
+    /// of [`FirstErr::first_err_or_else()`](crate::FirstErr::first_err_or_else) only depends on
+    /// the original one (the argument of the closure), so an `Err` found by the clone will not be
+    /// reported, unless the original one also found it (or it is found when draining the
+    /// original one).
+    impl<I, T, E> Clone for FirstErrIter<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>> + Clone,
+        E: Clone,
+    {
+        #[inline]
+        fn clone(&self) -> Self {
+            let state = match &self.state {
+                State::Active(inner) => State::Active(inner.clone()),
+                State::FoundFirstErr(inner, e) => State::FoundFirstErr(inner.clone(), e.clone()),
+                State::ErrTaken(inner) => State::ErrTaken(inner.clone()),
+                State::Exhausted => State::Exhausted,
+                State::Aborted => State::Aborted,
+            };
+
+            Self {
+                state,
+                ok_count: self.ok_count,
+                skipped: self.skipped,
+                back_err: self.back_err.clone(),
+                back_count: self.back_count,
+            }
+        }
+    }
+
+    impl<I, T, E> FusedIterator for FirstErrIter<I, T, E> where I: Iterator<Item = Result<T, E>> {}
+
+    /// A [`FirstErrIter`] which can peek the next `Ok` value.
+    ///
+    /// See [`FirstErr::first_err_or_else_peekable()`](crate::FirstErr::first_err_or_else_peekable)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstErrPeekable<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        iter: FirstErrIter<I, T, E>,
+
+        /// Remember a peeked value, even if it was `None`.
+        peeked: Option<Option<T>>,
+    }
+
+    impl<I, T, E> FirstErrPeekable<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        #[inline]
+        pub(super) fn first_err_or_else_peekable<O, F>(inner: I, f: F) -> Result<O, E>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                iter: FirstErrIter::new(inner),
+                peeked: None,
+            };
+
+            let output = f(&mut me);
+
+            // A peeked but unconsumed value must be an `Ok` value, just drop it.
+            me.iter.finish(output)
+        }
+
+        /// Returns a reference to the next `Ok` value without advancing the iterator.
+        ///
+        /// Returns `None` when the source is exhausted or the next item is the first `Err`.
+        #[inline]
+        pub fn peek(&mut self) -> Option<&T> {
+            let iter = &mut self.iter;
+            self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+        }
+
+        /// Returns a mutable reference to the next `Ok` value without advancing the iterator.
+        ///
+        /// Returns `None` when the source is exhausted or the next item is the first `Err`.
+        #[inline]
+        pub fn peek_mut(&mut self) -> Option<&mut T> {
+            let iter = &mut self.iter;
+            self.peeked.get_or_insert_with(|| iter.next()).as_mut()
+        }
+
+        /// Consume and return the next `Ok` value if `func` returns `true` for it.
+        #[inline]
+        pub fn next_if(&mut self, func: impl FnOnce(&T) -> bool) -> Option<T> {
+            match self.next() {
+                Some(t) if func(&t) => Some(t),
+                other => {
+                    self.peeked = Some(other);
+                    None
+                }
+            }
+        }
+
+        /// Consume and return the next `Ok` value if it is equal to `expected`.
+        #[inline]
+        pub fn next_if_eq<U>(&mut self, expected: &U) -> Option<T>
+        where
+            U: ?Sized,
+            T: PartialEq<U>,
+        {
+            self.next_if(|t| t == expected)
+        }
+    }
+
+    impl<I, T, E> Iterator for FirstErrPeekable<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.peeked.take() {
+                Some(peeked) => peeked,
+                None => self.iter.next(),
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let peek_len = match self.peeked {
+                Some(None) => return (0, Some(0)),
+                Some(Some(_)) => 1,
+                None => 0,
+            };
+            let (lower, upper) = self.iter.size_hint();
+            (
+                lower.saturating_add(peek_len),
+                upper.and_then(|n| n.checked_add(peek_len)),
+            )
+        }
+    }
+
+    impl<I, T, E> FusedIterator for FirstErrPeekable<I, T, E> where I: Iterator<Item = Result<T, E>> {}
+
+    /// A [`FirstErrIter`] which producing owned `Ok` values from an iterator of `&Result<T, E>`.
+    ///
+    /// See [`FirstErr::first_err_copied_or_else()`](crate::FirstErr::first_err_copied_or_else)
+    /// and [`FirstErr::first_err_cloned_or_else()`](crate::FirstErr::first_err_cloned_or_else)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstErrOwned<'a, I, T, E>
+    where
+        I: Iterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: 'a,
+    {
+        iter: FirstErrIter<RefResults<I>, &'a T, &'a E>,
+    }
+
+    impl<'a, I, T, E> FirstErrOwned<'a, I, T, E>
+    where
+        I: Iterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: 'a,
+    {
+        #[inline]
+        pub(super) fn first_err_or_else_owned<O, F>(inner: I, f: F) -> Result<O, &'a E>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                iter: FirstErrIter::new(RefResults::new(inner)),
+            };
+
+            let output = f(&mut me);
+
+            me.iter.finish(output)
+        }
+
+        /// Same as [`FirstErrIter::ok_count()`].
+        #[inline]
+        pub fn ok_count(&self) -> usize {
+            self.iter.ok_count()
+        }
+
+        /// Same as [`FirstErrIter::source_position()`].
+        #[inline]
+        pub fn source_position(&self) -> usize {
+            self.iter.source_position()
+        }
+
+        /// Same as [`FirstErrIter::found_err()`].
+        #[inline]
+        pub fn found_err(&self) -> bool {
+            self.iter.found_err()
+        }
+
+        /// Same as [`FirstErrIter::is_exhausted()`].
+        #[inline]
+        pub fn is_exhausted(&self) -> bool {
+            self.iter.is_exhausted()
+        }
+
+        /// Same as [`FirstErrIter::peek_err()`], the error is borrowed from the source data.
+        #[inline]
+        pub fn peek_err(&self) -> Option<&'a E> {
+            self.iter.peek_err().copied()
+        }
+
+        /// Same as [`FirstErrIter::fold_ok()`], only the folded values are cloned.
+        #[inline]
+        pub fn fold_ok<B, F>(&mut self, init: B, mut f: F) -> B
+        where
+            F: FnMut(B, T) -> B,
+            T: Clone,
+        {
+            self.iter.fold_ok(init, |acc, t| f(acc, t.clone()))
+        }
+
+        /// Same as [`FirstErrIter::take_err()`], the taken error is never cloned.
+        #[inline]
+        pub fn take_err(&mut self) -> Option<&'a E> {
+            self.iter.take_err()
+        }
+
+        /// Same as [`FirstErrIter::check_rest()`], the error is borrowed from the source data.
+        #[inline]
+        pub fn check_rest(&mut self) -> Result<(), &'a E> {
+            self.iter.check_rest().map_err(|e| *e)
+        }
+
+        /// Same as [`FirstErrIter::abort()`].
+        #[inline]
+        pub fn abort(&mut self) {
+            self.iter.abort()
+        }
+    }
+
+    impl<'a, I, T, E> Iterator for FirstErrOwned<'a, I, T, E>
+    where
+        I: Iterator<Item = &'a Result<T, E>>,
+        T: 'a + Clone,
+        E: 'a,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.iter.next().cloned()
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.iter.size_hint()
+        }
+    }
+
+    impl<'a, I, T, E> DoubleEndedIterator for FirstErrOwned<'a, I, T, E>
+    where
+        I: DoubleEndedIterator<Item = &'a Result<T, E>>,
+        T: 'a + Clone,
+        E: 'a,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            self.iter.next_back().cloned()
+        }
+    }
+
+    impl<'a, I, T, E> FusedIterator for FirstErrOwned<'a, I, T, E>
+    where
+        I: Iterator<Item = &'a Result<T, E>>,
+        T: 'a + Clone,
+        E: 'a,
+    {
+    }
+
+    /// A [`FirstErrIter`] which producing `Ok` values chunk by chunk.
+    ///
+    /// See [`FirstErr::first_err_or_else_chunks()`](crate::FirstErr::first_err_or_else_chunks)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstErrChunks<I, T, E, const N: usize>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        iter: FirstErrIter<I, T, E>,
+
+        /// Length of the partial chunk, if the source stopped in the middle of a chunk.
+        partial: Option<usize>,
+    }
+
+    impl<I, T, E, const N: usize> FirstErrChunks<I, T, E, N>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        #[inline]
+        pub(super) fn first_err_or_else_chunks<O, F>(inner: I, f: F) -> Result<O, ChunkError<E>>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            const { assert!(N != 0, "chunk size must be non-zero") };
+
+            let mut me = Self {
+                iter: FirstErrIter::new(inner),
+                partial: None,
+            };
+
+            let output = f(&mut me);
+
+            // The rest `Ok` values decide whether the last chunk is a partial one. If the
+            // closure already met the partial chunk, nothing left here.
+            let rest = (&mut me.iter).count();
+            let partial = me.partial.unwrap_or(rest % N);
+
+            // `Err` takes priority over the partial chunk.
+            me.iter.finish(()).map_err(ChunkError::Err)?;
+
+            match partial {
+                0 => Ok(output),
+                len => Err(ChunkError::Partial(len)),
+            }
+        }
+    }
+
+    impl<I, T, E, const N: usize> Iterator for FirstErrChunks<I, T, E, N>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        type Item = [T; N];
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match crate::array::try_from_fn(|_| self.iter.next()) {
+                Ok(chunk) => Some(chunk),
+                Err(0) => None,
+                Err(len) => {
+                    self.partial = Some(len);
+                    None
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let (lower, upper) = self.iter.size_hint();
+            (lower / N, upper.map(|n| n / N))
+        }
+    }
+
+    impl<I, T, E, const N: usize> FusedIterator for FirstErrChunks<I, T, E, N> where
+        I: Iterator<Item = Result<T, E>>
+    {
+    }
+
+    /// The error type of
+    /// [`FirstErr::first_err_or_else_chunks()`](crate::FirstErr::first_err_or_else_chunks).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ChunkError<E> {
+        /// The first `Err` item in the source iterator.
+        Err(E),
+
+        /// The `Ok` values can't fill up the last chunk, contains the length of this partial
+        /// chunk.
+        Partial(usize),
+    }
+
+    /// The error type of
+    /// [`FirstErr::try_collect_array()`](crate::FirstErr::try_collect_array).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ArrayCollectError<E> {
+        /// The first `Err` item in the source iterator, and its index.
+        Err { index: usize, error: E },
+
+        /// The source iterator ended before producing enough items, contains the count of the
+        /// `Ok` values already got.
+        TooShort { got: usize },
+    }
+
+    /// A [`FirstErrIter`] which producing the flattened `Ok` values.
+    ///
+    /// See [`FirstErr::first_err_or_else_flat()`](crate::FirstErr::first_err_or_else_flat)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstErrFlat<I, II, E>
+    where
+        I: Iterator<Item = Result<II, E>>,
+        II: IntoIterator,
+    {
+        iter: FirstErrIter<I, II, E>,
+
+        /// The `Ok` value which is iterating now.
+        front: Option<II::IntoIter>,
+    }
+
+    impl<I, II, E> FirstErrFlat<I, II, E>
+    where
+        I: Iterator<Item = Result<II, E>>,
+        II: IntoIterator,
+    {
+        #[inline]
+        pub(super) fn first_err_or_else_flat<O, F>(inner: I, f: F) -> Result<O, E>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                iter: FirstErrIter::new(inner),
+                front: None,
+            };
+
+            let output = f(&mut me);
+
+            // Only check the outer items, the rest elements in `front` are ignored.
+            me.iter.finish(output)
+        }
+    }
+
+    impl<I, II, E> Iterator for FirstErrFlat<I, II, E>
+    where
+        I: Iterator<Item = Result<II, E>>,
+        II: IntoIterator,
+    {
+        type Item = II::Item;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(front) = &mut self.front {
+                    match front.next() {
+                        Some(item) => return Some(item),
+                        None => self.front = None,
+                    }
+                }
+
+                self.front = Some(self.iter.next()?.into_iter());
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let (lower, upper) = match &self.front {
+                Some(front) => front.size_hint(),
+                None => (0, Some(0)),
+            };
+
+            match self.iter.size_hint() {
+                (_, Some(0)) => (lower, upper),
+                _ => (lower, None),
+            }
+        }
+    }
+
+    impl<I, II, E> FusedIterator for FirstErrFlat<I, II, E>
+    where
+        I: Iterator<Item = Result<II, E>>,
+        II: IntoIterator,
+    {
+    }
+
+    /// An `Iterator` which producing all `Ok` values from another iterator, and pushing all
+    /// `Err`s into a sink.
+    ///
+    /// See [`FirstErr::errs_or_else()`](crate::FirstErr::errs_or_else) for more details.
+    #[derive(Debug)]
+    pub struct ErrsIter<'a, I, X> {
+        /// `None` if the inner iterator is exhausted.
+        inner: Option<I>,
+        sink: &'a mut X,
+        count: usize,
+    }
+
+    impl<'a, I, X> ErrsIter<'a, I, X> {
+        #[inline]
+        pub(super) fn errs_or_else<T, E, O, F>(inner: I, sink: &'a mut X, f: F) -> Result<O, usize>
+        where
+            I: Iterator<Item = Result<T, E>>,
+            X: Extend<E>,
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                inner: Some(inner),
+                sink,
+                count: 0,
+            };
+
+            let output = f(&mut me);
+
+            // Push all the rest `Err`s into sink.
+            me.by_ref().for_each(drop);
+
+            match me.count {
+                0 => Ok(output),
+                count => Err(count),
+            }
+        }
+    }
+
+    impl<I, X, T, E> Iterator for ErrsIter<'_, I, X>
+    where
+        I: Iterator<Item = Result<T, E>>,
+        X: Extend<E>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let inner = self.inner.as_mut()?;
+
+            loop {
+                match inner.next() {
+                    Some(Ok(t)) => return Some(t),
+                    Some(Err(e)) => {
+                        self.sink.extend(core::iter::once(e));
+                        self.count += 1;
+                    }
+                    None => {
+                        self.inner = None;
+                        return None;
+                    }
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, X, T, E> FusedIterator for ErrsIter<'_, I, X>
+    where
+        I: Iterator<Item = Result<T, E>>,
+        X: Extend<E>,
+    {
+    }
+
+    /// Types which have no value, such as [`Infallible`](core::convert::Infallible).
+    ///
+    /// This trait is sealed and cannot be implemented outside of this crate.
+    pub trait Never: sealed::Sealed {
+        /// Converts an impossible value into any type.
+        fn never<U>(self) -> U;
+    }
+
+    impl Never for core::convert::Infallible {
+        #[inline]
+        fn never<U>(self) -> U {
+            match self {}
+        }
+    }
+
+    #[cfg(feature = "nightly-never")]
+    impl Never for ! {
+        #[inline]
+        fn never<U>(self) -> U {
+            self
+        }
+    }
+
+    mod sealed {
+        pub trait Sealed {}
+
+        impl Sealed for core::convert::Infallible {}
+
+        #[cfg(feature = "nightly-never")]
+        impl Sealed for ! {}
+    }
+
+    /// An `Iterator` which unwraps every item from another iterator, which can never produce any
+    /// `Err` item.
+    ///
+    /// See [`FirstErr::into_ok_iter()`](crate::FirstErr::into_ok_iter) for more details.
+    #[derive(Debug, Clone)]
+    pub struct IntoOkIter<I> {
+        inner: I,
+    }
+
+    impl<I> IntoOkIter<I> {
+        #[inline]
+        pub(super) fn new(inner: I) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<I, T, N> Iterator for IntoOkIter<I>
+    where
+        I: Iterator<Item = Result<T, N>>,
+        N: Never,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().map(|res| res.unwrap_or_else(N::never))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, T, N> DoubleEndedIterator for IntoOkIter<I>
+    where
+        I: DoubleEndedIterator<Item = Result<T, N>>,
+        N: Never,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            self.inner
+                .next_back()
+                .map(|res| res.unwrap_or_else(N::never))
+        }
+    }
+
+    impl<I, T, N> ExactSizeIterator for IntoOkIter<I>
+    where
+        I: ExactSizeIterator<Item = Result<T, N>>,
+        N: Never,
+    {
+    }
+
+    impl<I, T, N> FusedIterator for IntoOkIter<I>
+    where
+        I: FusedIterator<Item = Result<T, N>>,
+        N: Never,
+    {
+    }
+
+    /// An `Iterator` which offers every `Err` item to a recover function.
+    ///
+    /// See [`FirstErr::first_err_or_else_recover()`](crate::FirstErr::first_err_or_else_recover)
+    /// for more details.
+    pub struct RecoverErr<I, R> {
+        inner: I,
+        recover: R,
+    }
+
+    impl<I, R> RecoverErr<I, R> {
+        #[inline]
+        pub(super) fn new(inner: I, recover: R) -> Self {
+            Self { inner, recover }
+        }
+    }
+
+    impl<I, R> fmt::Debug for RecoverErr<I, R>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("RecoverErr")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, R, T, E> Iterator for RecoverErr<I, R>
+    where
+        I: Iterator<Item = Result<T, E>>,
+        R: FnMut(E) -> Result<T, E>,
+    {
+        type Item = Result<T, E>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().map(|res| res.or_else(&mut self.recover))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, R, T, E> FusedIterator for RecoverErr<I, R>
+    where
+        I: FusedIterator<Item = Result<T, E>>,
+        R: FnMut(E) -> Result<T, E>,
+    {
+    }
+
+    /// An `Iterator` which turns every `&Result<T, E>` item into `Result<&T, &E>`.
+    ///
+    /// See [`FirstErr::first_err_ref_or_else()`](crate::FirstErr::first_err_ref_or_else) for more
+    /// details.
+    #[derive(Debug, Clone)]
+    pub struct RefResults<I> {
+        inner: I,
+    }
+
+    impl<I> RefResults<I> {
+        #[inline]
+        pub(super) fn new(inner: I) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<'a, I, T, E> Iterator for RefResults<I>
+    where
+        I: Iterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: 'a,
+    {
+        type Item = Result<&'a T, &'a E>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().map(Result::as_ref)
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<'a, I, T, E> DoubleEndedIterator for RefResults<I>
+    where
+        I: DoubleEndedIterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: 'a,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            self.inner.next_back().map(Result::as_ref)
+        }
+    }
+
+    impl<'a, I, T, E> ExactSizeIterator for RefResults<I>
+    where
+        I: ExactSizeIterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: 'a,
+    {
+    }
+
+    impl<'a, I, T, E> FusedIterator for RefResults<I>
+    where
+        I: FusedIterator<Item = &'a Result<T, E>>,
+        T: 'a,
+        E: 'a,
+    {
+    }
+
+    /// An `Iterator` which parses every `&str` item, and pairs the parse error with its index.
+    ///
+    /// See [`FirstErr::parse_all_or_first_err()`](crate::FirstErr::parse_all_or_first_err) for
+    /// more details.
+    pub struct ParseAll<I, T> {
+        inner: Enumerate<I>,
+        _marker: PhantomData<fn() -> T>,
+    }
+
+    impl<I, T> ParseAll<I, T>
+    where
+        I: Iterator,
+    {
+        #[inline]
+        pub(super) fn new(inner: I) -> Self {
+            Self {
+                inner: inner.enumerate(),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<I, T> fmt::Debug for ParseAll<I, T>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("ParseAll")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, T> Clone for ParseAll<I, T>
+    where
+        I: Clone,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, I, T> Iterator for ParseAll<I, T>
+    where
+        I: Iterator<Item = &'a str>,
+        T: FromStr,
+    {
+        type Item = Result<T, (usize, T::Err)>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let (i, s) = self.inner.next()?;
+            Some(s.parse().map_err(|e| (i, e)))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<'a, I, T> DoubleEndedIterator for ParseAll<I, T>
+    where
+        I: DoubleEndedIterator<Item = &'a str> + ExactSizeIterator,
+        T: FromStr,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            let (i, s) = self.inner.next_back()?;
+            Some(s.parse().map_err(|e| (i, e)))
+        }
+    }
+
+    impl<'a, I, T> ExactSizeIterator for ParseAll<I, T>
+    where
+        I: ExactSizeIterator<Item = &'a str>,
+        T: FromStr,
+    {
+    }
+
+    impl<'a, I, T> FusedIterator for ParseAll<I, T>
+    where
+        I: FusedIterator<Item = &'a str>,
+        T: FromStr,
+    {
+    }
+
+    /// Walk two iterators in lockstep, and return the first `Err` at or before the position
+    /// where the comparison is decided. At the same position, `a` is checked before `b`.
+    ///
+    /// `f` decides whether to stop at each pair of `Ok` values. If it never stops, returns
+    /// the ordering of their lengths.
+    #[inline]
+    pub(super) fn iter_compare<A, B, T, E, R, F>(
+        mut a: A,
+        mut b: B,
+        mut f: F,
+    ) -> Result<ControlFlow<R, Ordering>, E>
+    where
+        A: Iterator<Item = Result<T, E>>,
+        B: Iterator<Item = Result<T, E>>,
+        F: FnMut(T, T) -> ControlFlow<R>,
+    {
+        loop {
+            let a_value = match a.next() {
+                Some(res) => res?,
+                None => {
+                    return match b.next() {
+                        Some(res) => res.map(|_| ControlFlow::Continue(Ordering::Less)),
+                        None => Ok(ControlFlow::Continue(Ordering::Equal)),
+                    };
+                }
+            };
+
+            let b_value = match b.next() {
+                Some(res) => res?,
+                None => return Ok(ControlFlow::Continue(Ordering::Greater)),
+            };
+
+            if let ControlFlow::Break(r) = f(a_value, b_value) {
+                return Ok(ControlFlow::Break(r));
+            }
+        }
+    }
+
+    /// An `Iterator` can take first failure, an `Err` or an `Ok(None)`, from another iterator.
+    ///
+    /// See [`FirstErr::first_failure_or_else()`](crate::FirstErr::first_failure_or_else) for
+    /// more details.
+    #[derive(Debug)]
+    pub struct FirstFailureIter<I, T, E>
+    where
+        I: Iterator<Item = Result<Option<T>, E>>,
+    {
+        state: FailureState<I, E>,
+    }
+
+    impl<I, T, E> FirstFailureIter<I, T, E>
+    where
+        I: Iterator<Item = Result<Option<T>, E>>,
+    {
+        #[inline]
+        pub(super) fn first_failure_or_else<O, F>(inner: I, f: F) -> Result<Option<O>, E>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                state: FailureState::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first failure, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                FailureState::Active(inner) => {
+                    for res in inner {
+                        if res?.is_none() {
+                            return Ok(None);
+                        }
+                    }
+                    Ok(Some(output))
+                }
+                FailureState::Exhausted => Ok(Some(output)),
+                FailureState::FoundFirstErr(e) => Err(e),
+                FailureState::FoundFirstNone => Ok(None),
+            }
+        }
+    }
+
+    impl<I, T, E> Iterator for FirstFailureIter<I, T, E>
+    where
+        I: Iterator<Item = Result<Option<T>, E>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                FailureState::Active(inner) => match inner.next() {
+                    Some(Ok(Some(t))) => Some(t),
+                    Some(Ok(None)) => {
+                        self.state = FailureState::FoundFirstNone;
+                        None
+                    }
+                    Some(Err(e)) => {
+                        self.state = FailureState::FoundFirstErr(e);
+                        None
+                    }
+                    None => {
+                        self.state = FailureState::Exhausted;
+                        None
+                    }
+                },
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                FailureState::Active(inner) => (0, inner.size_hint().1),
+                _ => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T, E> FusedIterator for FirstFailureIter<I, T, E> where
+        I: Iterator<Item = Result<Option<T>, E>>
+    {
+    }
+
+    /// Internal state of [`FirstFailureIter`].
+    #[derive(Debug)]
+    enum FailureState<I, E> {
+        Active(I),
+        FoundFirstErr(E),
+        FoundFirstNone,
+        Exhausted,
+    }
+
+    /// An `Iterator` which turns the `Err` items into `None`, and `Ok` values into `Some`.
+    ///
+    /// See [`FirstErr::err_into_none()`](crate::FirstErr::err_into_none) for more details.
+    #[derive(Debug)]
+    pub struct ErrIntoNone<'a, I, E> {
+        inner: I,
+
+        /// Where to put the first dropped error, taken after used.
+        slot: Option<&'a mut Option<E>>,
+    }
+
+    impl<'a, I, E> ErrIntoNone<'a, I, E> {
+        #[inline]
+        pub(super) fn new(inner: I, slot: Option<&'a mut Option<E>>) -> Self {
+            Self { inner, slot }
+        }
+    }
+
+    impl<I, T, E> Iterator for ErrIntoNone<'_, I, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        type Item = Option<T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.inner.next()? {
+                Ok(t) => Some(Some(t)),
+                Err(e) => {
+                    if let Some(slot) = self.slot.take() {
+                        *slot = Some(e);
+                    }
+                    Some(None)
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, T, E> FusedIterator for ErrIntoNone<'_, I, E> where I: FusedIterator<Item = Result<T, E>> {}
+
+    /// An error type which has a severity level, can be used in
+    /// [`FirstErr::worst_err_or_else()`](crate::FirstErr::worst_err_or_else).
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::Severity;
+    /// #
+    /// # fn main() {
+    /// #[derive(Debug, PartialEq)]
+    /// enum Error {
+    ///     Warning(u8),
+    ///     Fatal(u8),
+    /// }
+    ///
+    /// impl Severity for Error {
+    ///     type Level = u8;
+    ///
+    ///     fn severity(&self) -> u8 {
+    ///         match self {
+    ///             Error::Warning(_) => 1,
+    ///             Error::Fatal(_) => 2,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(Error::Fatal(0).severity() > Error::Warning(0).severity());
+    /// # }
+    /// ```
+    pub trait Severity {
+        /// The type of severity level, a bigger level is more severe.
+        type Level: Ord;
+
+        /// Returns the severity level of `self`.
+        fn severity(&self) -> Self::Level;
+    }
+
+    /// An `Iterator` which producing all `Ok` values from another iterator, and keeping the most
+    /// severe `Err`.
+    ///
+    /// See [`FirstErr::worst_err_or_else()`](crate::FirstErr::worst_err_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct WorstErrIter<I, E> {
+        /// `None` if the inner iterator is exhausted.
+        inner: Option<I>,
+        worst: Option<E>,
+    }
+
+    impl<I, E> WorstErrIter<I, E> {
+        #[inline]
+        pub(super) fn worst_err_or_else<T, O, F>(inner: I, f: F) -> Result<O, E>
+        where
+            I: Iterator<Item = Result<T, E>>,
+            E: Severity,
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                inner: Some(inner),
+                worst: None,
+            };
+
+            let output = f(&mut me);
+
+            // Check all the rest `Err`s.
+            me.by_ref().for_each(drop);
+
+            match me.worst {
+                None => Ok(output),
+                Some(e) => Err(e),
+            }
+        }
+    }
+
+    impl<I, T, E> Iterator for WorstErrIter<I, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+        E: Severity,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let inner = self.inner.as_mut()?;
+
+            loop {
+                match inner.next() {
+                    Some(Ok(t)) => return Some(t),
+                    Some(Err(e)) => match &self.worst {
+                        // Only a strictly more severe one can replace the earlier one.
+                        Some(worst) if e.severity() <= worst.severity() => {}
+                        _ => self.worst = Some(e),
+                    },
+                    None => {
+                        self.inner = None;
+                        return None;
+                    }
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T, E> FusedIterator for WorstErrIter<I, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+        E: Severity,
+    {
+    }
+
+    /// Internal state of [`FirstErrIter`].
+    #[derive(Debug)]
+    enum State<I, T, E>
+    where
+        I: Iterator<Item = Result<T, E>>,
+    {
+        Active(I),
+
+        /// Keep the inner iterator for [`FirstErrIter::take_err()`].
+        FoundFirstErr(I, E),
+
+        /// The first `Err` is taken, the remaining items are only checked after the closure
+        /// returned.
+        ErrTaken(I),
+        Exhausted,
+
+        /// Stopped by [`FirstErrIter::abort()`], the remaining items are not checked.
+        Aborted,
+    }
+}
+
+mod option {
+    use core::{
+        cmp::Ordering,
+        fmt,
+        iter::{Enumerate, FusedIterator},
+        ops::ControlFlow,
+    };
+
+    /// An `Iterator` can take first `None` from another iterator.
+    ///
+    /// See [`FirstErr::first_none_or_else()`](crate::FirstErr::first_none_or_else) for more details.
+    pub struct FirstNoneIter<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        state: State<I, T>,
+    }
+
+    impl<I, T> FirstNoneIter<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        #[inline]
+        pub(super) fn first_none_or_else<O, F>(inner: I, f: F) -> Option<O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            Self::first_none_or_else_partial(inner, f).ok()
+        }
+
+        #[inline]
+        pub(super) fn first_none_or_else_partial<O, F>(inner: I, f: F) -> Result<O, O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self::new(inner);
+
+            let output = f(&mut me);
+
+            me.finish(output)
+        }
+
+        #[inline]
+        pub(super) fn first_none_or_else_no_drain<O, F>(inner: I, f: F) -> Option<O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self::new(inner);
+
+            let output = f(&mut me);
+
+            // Only trust what the closure consumed.
+            match me.state {
+                State::FoundFirstNone => None,
+                State::Active(_) | State::Exhausted => Some(output),
+            }
+        }
+
+        #[inline]
+        fn new(inner: I) -> Self {
+            Self {
+                state: State::Active(inner),
+            }
+        }
+
+        /// Consume the remaining items, then return the `output` as `Err` if any `None` found,
+        /// or as `Ok` if not.
+        #[inline]
+        fn finish<O>(self, output: O) -> Result<O, O> {
+            // Take first None, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match self.state {
+                State::Active(mut inner) => match inner.try_for_each(|opt| opt.map(drop)) {
+                    Some(()) => Ok(output),
+                    None => Err(output),
+                },
+                State::Exhausted => Ok(output),
+                State::FoundFirstNone => Err(output),
+            }
+        }
+    }
+
+    /// Only print the name of the current state, so no `Debug` bound is required on `I` or `T`.
+    impl<I, T> fmt::Debug for FirstNoneIter<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let state = match &self.state {
+                State::Active(_) => "Active",
+                State::FoundFirstNone => "FoundFirstNone",
+                State::Exhausted => "Exhausted",
+            };
+
+            f.debug_struct("FirstNoneIter")
+                .field("state", &format_args!("{state}"))
+                .finish()
+        }
+    }
+
+    impl<I, T> Iterator for FirstNoneIter<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next() {
+                    Some(Some(t)) => Some(t),
+                    Some(None) => {
+                        self.state = State::FoundFirstNone;
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
+                        None
+                    }
+                },
+                State::FoundFirstNone => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => inner.size_hint(),
+                State::FoundFirstNone => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T> FusedIterator for FirstNoneIter<I, T> where I: Iterator<Item = Option<T>> {}
+
+    /// A [`FirstNoneIter`] which can peek the next `Some` value.
+    ///
+    /// See [`FirstErr::first_none_or_else_peekable()`](crate::FirstErr::first_none_or_else_peekable)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstNonePeekable<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        iter: FirstNoneIter<I, T>,
+
+        /// Remember a peeked value, even if it was `None`.
+        peeked: Option<Option<T>>,
+    }
+
+    impl<I, T> FirstNonePeekable<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        #[inline]
+        pub(super) fn first_none_or_else_peekable<O, F>(inner: I, f: F) -> Option<O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                iter: FirstNoneIter::new(inner),
+                peeked: None,
+            };
+
+            let output = f(&mut me);
+
+            // A peeked but unconsumed value must be a `Some` value, just drop it.
+            me.iter.finish(output).ok()
+        }
+
+        /// Returns a reference to the next `Some` value without advancing the iterator.
+        ///
+        /// Returns `None` when the source is exhausted or the next item is the first `None`.
+        #[inline]
+        pub fn peek(&mut self) -> Option<&T> {
+            let iter = &mut self.iter;
+            self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+        }
+
+        /// Returns a mutable reference to the next `Some` value without advancing the iterator.
+        ///
+        /// Returns `None` when the source is exhausted or the next item is the first `None`.
+        #[inline]
+        pub fn peek_mut(&mut self) -> Option<&mut T> {
+            let iter = &mut self.iter;
+            self.peeked.get_or_insert_with(|| iter.next()).as_mut()
+        }
+
+        /// Consume and return the next `Some` value if `func` returns `true` for it.
+        #[inline]
+        pub fn next_if(&mut self, func: impl FnOnce(&T) -> bool) -> Option<T> {
+            match self.next() {
+                Some(t) if func(&t) => Some(t),
+                other => {
+                    self.peeked = Some(other);
+                    None
+                }
+            }
+        }
+
+        /// Consume and return the next `Some` value if it is equal to `expected`.
+        #[inline]
+        pub fn next_if_eq<U>(&mut self, expected: &U) -> Option<T>
+        where
+            U: ?Sized,
+            T: PartialEq<U>,
+        {
+            self.next_if(|t| t == expected)
+        }
+    }
+
+    impl<I, T> Iterator for FirstNonePeekable<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.peeked.take() {
+                Some(peeked) => peeked,
+                None => self.iter.next(),
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let peek_len = match self.peeked {
+                Some(None) => return (0, Some(0)),
+                Some(Some(_)) => 1,
+                None => 0,
+            };
+            let (lower, upper) = self.iter.size_hint();
+            (
+                lower.saturating_add(peek_len),
+                upper.and_then(|n| n.checked_add(peek_len)),
+            )
+        }
+    }
+
+    impl<I, T> FusedIterator for FirstNonePeekable<I, T> where I: Iterator<Item = Option<T>> {}
+
+    /// A [`FirstNoneIter`] which producing owned values from an iterator of `&Option<T>`.
+    ///
+    /// See [`FirstErr::first_none_copied_or_else()`](crate::FirstErr::first_none_copied_or_else)
+    /// and [`FirstErr::first_none_cloned_or_else()`](crate::FirstErr::first_none_cloned_or_else)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstNoneOwned<'a, I, T>
+    where
+        I: Iterator<Item = &'a Option<T>>,
+        T: 'a,
+    {
+        iter: FirstNoneIter<RefOptions<I>, &'a T>,
+    }
+
+    impl<'a, I, T> FirstNoneOwned<'a, I, T>
+    where
+        I: Iterator<Item = &'a Option<T>>,
+        T: 'a,
+    {
+        #[inline]
+        pub(super) fn first_none_or_else_owned<O, F>(inner: I, f: F) -> Option<O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                iter: FirstNoneIter::new(RefOptions::new(inner)),
+            };
+
+            let output = f(&mut me);
+
+            me.iter.finish(output).ok()
+        }
+    }
+
+    impl<'a, I, T> Iterator for FirstNoneOwned<'a, I, T>
+    where
+        I: Iterator<Item = &'a Option<T>>,
+        T: 'a + Clone,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.iter.next().cloned()
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.iter.size_hint()
+        }
+    }
+
+    impl<'a, I, T> FusedIterator for FirstNoneOwned<'a, I, T>
+    where
+        I: Iterator<Item = &'a Option<T>>,
+        T: 'a + Clone,
+    {
+    }
+
+    /// A [`FirstNoneIter`] which producing `Some` values chunk by chunk.
+    ///
+    /// See [`FirstErr::first_none_or_else_chunks()`](crate::FirstErr::first_none_or_else_chunks)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstNoneChunks<I, T, const N: usize>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        iter: FirstNoneIter<I, T>,
+
+        /// Length of the partial chunk, if the source stopped in the middle of a chunk.
+        partial: Option<usize>,
+    }
+
+    impl<I, T, const N: usize> FirstNoneChunks<I, T, N>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        #[inline]
+        pub(super) fn first_none_or_else_chunks<O, F>(inner: I, f: F) -> Result<O, ChunkGap>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            const { assert!(N != 0, "chunk size must be non-zero") };
+
+            let mut me = Self {
+                iter: FirstNoneIter::new(inner),
+                partial: None,
+            };
+
+            let output = f(&mut me);
+
+            // The rest `Some` values decide whether the last chunk is a partial one. If the
+            // closure already met the partial chunk, nothing left here.
+            let rest = (&mut me.iter).count();
+            let partial = me.partial.unwrap_or(rest % N);
+
+            // `None` takes priority over the partial chunk.
+            me.iter.finish(()).map_err(|()| ChunkGap::None)?;
+
+            match partial {
+                0 => Ok(output),
+                len => Err(ChunkGap::Partial(len)),
+            }
+        }
+    }
+
+    impl<I, T, const N: usize> Iterator for FirstNoneChunks<I, T, N>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = [T; N];
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match crate::array::try_from_fn(|_| self.iter.next()) {
+                Ok(chunk) => Some(chunk),
+                Err(0) => None,
+                Err(len) => {
+                    self.partial = Some(len);
+                    None
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let (lower, upper) = self.iter.size_hint();
+            (lower / N, upper.map(|n| n / N))
+        }
+    }
+
+    impl<I, T, const N: usize> FusedIterator for FirstNoneChunks<I, T, N> where
+        I: Iterator<Item = Option<T>>
+    {
+    }
+
+    /// A [`FirstNoneIter`] which producing the flattened `Some` values.
+    ///
+    /// See [`FirstErr::first_none_or_else_flat()`](crate::FirstErr::first_none_or_else_flat)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstNoneFlat<I, II>
+    where
+        I: Iterator<Item = Option<II>>,
+        II: IntoIterator,
+    {
+        iter: FirstNoneIter<I, II>,
+
+        /// The `Some` value which is iterating now.
+        front: Option<II::IntoIter>,
+    }
+
+    impl<I, II> FirstNoneFlat<I, II>
+    where
+        I: Iterator<Item = Option<II>>,
+        II: IntoIterator,
+    {
+        #[inline]
+        pub(super) fn first_none_or_else_flat<O, F>(inner: I, f: F) -> Option<O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                iter: FirstNoneIter::new(inner),
+                front: None,
+            };
+
+            let output = f(&mut me);
+
+            // Only check the outer items, the rest elements in `front` are ignored.
+            me.iter.finish(output).ok()
+        }
+    }
+
+    impl<I, II> Iterator for FirstNoneFlat<I, II>
+    where
+        I: Iterator<Item = Option<II>>,
+        II: IntoIterator,
+    {
+        type Item = II::Item;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                if let Some(front) = &mut self.front {
+                    match front.next() {
+                        Some(item) => return Some(item),
+                        None => self.front = None,
+                    }
+                }
+
+                self.front = Some(self.iter.next()?.into_iter());
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let (lower, upper) = match &self.front {
+                Some(front) => front.size_hint(),
+                None => (0, Some(0)),
+            };
+
+            match self.iter.size_hint() {
+                (_, Some(0)) => (lower, upper),
+                _ => (lower, None),
+            }
+        }
+    }
+
+    impl<I, II> FusedIterator for FirstNoneFlat<I, II>
+    where
+        I: Iterator<Item = Option<II>>,
+        II: IntoIterator,
+    {
+    }
+
+    /// The error type of
+    /// [`FirstErr::collect_array_or_none()`](crate::FirstErr::collect_array_or_none).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ArrayCollectGap {
+        /// A `None` item found in the source iterator, and its index.
+        None { index: usize },
+
+        /// The source iterator ended before producing enough items, contains the count of the
+        /// `Some` values already got.
+        TooShort { got: usize },
+    }
+
+    /// The error type of
+    /// [`FirstErr::first_none_or_else_chunks()`](crate::FirstErr::first_none_or_else_chunks).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ChunkGap {
+        /// A `None` item found in the source iterator.
+        None,
+
+        /// The `Some` values can't fill up the last chunk, contains the length of this partial
+        /// chunk.
+        Partial(usize),
+    }
+
+    /// An `Iterator` which producing all `Some` values from another iterator, and counting the
+    /// `None` items.
+    ///
+    /// See [`FirstErr::last_none_or_else()`](crate::FirstErr::last_none_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct LastNoneIter<I> {
+        /// `None` if the inner iterator is exhausted.
+        inner: Option<I>,
+        gaps: usize,
+    }
+
+    impl<I> LastNoneIter<I> {
+        #[inline]
+        pub(super) fn last_none_or_else<T, O, F>(inner: I, f: F) -> Result<O, usize>
+        where
+            I: Iterator<Item = Option<T>>,
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                inner: Some(inner),
+                gaps: 0,
+            };
+
+            let output = f(&mut me);
+
+            // Count all the rest `None`s.
+            me.by_ref().for_each(drop);
+
+            match me.gaps {
+                0 => Ok(output),
+                gaps => Err(gaps),
+            }
+        }
+    }
+
+    impl<I, T> Iterator for LastNoneIter<I>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let inner = self.inner.as_mut()?;
+
+            loop {
+                match inner.next() {
+                    Some(Some(t)) => return Some(t),
+                    Some(None) => self.gaps += 1,
+                    None => {
+                        self.inner = None;
+                        return None;
+                    }
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T> FusedIterator for LastNoneIter<I> where I: Iterator<Item = Option<T>> {}
+
+    /// An `Iterator` can take first `None` from another iterator, and counting the `Some` items.
+    ///
+    /// See
+    /// [`FirstErr::first_none_or_else_counted()`](crate::FirstErr::first_none_or_else_counted)
+    /// for more details.
+    #[derive(Debug)]
+    pub struct FirstNoneCountedIter<I> {
+        /// `None` if the first `None` found or the inner iterator is exhausted.
+        inner: Option<I>,
+        count: usize,
+        found_none: bool,
+    }
+
+    impl<I> FirstNoneCountedIter<I> {
+        #[inline]
+        pub(super) fn first_none_or_else_counted<T, O, F>(
+            inner: I,
+            f: F,
+        ) -> Result<(O, usize), usize>
+        where
+            I: Iterator<Item = Option<T>>,
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                inner: Some(inner),
+                count: 0,
+                found_none: false,
+            };
+
+            let output = f(&mut me);
+
+            // Count the rest `Some`s until the first `None`.
+            me.by_ref().for_each(drop);
+
+            if me.found_none {
+                Err(me.count)
+            } else {
+                Ok((output, me.count))
+            }
+        }
+    }
+
+    impl<I, T> Iterator for FirstNoneCountedIter<I>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.inner.as_mut()?.next() {
+                Some(Some(t)) => {
+                    self.count += 1;
+                    Some(t)
+                }
+                Some(None) => {
+                    self.found_none = true;
+                    self.inner = None;
+                    None
+                }
+                None => {
+                    self.inner = None;
+                    None
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T> FusedIterator for FirstNoneCountedIter<I> where I: Iterator<Item = Option<T>> {}
+
+    /// A fixed capacity buffer of the indices of `None` items.
+    ///
+    /// See
+    /// [`FirstErr::first_none_indices_or_else()`](crate::FirstErr::first_none_indices_or_else)
+    /// for more details.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct IndexBuf<const N: usize> {
+        indices: [usize; N],
+        total: usize,
+    }
+
+    impl<const N: usize> IndexBuf<N> {
+        #[inline]
+        pub(super) fn new() -> Self {
+            Self {
+                indices: [0; N],
+                total: 0,
+            }
+        }
+
+        #[inline]
+        pub(super) fn push(&mut self, index: usize) {
+            if let Some(slot) = self.indices.get_mut(self.total) {
+                *slot = index;
+            }
+            self.total += 1;
+        }
+
+        /// Returns the recorded indices, at most `N` of them.
+        #[inline]
+        pub fn as_slice(&self) -> &[usize] {
+            &self.indices[..self.total.min(N)]
+        }
+
+        /// Returns the count of all indices, including the truncated ones.
+        #[inline]
+        pub fn total(&self) -> usize {
+            self.total
+        }
+
+        /// Returns `true` if there are more than `N` indices, and some of them are not recorded.
+        #[inline]
+        pub fn is_truncated(&self) -> bool {
+            self.total > N
+        }
+    }
+
+    /// An `Iterator` which producing items from another iterator, until a `Some` value not
+    /// satisfies the predicate.
+    ///
+    /// See [`FirstErr::first_none_or_else_while()`](crate::FirstErr::first_none_or_else_while)
+    /// for more details.
+    pub struct SomeWhile<I, P> {
+        /// `None` if cut off.
+        inner: Option<I>,
+        predicate: P,
+    }
+
+    impl<I, P> SomeWhile<I, P> {
+        #[inline]
+        pub(super) fn new(inner: I, predicate: P) -> Self {
+            Self {
+                inner: Some(inner),
+                predicate,
+            }
+        }
+    }
+
+    impl<I, P> fmt::Debug for SomeWhile<I, P>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("SomeWhile")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, P, T> Iterator for SomeWhile<I, P>
+    where
+        I: Iterator<Item = Option<T>>,
+        P: FnMut(&T) -> bool,
+    {
+        type Item = Option<T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.inner.as_mut()?.next()? {
+                Some(t) if !(self.predicate)(&t) => {
+                    self.inner = None;
+                    None
+                }
+                opt => Some(opt),
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, P, T> FusedIterator for SomeWhile<I, P>
+    where
+        I: FusedIterator<Item = Option<T>>,
+        P: FnMut(&T) -> bool,
+    {
+    }
+
+    /// An `Iterator` which turns the `Some` values satisfying the predicate into `None`.
+    ///
+    /// See [`FirstErr::none_if()`](crate::FirstErr::none_if) for more details.
+    pub struct NoneIf<I, P> {
+        inner: I,
+        predicate: P,
+    }
+
+    impl<I, P> NoneIf<I, P> {
+        #[inline]
+        pub(super) fn new(inner: I, predicate: P) -> Self {
+            Self { inner, predicate }
+        }
+    }
+
+    impl<I, P> fmt::Debug for NoneIf<I, P>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("NoneIf")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, P, T> Iterator for NoneIf<I, P>
+    where
+        I: Iterator<Item = Option<T>>,
+        P: FnMut(&T) -> bool,
+    {
+        type Item = Option<T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let predicate = &mut self.predicate;
+
+            self.inner.next().map(|opt| opt.filter(|t| !predicate(t)))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, P, T> FusedIterator for NoneIf<I, P>
+    where
+        I: FusedIterator<Item = Option<T>>,
+        P: FnMut(&T) -> bool,
+    {
+    }
+
+    /// An `Iterator` which maps every `Some` value from another iterator by a fallible function.
+    ///
+    /// See [`FirstErr::map_first_none_or_else()`](crate::FirstErr::map_first_none_or_else) for
+    /// more details.
+    pub struct AndThen<I, M> {
+        inner: I,
+        map: M,
+    }
+
+    impl<I, M> AndThen<I, M> {
+        #[inline]
+        pub(super) fn new(inner: I, map: M) -> Self {
+            Self { inner, map }
+        }
+    }
+
+    impl<I, M> fmt::Debug for AndThen<I, M>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("AndThen")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, M, T, U> Iterator for AndThen<I, M>
+    where
+        I: Iterator<Item = Option<T>>,
+        M: FnMut(T) -> Option<U>,
+    {
+        type Item = Option<U>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().map(|opt| opt.and_then(&mut self.map))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, M, T, U> FusedIterator for AndThen<I, M>
+    where
+        I: FusedIterator<Item = Option<T>>,
+        M: FnMut(T) -> Option<U>,
+    {
+    }
+
+    /// Walk two iterators in lockstep, and return `None` if any `None` found at or before the
+    /// position where the comparison is decided. At the same position, `a` is pulled before `b`.
+    ///
+    /// `f` decides whether to stop at each pair of `Some` values. If it never stops, returns
+    /// the ordering of their lengths.
+    #[inline]
+    pub(super) fn iter_compare<A, B, T, R, F>(
+        mut a: A,
+        mut b: B,
+        mut f: F,
+    ) -> Option<ControlFlow<R, Ordering>>
+    where
+        A: Iterator<Item = Option<T>>,
+        B: Iterator<Item = Option<T>>,
+        F: FnMut(T, T) -> ControlFlow<R>,
+    {
+        loop {
+            let a_value = match a.next() {
+                Some(opt) => opt?,
+                None => {
+                    return match b.next() {
+                        Some(opt) => opt.map(|_| ControlFlow::Continue(Ordering::Less)),
+                        None => Some(ControlFlow::Continue(Ordering::Equal)),
+                    };
+                }
+            };
+
+            let b_value = match b.next() {
+                Some(opt) => opt?,
+                None => return Some(ControlFlow::Continue(Ordering::Greater)),
+            };
+
+            if let ControlFlow::Break(r) = f(a_value, b_value) {
+                return Some(ControlFlow::Break(r));
+            }
+        }
+    }
+
+    /// An `Iterator` which offers every `None` item to a recover function.
+    ///
+    /// See [`FirstErr::first_none_or_else_recover()`](crate::FirstErr::first_none_or_else_recover)
+    /// for more details.
+    pub struct RecoverNone<I, R> {
+        inner: I,
+        recover: R,
+    }
+
+    impl<I, R> RecoverNone<I, R> {
+        #[inline]
+        pub(super) fn new(inner: I, recover: R) -> Self {
+            Self { inner, recover }
+        }
+    }
+
+    impl<I, R> fmt::Debug for RecoverNone<I, R>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("RecoverNone")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, R, T> Iterator for RecoverNone<I, R>
+    where
+        I: Iterator<Item = Option<T>>,
+        R: FnMut() -> Option<T>,
+    {
+        type Item = Option<T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().map(|opt| opt.or_else(&mut self.recover))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, R, T> FusedIterator for RecoverNone<I, R>
+    where
+        I: FusedIterator<Item = Option<T>>,
+        R: FnMut() -> Option<T>,
+    {
+    }
+
+    /// An `Iterator` which producing `Some` values from another iterator until the first `None`,
+    /// and recording whether the `None` was hit into a flag.
+    ///
+    /// See [`FirstErr::until_none()`](crate::FirstErr::until_none) for more details.
+    #[derive(Debug)]
+    pub struct UntilNone<'a, I> {
+        /// `None` if the inner iterator is exhausted or the first `None` was hit.
+        inner: Option<I>,
+        flag: &'a mut bool,
+    }
+
+    impl<'a, I> UntilNone<'a, I> {
+        #[inline]
+        pub(super) fn new(inner: I, flag: &'a mut bool) -> Self {
+            *flag = false;
+
+            Self {
+                inner: Some(inner),
+                flag,
+            }
+        }
+    }
+
+    impl<I, T> Iterator for UntilNone<'_, I>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match self.inner.as_mut()?.next() {
+                Some(Some(t)) => Some(t),
+                Some(None) => {
+                    *self.flag = true;
+                    self.inner = None;
+                    None
+                }
+                None => {
+                    self.inner = None;
+                    None
+                }
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T> FusedIterator for UntilNone<'_, I> where I: Iterator<Item = Option<T>> {}
+
+    /// An `Iterator` can take first gap, a `None` or a `Some(Err)`, from another iterator.
+    ///
+    /// See [`FirstErr::first_gap_or_else()`](crate::FirstErr::first_gap_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct FirstGapIter<I, T, E>
+    where
+        I: Iterator<Item = Option<Result<T, E>>>,
+    {
+        state: GapState<I, E>,
+    }
+
+    impl<I, T, E> FirstGapIter<I, T, E>
+    where
+        I: Iterator<Item = Option<Result<T, E>>>,
+    {
+        #[inline]
+        pub(super) fn first_gap_or_else<O, F>(inner: I, f: F) -> Result<O, Gap<E>>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                state: GapState::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first gap, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                GapState::Active(inner) => {
+                    for opt in inner {
+                        match opt {
+                            Some(Ok(_)) => {}
+                            Some(Err(e)) => return Err(Gap::Err(e)),
+                            None => return Err(Gap::None),
+                        }
+                    }
+                    Ok(output)
+                }
+                GapState::Exhausted => Ok(output),
+                GapState::FoundFirstErr(e) => Err(Gap::Err(e)),
+                GapState::FoundFirstNone => Err(Gap::None),
+            }
+        }
+    }
+
+    impl<I, T, E> Iterator for FirstGapIter<I, T, E>
+    where
+        I: Iterator<Item = Option<Result<T, E>>>,
+    {
+        type Item = T;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                GapState::Active(inner) => match inner.next() {
+                    Some(Some(Ok(t))) => Some(t),
+                    Some(Some(Err(e))) => {
+                        self.state = GapState::FoundFirstErr(e);
+                        None
+                    }
+                    Some(None) => {
+                        self.state = GapState::FoundFirstNone;
+                        None
+                    }
+                    None => {
+                        self.state = GapState::Exhausted;
+                        None
+                    }
+                },
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                GapState::Active(inner) => (0, inner.size_hint().1),
+                _ => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, T, E> FusedIterator for FirstGapIter<I, T, E> where I: Iterator<Item = Option<Result<T, E>>> {}
+
+    /// The error type of [`FirstErr::first_gap_or_else()`](crate::FirstErr::first_gap_or_else).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Gap<E> {
+        /// A `None` item found in the source iterator.
+        None,
+
+        /// A `Some(Err)` item found in the source iterator, contains the error.
+        Err(E),
+    }
+
+    /// Internal state of [`FirstGapIter`].
+    #[derive(Debug)]
+    enum GapState<I, E> {
+        Active(I),
+        FoundFirstErr(E),
+        FoundFirstNone,
+        Exhausted,
+    }
+
+    /// An `Iterator` which turns the `None` items into `Err`, and `Some` values into `Ok`.
+    ///
+    /// See [`FirstErr::none_into_err()`](crate::FirstErr::none_into_err) for more details.
+    pub struct NoneIntoErr<I, M> {
+        inner: I,
+        make_err: M,
+    }
+
+    impl<I, M> NoneIntoErr<I, M> {
+        #[inline]
+        pub(super) fn new(inner: I, make_err: M) -> Self {
+            Self { inner, make_err }
+        }
+    }
+
+    impl<I, M> fmt::Debug for NoneIntoErr<I, M>
+    where
+        I: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("NoneIntoErr")
+                .field("inner", &self.inner)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<I, M, T, E> Iterator for NoneIntoErr<I, M>
+    where
+        I: Iterator<Item = Option<T>>,
+        M: FnMut() -> E,
+    {
+        type Item = Result<T, E>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let make_err = &mut self.make_err;
+
+            self.inner.next().map(|opt| opt.ok_or_else(make_err))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, M, T, E> FusedIterator for NoneIntoErr<I, M>
+    where
+        I: FusedIterator<Item = Option<T>>,
+        M: FnMut() -> E,
+    {
+    }
+
+    /// An `Iterator` which turns every `None` item into an `Err` of its position, and `Some`
+    /// values into `Ok`.
+    ///
+    /// See [`FirstErr::first_none_position_or_else()`](crate::FirstErr::first_none_position_or_else)
+    /// for more details.
+    #[derive(Debug, Clone)]
+    pub struct NonePositions<I> {
+        inner: Enumerate<I>,
+    }
+
+    impl<I> NonePositions<I>
+    where
+        I: Iterator,
+    {
+        #[inline]
+        pub(super) fn new(inner: I) -> Self {
+            Self {
+                inner: inner.enumerate(),
+            }
+        }
+    }
+
+    impl<I, T> Iterator for NonePositions<I>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        type Item = Result<T, usize>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let (i, opt) = self.inner.next()?;
+            Some(opt.ok_or(i))
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<I, T> DoubleEndedIterator for NonePositions<I>
+    where
+        I: DoubleEndedIterator<Item = Option<T>> + ExactSizeIterator,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            let (i, opt) = self.inner.next_back()?;
+            Some(opt.ok_or(i))
+        }
+    }
+
+    impl<I, T> ExactSizeIterator for NonePositions<I> where I: ExactSizeIterator<Item = Option<T>> {}
+
+    impl<I, T> FusedIterator for NonePositions<I> where I: FusedIterator<Item = Option<T>> {}
+
+    /// An `Iterator` which turns every `&Option<T>` item into `Option<&T>`.
+    ///
+    /// See [`FirstErr::first_none_ref_or_else()`](crate::FirstErr::first_none_ref_or_else) for
+    /// more details.
+    #[derive(Debug, Clone)]
+    pub struct RefOptions<I> {
+        inner: I,
+    }
+
+    impl<I> RefOptions<I> {
+        #[inline]
+        pub(super) fn new(inner: I) -> Self {
+            Self { inner }
+        }
+    }
+
+    impl<'a, I, T> Iterator for RefOptions<I>
+    where
+        I: Iterator<Item = &'a Option<T>>,
+        T: 'a,
+    {
+        type Item = Option<&'a T>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            self.inner.next().map(Option::as_ref)
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            self.inner.size_hint()
+        }
+    }
+
+    impl<'a, I, T> DoubleEndedIterator for RefOptions<I>
+    where
+        I: DoubleEndedIterator<Item = &'a Option<T>>,
+        T: 'a,
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> {
+            self.inner.next_back().map(Option::as_ref)
+        }
+    }
+
+    impl<'a, I, T> ExactSizeIterator for RefOptions<I>
+    where
+        I: ExactSizeIterator<Item = &'a Option<T>>,
+        T: 'a,
+    {
+    }
+
+    impl<'a, I, T> FusedIterator for RefOptions<I>
+    where
+        I: FusedIterator<Item = &'a Option<T>>,
+        T: 'a,
+    {
+    }
+
+    /// Internal state of [`FirstNoneIter`].
+    #[derive(Debug)]
+    enum State<I, T>
+    where
+        I: Iterator<Item = Option<T>>,
+    {
+        Active(I),
+        FoundFirstNone,
+        Exhausted,
+    }
+}
+
+mod control_flow {
+    use core::{iter::FusedIterator, ops::ControlFlow};
+
+    /// An `Iterator` can take first `Break` from another iterator.
+    ///
+    /// See [`FirstErr::first_break_or_else()`](crate::FirstErr::first_break_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct FirstBreakIter<I, C, B>
+    where
+        I: Iterator<Item = ControlFlow<B, C>>,
+    {
+        state: State<I, C, B>,
+    }
+
+    impl<I, C, B> FirstBreakIter<I, C, B>
+    where
+        I: Iterator<Item = ControlFlow<B, C>>,
+    {
+        #[inline]
+        pub(super) fn first_break_or_else<O, F>(inner: I, f: F) -> ControlFlow<B, O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self::new(inner);
+
+            let output = f(&mut me);
+
+            me.finish(output)
+        }
+
+        #[inline]
+        fn new(inner: I) -> Self {
+            Self {
+                state: State::Active(inner),
+            }
+        }
+
+        /// Consume the remaining items, then return the first `Break` or the `output`.
+        #[inline]
+        fn finish<O>(self, output: O) -> ControlFlow<B, O> {
+            // Take first break, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match self.state {
+                State::Active(mut inner) => {
+                    inner.try_for_each(|flow| match flow {
+                        ControlFlow::Continue(_) => ControlFlow::Continue(()),
+                        ControlFlow::Break(b) => ControlFlow::Break(b),
+                    })?;
+                    ControlFlow::Continue(output)
+                }
+                State::Exhausted => ControlFlow::Continue(output),
+                State::FoundFirstBreak(b) => ControlFlow::Break(b),
+            }
+        }
+    }
+
+    impl<I, C, B> Iterator for FirstBreakIter<I, C, B>
+    where
+        I: Iterator<Item = ControlFlow<B, C>>,
+    {
+        type Item = C;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next() {
+                    Some(ControlFlow::Continue(c)) => Some(c),
+                    Some(ControlFlow::Break(b)) => {
+                        self.state = State::FoundFirstBreak(b);
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
+                        None
+                    }
+                },
+                State::FoundFirstBreak(_) => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => inner.size_hint(),
+                State::FoundFirstBreak(_) => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, C, B> FusedIterator for FirstBreakIter<I, C, B> where I: Iterator<Item = ControlFlow<B, C>> {}
+
+    /// Internal state of [`FirstBreakIter`].
+    #[derive(Debug)]
+    enum State<I, C, B>
+    where
+        I: Iterator<Item = ControlFlow<B, C>>,
+    {
+        Active(I),
+        FoundFirstBreak(B),
+        Exhausted,
+    }
+}
+
+mod boolean {
+    use core::iter::FusedIterator;
+
+    /// An `Iterator` producing the indices of `true` items from another iterator, and stop on
+    /// first `false`.
+    ///
+    /// See [`FirstErr::first_false_or_else()`](crate::FirstErr::first_false_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct FirstFalseIter<I>
+    where
+        I: Iterator<Item = bool>,
+    {
+        /// Index of the next item.
+        index: usize,
+        state: State<I>,
+    }
+
+    impl<I> FirstFalseIter<I>
+    where
+        I: Iterator<Item = bool>,
+    {
+        #[inline]
+        pub(super) fn first_false_or_else<O, F>(inner: I, f: F) -> Result<O, usize>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                index: 0,
+                state: State::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first false, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                State::Active(mut inner) => match inner.position(|b| !b) {
+                    Some(pos) => Err(me.index + pos),
+                    None => Ok(output),
+                },
+                State::Exhausted => Ok(output),
+                State::FoundFirstFalse(index) => Err(index),
+            }
+        }
+    }
+
+    impl<I> Iterator for FirstFalseIter<I>
+    where
+        I: Iterator<Item = bool>,
+    {
+        type Item = usize;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next() {
+                    Some(true) => {
+                        self.index += 1;
+                        Some(self.index - 1)
+                    }
+                    Some(false) => {
+                        self.state = State::FoundFirstFalse(self.index);
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
+                        None
+                    }
+                },
+                State::FoundFirstFalse(_) => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => (0, inner.size_hint().1),
+                State::FoundFirstFalse(_) => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I> FusedIterator for FirstFalseIter<I> where I: Iterator<Item = bool> {}
+
+    /// Internal state of [`FirstFalseIter`].
+    #[derive(Debug)]
+    enum State<I> {
+        Active(I),
+        FoundFirstFalse(usize),
+        Exhausted,
+    }
+}
+
+mod fallible {
+    use core::{fmt, iter::FusedIterator, ops::ControlFlow};
+
+    /// A type which may be a success or a failure, can be used in
+    /// [`FirstErr::first_failure_of_or_else()`](crate::FirstErr::first_failure_of_or_else).
+    ///
+    /// This trait is implemented for `Result<T, E>`, `Option<T>` and `ControlFlow<B, C>`, and can
+    /// be implemented for any user-defined type.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::{Fallible, FirstErr};
+    /// #
+    /// # fn main() {
+    /// enum Tri {
+    ///     Good(u8),
+    ///     Soft(&'static str),
+    ///     Hard(&'static str),
+    /// }
+    ///
+    /// // Treat `Soft` as a success with a default value.
+    /// impl Fallible for Tri {
+    ///     type Ok = u8;
+    ///     type Failure = &'static str;
+    ///
+    ///     fn into_parts(self) -> Result<u8, &'static str> {
+    ///         match self {
+    ///             Tri::Good(n) => Ok(n),
+    ///             Tri::Soft(_) => Ok(0),
+    ///             Tri::Hard(e) => Err(e),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let result = [Tri::Good(1), Tri::Soft("warn"), Tri::Hard("fatal"), Tri::Good(2)]
+    ///     .into_iter()
+    ///     .first_failure_of_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err("fatal"));
+    /// # }
+    /// ```
+    pub trait Fallible {
+        /// The type of success values.
+        type Ok;
+
+        /// The type of failure values.
+        type Failure;
+
+        /// Splits `self` into a success value or a failure value.
+        fn into_parts(self) -> Result<Self::Ok, Self::Failure>;
+    }
+
+    impl<T, E> Fallible for Result<T, E> {
+        type Ok = T;
+        type Failure = E;
+
+        #[inline]
+        fn into_parts(self) -> Result<T, E> {
+            self
+        }
+    }
+
+    impl<T> Fallible for Option<T> {
+        type Ok = T;
+        type Failure = ();
+
+        #[inline]
+        fn into_parts(self) -> Result<T, ()> {
+            self.ok_or(())
+        }
+    }
+
+    impl<B, C> Fallible for ControlFlow<B, C> {
+        type Ok = C;
+        type Failure = B;
+
+        #[inline]
+        fn into_parts(self) -> Result<C, B> {
+            match self {
+                ControlFlow::Continue(c) => Ok(c),
+                ControlFlow::Break(b) => Err(b),
+            }
+        }
+    }
+
+    /// An `Iterator` can take first failure from another iterator of any [`Fallible`] type.
+    ///
+    /// See [`FirstErr::first_failure_of_or_else()`](crate::FirstErr::first_failure_of_or_else)
+    /// for more details.
+    pub struct FirstFailureOfIter<I>
+    where
+        I: Iterator,
+        I::Item: Fallible,
+    {
+        state: State<I, <I::Item as Fallible>::Failure>,
+    }
+
+    impl<I> FirstFailureOfIter<I>
+    where
+        I: Iterator,
+        I::Item: Fallible,
+    {
+        #[inline]
+        pub(super) fn first_failure_of_or_else<O, F>(
+            inner: I,
+            f: F,
+        ) -> Result<O, <I::Item as Fallible>::Failure>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                state: State::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first failure, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                State::Active(inner) => {
+                    for item in inner {
+                        item.into_parts()?;
+                    }
+                    Ok(output)
+                }
+                State::Exhausted => Ok(output),
+                State::FoundFirstFailure(failure) => Err(failure),
+            }
+        }
+    }
+
+    impl<I> fmt::Debug for FirstFailureOfIter<I>
+    where
+        I: Iterator + fmt::Debug,
+        I::Item: Fallible,
+        <I::Item as Fallible>::Failure: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("FirstFailureOfIter")
+                .field("state", &self.state)
+                .finish()
+        }
+    }
+
+    impl<I> Iterator for FirstFailureOfIter<I>
+    where
+        I: Iterator,
+        I::Item: Fallible,
+    {
+        type Item = <I::Item as Fallible>::Ok;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next().map(Fallible::into_parts) {
                     Some(Ok(t)) => Some(t),
-                    Some(Err(e)) => {
-                        self.state = State::FoundFirstErr(e);
+                    Some(Err(failure)) => {
+                        self.state = State::FoundFirstFailure(failure);
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
+                        None
+                    }
+                },
+                State::FoundFirstFailure(_) => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => (0, inner.size_hint().1),
+                State::FoundFirstFailure(_) => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I> FusedIterator for FirstFailureOfIter<I>
+    where
+        I: Iterator,
+        I::Item: Fallible,
+    {
+    }
+
+    /// Internal state of [`FirstFailureOfIter`].
+    #[derive(Debug)]
+    enum State<I, Failure> {
+        Active(I),
+        FoundFirstFailure(Failure),
+        Exhausted,
+    }
+}
+
+#[cfg(feature = "either")]
+mod either {
+    use ::either::Either;
+    use core::iter::FusedIterator;
+
+    /// An `Iterator` can take first `Left` from another iterator.
+    ///
+    /// See [`FirstErr::first_left_or_else()`](crate::FirstErr::first_left_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct FirstLeftIter<I, L, R>
+    where
+        I: Iterator<Item = Either<L, R>>,
+    {
+        state: State<I, L>,
+    }
+
+    impl<I, L, R> FirstLeftIter<I, L, R>
+    where
+        I: Iterator<Item = Either<L, R>>,
+    {
+        #[inline]
+        pub(super) fn first_left_or_else<O, F>(inner: I, f: F) -> Either<L, O>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                state: State::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first left, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                State::Active(mut inner) => match inner.find_map(Either::left) {
+                    Some(l) => Either::Left(l),
+                    None => Either::Right(output),
+                },
+                State::Exhausted => Either::Right(output),
+                State::Found(l) => Either::Left(l),
+            }
+        }
+    }
+
+    impl<I, L, R> Iterator for FirstLeftIter<I, L, R>
+    where
+        I: Iterator<Item = Either<L, R>>,
+    {
+        type Item = R;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next() {
+                    Some(Either::Right(r)) => Some(r),
+                    Some(Either::Left(l)) => {
+                        self.state = State::Found(l);
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
+                        None
+                    }
+                },
+                State::Found(_) => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => inner.size_hint(),
+                State::Found(_) => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, L, R> FusedIterator for FirstLeftIter<I, L, R> where I: Iterator<Item = Either<L, R>> {}
+
+    /// An `Iterator` can take first `Right` from another iterator.
+    ///
+    /// See [`FirstErr::first_right_or_else()`](crate::FirstErr::first_right_or_else) for more
+    /// details.
+    #[derive(Debug)]
+    pub struct FirstRightIter<I, L, R>
+    where
+        I: Iterator<Item = Either<L, R>>,
+    {
+        state: State<I, R>,
+    }
+
+    impl<I, L, R> FirstRightIter<I, L, R>
+    where
+        I: Iterator<Item = Either<L, R>>,
+    {
+        #[inline]
+        pub(super) fn first_right_or_else<O, F>(inner: I, f: F) -> Either<O, R>
+        where
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                state: State::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first right, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                State::Active(mut inner) => match inner.find_map(Either::right) {
+                    Some(r) => Either::Right(r),
+                    None => Either::Left(output),
+                },
+                State::Exhausted => Either::Left(output),
+                State::Found(r) => Either::Right(r),
+            }
+        }
+    }
+
+    impl<I, L, R> Iterator for FirstRightIter<I, L, R>
+    where
+        I: Iterator<Item = Either<L, R>>,
+    {
+        type Item = L;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next() {
+                    Some(Either::Left(l)) => Some(l),
+                    Some(Either::Right(r)) => {
+                        self.state = State::Found(r);
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
+                        None
+                    }
+                },
+                State::Found(_) => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => inner.size_hint(),
+                State::Found(_) => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, L, R> FusedIterator for FirstRightIter<I, L, R> where I: Iterator<Item = Either<L, R>> {}
+
+    /// Internal state of [`FirstLeftIter`] and [`FirstRightIter`].
+    #[derive(Debug)]
+    enum State<I, X> {
+        Active(I),
+        Found(X),
+        Exhausted,
+    }
+}
+
+#[cfg(feature = "nightly-try")]
+mod residual {
+    use core::{
+        fmt,
+        iter::FusedIterator,
+        ops::{ControlFlow, FromResidual, Residual, Try},
+    };
+
+    /// An `Iterator` can take first residual from another iterator of any [`Try`] type.
+    ///
+    /// See [`FirstErr::first_residual_or_else()`](crate::FirstErr::first_residual_or_else) for
+    /// more details.
+    pub struct FirstResidualIter<I, R>
+    where
+        I: Iterator<Item = R>,
+        R: Try,
+    {
+        state: State<I, R::Residual>,
+    }
+
+    impl<I, R> FirstResidualIter<I, R>
+    where
+        I: Iterator<Item = R>,
+        R: Try,
+    {
+        #[inline]
+        pub(super) fn first_residual_or_else<O, F>(
+            inner: I,
+            f: F,
+        ) -> <R::Residual as Residual<O>>::TryType
+        where
+            R::Residual: Residual<O>,
+            F: FnOnce(&mut Self) -> O,
+        {
+            let mut me = Self {
+                state: State::Active(inner),
+            };
+
+            let output = f(&mut me);
+
+            // Take first residual, if not found and not exhausted yet, find it.
+            // If just not found finally, return output.
+            match me.state {
+                State::Active(inner) => {
+                    for item in inner {
+                        if let ControlFlow::Break(residual) = item.branch() {
+                            return FromResidual::from_residual(residual);
+                        }
+                    }
+                    Try::from_output(output)
+                }
+                State::Exhausted => Try::from_output(output),
+                State::FoundFirstResidual(residual) => FromResidual::from_residual(residual),
+            }
+        }
+    }
+
+    impl<I, R> fmt::Debug for FirstResidualIter<I, R>
+    where
+        I: Iterator<Item = R> + fmt::Debug,
+        R: Try,
+        R::Residual: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("FirstResidualIter")
+                .field("state", &self.state)
+                .finish()
+        }
+    }
+
+    impl<I, R> Iterator for FirstResidualIter<I, R>
+    where
+        I: Iterator<Item = R>,
+        R: Try,
+    {
+        type Item = R::Output;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            match &mut self.state {
+                State::Active(inner) => match inner.next().map(Try::branch) {
+                    Some(ControlFlow::Continue(output)) => Some(output),
+                    Some(ControlFlow::Break(residual)) => {
+                        self.state = State::FoundFirstResidual(residual);
+                        None
+                    }
+                    None => {
+                        self.state = State::Exhausted;
                         None
                     }
-                    None => {
-                        self.state = State::Exhausted;
-                        None
+                },
+                State::FoundFirstResidual(_) => None,
+                State::Exhausted => None,
+            }
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                State::Active(inner) => inner.size_hint(),
+                State::FoundFirstResidual(_) => (0, Some(0)),
+                State::Exhausted => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, R> FusedIterator for FirstResidualIter<I, R>
+    where
+        I: Iterator<Item = R>,
+        R: Try,
+    {
+    }
+
+    /// Internal state of [`FirstResidualIter`].
+    #[derive(Debug)]
+    enum State<I, Residual> {
+        Active(I),
+        FoundFirstResidual(Residual),
+        Exhausted,
+    }
+}
+
+mod sources {
+    //! Constructors of fallible iterators, designed to pair with [`FirstErr`](crate::FirstErr).
+
+    use core::{fmt, iter::FusedIterator};
+
+    /// Creates an iterator of `Result<T, E>` items, which calls the closure `f` to get each item.
+    ///
+    /// This is the fallible version of [`core::iter::from_fn()`]. The iterator will end when
+    /// `f` returns `None`.
+    ///
+    /// Notice: the returned [`TryFromFn`] does NOT implement [`FusedIterator`], it will just call
+    /// `f` again if `.next()` is called after an `Err` or a `None` was returned. So `f` may
+    /// produce more items after that. Call [`.fuse()`](Iterator::fuse) if this is not expected
+    /// after a `None`.
+    ///
+    /// Most [`FirstErr`](crate::FirstErr) methods stop calling `.next()` at the first `Err` or
+    /// the end. But the following ones scan past the `Err` items by design, so they will call `f`
+    /// again after it returned an `Err`:
+    ///
+    /// - [`errs_or_else()`](crate::FirstErr::errs_or_else),
+    ///   [`collect_errs_or_else()`](crate::FirstErr::collect_errs_or_else),
+    ///   [`first_and_last_err_or_else()`](crate::FirstErr::first_and_last_err_or_else) and
+    ///   [`worst_err_or_else()`](crate::FirstErr::worst_err_or_else), which look at all the `Err`
+    ///   items.
+    /// - [`first_err_or_else_recover()`](crate::FirstErr::first_err_or_else_recover), after an
+    ///   `Err` item is recovered.
+    /// - [`first_err_or_else()`](crate::FirstErr::first_err_or_else) and the methods built on
+    ///   [`FirstErrIter`](crate::FirstErrIter), after the first `Err` is taken by
+    ///   [`FirstErrIter::take_err()`](crate::FirstErrIter::take_err), to check the remaining
+    ///   items.
+    ///
+    /// If `f` can't continue after an `Err`, make it return `None` from then on.
+    ///
+    /// [`FusedIterator`]: core::iter::FusedIterator
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// let mut count = 0;
+    ///
+    /// let result = first_err::try_from_fn(|| {
+    ///     count += 1;
+    ///     match count {
+    ///         1..=3 => Some(Ok::<u8, u8>(count)),
+    ///         4 => Some(Err(count)),
+    ///         _ => None,
+    ///     }
+    /// })
+    /// .first_err_or_else(|iter| iter.sum::<u8>());
+    ///
+    /// assert_eq!(result, Err(4));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_from_fn<T, E, F>(f: F) -> TryFromFn<F>
+    where
+        F: FnMut() -> Option<Result<T, E>>,
+    {
+        TryFromFn { f }
+    }
+
+    /// An `Iterator` of `Result<T, E>` items which calls a closure to get each item.
+    ///
+    /// See [`try_from_fn()`] for more details.
+    #[derive(Clone)]
+    pub struct TryFromFn<F> {
+        f: F,
+    }
+
+    impl<F> fmt::Debug for TryFromFn<F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TryFromFn").finish_non_exhaustive()
+        }
+    }
+
+    impl<T, E, F> Iterator for TryFromFn<F>
+    where
+        F: FnMut() -> Option<Result<T, E>>,
+    {
+        type Item = Result<T, E>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            (self.f)()
+        }
+    }
+
+    /// Creates an iterator of `Result<T, E>` items, where each next item is computed from the
+    /// preceding one by the fallible closure `f`.
+    ///
+    /// This is the fallible version of [`core::iter::successors()`]. The iterator starts with
+    /// `first` (if any), then calls `f` on each produced value to get the successor:
+    ///
+    /// - `Ok(Some(t))`: `Ok(t)` will be the next item.
+    /// - `Ok(None)`: the iterator ends.
+    /// - `Err(e)`: `Err(e)` will be the next item, and then the iterator ends.
+    ///
+    /// Like [`core::iter::successors()`], the successor is computed when the preceding item is
+    /// produced.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // (parent, weight) of each node, node 0 is the root.
+    /// let nodes: [(Option<usize>, u32); 4] = [(None, 1), (Some(0), 2), (Some(1), 4), (Some(9), 8)];
+    ///
+    /// // Fails if the parent does not exist.
+    /// let parent = |id: &usize| match nodes[*id].0 {
+    ///     Some(parent) if parent >= nodes.len() => Err(parent),
+    ///     parent => Ok(parent),
+    /// };
+    ///
+    /// // Sum the weights from node 2 to the root.
+    /// let result = first_err::try_successors(Some(2), parent)
+    ///     .first_err_or_else(|iter| iter.map(|id| nodes[id].1).sum::<u32>());
+    /// assert_eq!(result, Ok(7));
+    ///
+    /// // Node 3 has a broken parent.
+    /// let result = first_err::try_successors(Some(3), parent)
+    ///     .first_err_or_else(|iter| iter.map(|id| nodes[id].1).sum::<u32>());
+    /// assert_eq!(result, Err(9));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_successors<T, E, F>(first: Option<T>, f: F) -> TrySuccessors<T, E, F>
+    where
+        F: FnMut(&T) -> Result<Option<T>, E>,
+    {
+        TrySuccessors {
+            next: first.map(Ok),
+            f,
+        }
+    }
+
+    /// An `Iterator` of `Result<T, E>` items, where each next item is computed from the
+    /// preceding one.
+    ///
+    /// See [`try_successors()`] for more details.
+    #[derive(Clone)]
+    pub struct TrySuccessors<T, E, F> {
+        next: Option<Result<T, E>>,
+        f: F,
+    }
+
+    impl<T, E, F> fmt::Debug for TrySuccessors<T, E, F>
+    where
+        T: fmt::Debug,
+        E: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TrySuccessors")
+                .field("next", &self.next)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<T, E, F> Iterator for TrySuccessors<T, E, F>
+    where
+        F: FnMut(&T) -> Result<Option<T>, E>,
+    {
+        type Item = Result<T, E>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let item = self.next.take()?;
+
+            if let Ok(t) = &item {
+                self.next = (self.f)(t).transpose();
+            }
+
+            Some(item)
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.next {
+                Some(Ok(_)) => (1, None),
+                Some(Err(_)) => (1, Some(1)),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<T, E, F> FusedIterator for TrySuccessors<T, E, F> where F: FnMut(&T) -> Result<Option<T>, E> {}
+
+    /// Creates an iterator of `Result<T, E>` items, which calls the closure `f` with a mutable
+    /// state to get each item.
+    ///
+    /// The closure `f` decides the next item:
+    ///
+    /// - `Ok(Some(t))`: `Ok(t)` will be the next item.
+    /// - `Ok(None)`: the iterator ends.
+    /// - `Err(e)`: `Err(e)` will be the next item, and then the iterator ends.
+    ///
+    /// After the end, `f` will never be called again, and the `state` will be dropped. This is
+    /// useful to wrap cursor or pagination APIs.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::FirstErr;
+    /// #
+    /// # fn main() {
+    /// // Fetch pages by a cursor, the page 3 is broken.
+    /// let fetch = |page: &mut u8| {
+    ///     *page += 1;
+    ///     match *page {
+    ///         1..=2 => Ok(Some(*page * 10)),
+    ///         3 => Err("broken page"),
+    ///         _ => Ok(None),
+    ///     }
+    /// };
+    ///
+    /// let result = first_err::try_unfold(0, fetch).first_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Err("broken page"));
+    ///
+    /// let result = first_err::try_unfold(3, fetch).first_err_or_else(|iter| iter.sum::<u8>());
+    /// assert_eq!(result, Ok(0));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_unfold<St, T, E, F>(state: St, f: F) -> TryUnfold<St, F>
+    where
+        F: FnMut(&mut St) -> Result<Option<T>, E>,
+    {
+        TryUnfold {
+            state: Some(state),
+            f,
+        }
+    }
+
+    /// An `Iterator` of `Result<T, E>` items, which calls a closure with a mutable state to get
+    /// each item.
+    ///
+    /// See [`try_unfold()`] for more details.
+    #[derive(Clone)]
+    pub struct TryUnfold<St, F> {
+        /// `None` if the iterator is ended.
+        state: Option<St>,
+        f: F,
+    }
+
+    impl<St, F> fmt::Debug for TryUnfold<St, F>
+    where
+        St: fmt::Debug,
+    {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TryUnfold")
+                .field("state", &self.state)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<St, T, E, F> Iterator for TryUnfold<St, F>
+    where
+        F: FnMut(&mut St) -> Result<Option<T>, E>,
+    {
+        type Item = Result<T, E>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let state = self.state.as_mut()?;
+
+            let item = (self.f)(state).transpose();
+            if !matches!(item, Some(Ok(_))) {
+                self.state = None;
+            }
+
+            item
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.state {
+                Some(_) => (0, None),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<St, T, E, F> FusedIterator for TryUnfold<St, F> where F: FnMut(&mut St) -> Result<Option<T>, E> {}
+}
+
+mod decode {
+    //! Decoders built on [`FirstErr`](crate::FirstErr).
+
+    use crate::{FirstErr, FirstErrIter};
+    use core::{convert::Infallible, iter::FusedIterator};
+
+    /// Decodes UTF-8 bytes incrementally, and returns the first decoding error, or a value
+    /// produced by the `f` closure.
+    ///
+    /// The argument iterator of the `f` closure will producing the decoded `char`s, but will stop
+    /// when encounter the first invalid or truncated sequence. No allocation is required.
+    ///
+    /// See [`try_decode_utf8_or_else()`] if the bytes come from a fallible source.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::Utf8DecodeError;
+    /// #
+    /// # fn main() {
+    /// let result = first_err::decode_utf8_or_else("héllo".bytes(), |chars| chars.count());
+    /// assert_eq!(result, Ok(5));
+    ///
+    /// let result = first_err::decode_utf8_or_else(*b"ab\xFFcd", |chars| chars.count());
+    /// assert_eq!(result, Err(Utf8DecodeError::Invalid { index: 2 }));
+    ///
+    /// let result = first_err::decode_utf8_or_else(*b"ab\xE2\x82", |chars| chars.count());
+    /// assert_eq!(result, Err(Utf8DecodeError::Truncated { index: 2 }));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn decode_utf8_or_else<B, O, F>(bytes: B, f: F) -> Result<O, Utf8DecodeError>
+    where
+        B: IntoIterator<Item = u8>,
+        F: FnOnce(&mut FirstErrIter<DecodeUtf8<OkBytes<B::IntoIter>>, char, Utf8DecodeError>) -> O,
+    {
+        try_decode_utf8_or_else(bytes.into_iter().map(Ok as fn(u8) -> _), f)
+    }
+
+    /// Decodes UTF-8 bytes from a fallible source incrementally, and returns the first decoding
+    /// error or source error, or a value produced by the `f` closure.
+    ///
+    /// This is the fallible source version of [`decode_utf8_or_else()`]. The source error will
+    /// be returned with the byte index it took place.
+    ///
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use first_err::Utf8DecodeError;
+    /// #
+    /// # fn main() {
+    /// let bytes = [Ok(b'a'), Ok(0xC3), Ok(0xA9), Err("disconnected"), Ok(b'b')];
+    ///
+    /// let result = first_err::try_decode_utf8_or_else(bytes, |chars| chars.count());
+    /// assert_eq!(
+    ///     result,
+    ///     Err(Utf8DecodeError::Source { index: 3, error: "disconnected" })
+    /// );
+    /// # }
+    /// ```
+    #[inline]
+    pub fn try_decode_utf8_or_else<B, E, O, F>(bytes: B, f: F) -> Result<O, Utf8DecodeError<E>>
+    where
+        B: IntoIterator<Item = Result<u8, E>>,
+        F: FnOnce(&mut FirstErrIter<DecodeUtf8<B::IntoIter>, char, Utf8DecodeError<E>>) -> O,
+    {
+        DecodeUtf8::new(bytes.into_iter()).first_err_or_else(f)
+    }
+
+    /// The infallible byte source used by [`decode_utf8_or_else()`].
+    pub type OkBytes<I> = core::iter::Map<I, fn(u8) -> Result<u8, Infallible>>;
+
+    /// The error type of [`decode_utf8_or_else()`] and [`try_decode_utf8_or_else()`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Utf8DecodeError<E = Infallible> {
+        /// An invalid sequence found, contains the byte index where this sequence starts.
+        Invalid { index: usize },
+
+        /// The source ended in the middle of a multi-byte sequence, contains the byte index where
+        /// this sequence starts.
+        Truncated { index: usize },
+
+        /// The source produced an error, contains the byte index of it.
+        Source { index: usize, error: E },
+    }
+
+    /// An `Iterator` which decodes UTF-8 bytes from another iterator.
+    ///
+    /// It producing `Err` once on the first error, then ends.
+    ///
+    /// See [`decode_utf8_or_else()`] for more details.
+    #[derive(Debug)]
+    pub struct DecodeUtf8<I> {
+        /// `None` if ended.
+        inner: Option<I>,
+
+        /// Index of the next byte.
+        index: usize,
+    }
+
+    impl<I> DecodeUtf8<I> {
+        #[inline]
+        fn new(inner: I) -> Self {
+            Self {
+                inner: Some(inner),
+                index: 0,
+            }
+        }
+    }
+
+    impl<I, E> DecodeUtf8<I>
+    where
+        I: Iterator<Item = Result<u8, E>>,
+    {
+        /// Decode one `char`, assume the inner iterator is still active.
+        #[inline]
+        fn decode(&mut self) -> Option<Result<char, Utf8DecodeError<E>>> {
+            let start = self.index;
+
+            let lead = match self.next_byte()? {
+                Ok(b) => b,
+                Err(e) => return Some(Err(e)),
+            };
+
+            // Width of the sequence and the valid range of the second byte.
+            let (width, second) = match lead {
+                0x00..=0x7F => return Some(Ok(char::from(lead))),
+                0xC2..=0xDF => (2, 0x80..=0xBF),
+                0xE0 => (3, 0xA0..=0xBF),
+                0xED => (3, 0x80..=0x9F),
+                0xE1..=0xEF => (3, 0x80..=0xBF),
+                0xF0 => (4, 0x90..=0xBF),
+                0xF4 => (4, 0x80..=0x8F),
+                0xF1..=0xF3 => (4, 0x80..=0xBF),
+                _ => return Some(Err(Utf8DecodeError::Invalid { index: start })),
+            };
+
+            let mut code = u32::from(lead) & (0x7F >> width);
+            for i in 1..width {
+                let b = match self.next_byte() {
+                    Some(Ok(b)) => b,
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => return Some(Err(Utf8DecodeError::Truncated { index: start })),
+                };
+
+                let valid = if i == 1 {
+                    second.contains(&b)
+                } else {
+                    (0x80..=0xBF).contains(&b)
+                };
+                if !valid {
+                    return Some(Err(Utf8DecodeError::Invalid { index: start }));
+                }
+
+                code = (code << 6) | u32::from(b & 0x3F);
+            }
+
+            // Always succeed, the ranges above only allow valid scalar values.
+            Some(char::from_u32(code).ok_or(Utf8DecodeError::Invalid { index: start }))
+        }
+
+        /// Take the next byte, and convert the source error.
+        #[inline]
+        fn next_byte(&mut self) -> Option<Result<u8, Utf8DecodeError<E>>> {
+            let index = self.index;
+            let res = self.inner.as_mut()?.next()?;
+            self.index += 1;
+
+            Some(res.map_err(|error| Utf8DecodeError::Source { index, error }))
+        }
+    }
+
+    impl<I, E> Iterator for DecodeUtf8<I>
+    where
+        I: Iterator<Item = Result<u8, E>>,
+    {
+        type Item = Result<char, Utf8DecodeError<E>>;
+
+        #[inline]
+        fn next(&mut self) -> Option<Self::Item> {
+            let item = self.decode();
+
+            if !matches!(item, Some(Ok(_))) {
+                self.inner = None;
+            }
+
+            item
+        }
+
+        #[inline]
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match &self.inner {
+                Some(inner) => (0, inner.size_hint().1),
+                None => (0, Some(0)),
+            }
+        }
+    }
+
+    impl<I, E> FusedIterator for DecodeUtf8<I> where I: Iterator<Item = Result<u8, E>> {}
+}
+
+mod array {
+    //! Helpers to build arrays without allocation.
+
+    use core::mem::{ManuallyDrop, MaybeUninit};
+    use core::ptr;
+
+    /// Build an array by calling `f` with each index in order.
+    ///
+    /// Stop at the first `None` returned by `f`, drop the elements already built, and return
+    /// the count of them as `Err`.
+    #[inline]
+    pub(crate) fn try_from_fn<T, const N: usize>(
+        mut f: impl FnMut(usize) -> Option<T>,
+    ) -> Result<[T; N], usize> {
+        let mut guard = Guard {
+            array: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        };
+
+        while guard.len < N {
+            match f(guard.len) {
+                Some(value) => {
+                    guard.array[guard.len].write(value);
+                    guard.len += 1;
+                }
+                None => return Err(guard.len),
+            }
+        }
+
+        // All elements are initialized, take them out without dropping.
+        let guard = ManuallyDrop::new(guard);
+
+        // SAFETY: all `N` elements are initialized, and `[MaybeUninit<T>; N]` has the same
+        // layout as `[T; N]`.
+        Ok(unsafe { ptr::read(&guard.array as *const [MaybeUninit<T>; N] as *const [T; N]) })
+    }
+
+    /// Drop the initialized part of the array, when the building is interrupted or panicked.
+    struct Guard<T, const N: usize> {
+        array: [MaybeUninit<T>; N],
+        len: usize,
+    }
+
+    impl<T, const N: usize> Drop for Guard<T, N> {
+        fn drop(&mut self) {
+            let initialized = &mut self.array[..self.len];
+
+            // SAFETY: the first `len` elements are initialized, and `MaybeUninit<T>` has the
+            // same layout as `T`.
+            unsafe { ptr::drop_in_place(initialized as *mut [MaybeUninit<T>] as *mut [T]) }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+
+    /// A fixed-capacity buffer to format values without allocation.
+    struct FmtBuf {
+        buf: [u8; 128],
+        len: usize,
+    }
+
+    impl FmtBuf {
+        fn format(args: fmt::Arguments<'_>) -> Self {
+            let mut me = Self {
+                buf: [0; 128],
+                len: 0,
+            };
+            fmt::Write::write_fmt(&mut me, args).unwrap();
+            me
+        }
+
+        fn as_str(&self) -> &str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl fmt::Write for FmtBuf {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.buf
+                .get_mut(self.len..end)
+                .ok_or(fmt::Error)?
+                .copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    mod test_first_err {
+        //! Test first_err_* methods.
+
+        use crate::{ArrayCollectError, FirstErr, FirstErrIter};
+
+        #[test]
+        fn _or_else_with_1_layer_data_and_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter1| iter1.sum::<u8>());
+
+            assert_eq!(ans, Ok(10));
+        }
+
+        #[test]
+        fn _or_else_with_1_layer_data_and_with_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter1| iter1.sum::<u8>());
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_with_2_layer_data_and_outmost_err_in_layer_1() {
+            let ans = [
+                Ok::<Result<u8, u8>, u8>(Ok(0)),
+                Ok(Err(1)),
+                Err(2),
+                Ok(Ok(3)),
+                Ok(Ok(4)),
+            ]
+            .into_iter()
+            .first_err_or_else(|iter1| {
+                iter1
+                    // could chain other ops
+                    .first_err_or_else(|iter2| iter2.sum::<u8>())
+            });
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_with_2_layer_data_and_outmost_err_in_layer_2() {
+            let ans = [
+                Ok::<Result<u8, u8>, u8>(Ok(0)),
+                Ok(Ok(1)),
+                Ok(Err(2)),
+                Ok(Err(3)),
+                Ok(Ok(4)),
+            ]
+            .into_iter()
+            .first_err_or_else(|iter1| {
+                iter1
+                    // could chain other ops
+                    .first_err_or_else(|iter2| iter2.sum::<u8>())
+            });
+
+            assert_eq!(ans, Ok(Err(2)));
+        }
+
+        #[test]
+        fn _or_else_with_3_layer_data_and_outmost_err_in_layer_2() {
+            let ans = [
+                Ok::<Result<Result<u8, u8>, u8>, u8>(Ok(Ok(0))),
+                Ok(Ok(Ok(1))),
+                Ok(Ok(Err(2))),
+                Ok(Err(3)),
+                Ok(Ok(Ok(4))),
+            ]
+            .into_iter()
+            .first_err_or_else(|iter1| {
+                iter1
+                    // could chain other ops
+                    .first_err_or_else(|iter2| {
+                        iter2
+                            // could chain other ops
+                            .first_err_or_else(|iter3| iter3.sum::<u8>())
+                    })
+            });
+
+            assert_eq!(ans, Ok(Err(3)));
+        }
+
+        #[test]
+        fn _or_else_not_need_to_consume_iter_manually() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Err(2)]
+                .into_iter()
+                .first_err_or_else(|_iter| {});
+
+            assert_eq!(ans, Err(1));
+        }
+
+        /// In most cases, API users should not be concerned about how many times the original
+        /// iterator's `.next()` method is called, as it gets consumed after
+        /// `first_err_or_else()` is called.
+        ///
+        /// However, if the inner iterator has some side-effect, this behavior is still
+        /// observable, and users may rely on it.
+        ///
+        /// This test is designed to ensure that this behavior remains consistent even when
+        /// the code changes.
+        #[test]
+        fn _or_else_never_call_next_on_orig_iter_after_first_err_found() {
+            let mut orig_iter_next_count = 0;
+
+            [Ok::<u8, u8>(0), Err(1), Err(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_err_or_else(|iter| {
+                    // exhaust whole iter.
+                    for _ in &mut *iter {}
+
+                    // call iter.next() after the iter already exhausted.
+                    assert_eq!(iter.next(), None);
+                })
+                .ok();
+
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_else_use_lazy_evaluation() {
+            use core::cell::{Cell, RefCell};
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            enum Trace {
+                None,
+                Outer(Result<u8, u8>),
+                Inner(u8),
+            }
+
+            // if index >= N, it will panic.
+            fn record_trace<const N: usize>(
+                traces: &RefCell<[Trace; N]>,
+                idx: &Cell<usize>,
+                v: Trace,
+            ) {
+                let i = idx.get();
+                traces.borrow_mut()[i] = v;
+                idx.set(i + 1);
+            }
+
+            // already known N = 5 within [_; N] in this test case.
+            // We don't use Vec here just bacause want to avoid `alloc` crate.
+            let traces = RefCell::new([Trace::None; 5]);
+
+            let index = Cell::new(0);
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .iter()
+                .cloned()
+                // record value from outer
+                .inspect(|&res| record_trace(&traces, &index, Trace::Outer(res)))
+                .first_err_or_else(|iter| {
+                    iter
+                        // record value from inner
+                        .inspect(|&n| record_trace(&traces, &index, Trace::Inner(n)))
+                        .sum::<u8>()
+                });
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(
+                traces.into_inner(),
+                [
+                    Trace::Outer(Ok(0)),
+                    Trace::Inner(0),
+                    Trace::Outer(Ok(1)),
+                    Trace::Inner(1),
+                    Trace::Outer(Err(2))
+                ]
+            );
+        }
+
+        #[test]
+        fn _or_else_with_non_fused_iterator() {
+            struct NonFusedIter {
+                curr: u32,
+            }
+
+            impl NonFusedIter {
+                fn new() -> Self {
+                    Self { curr: 0 }
+                }
+            }
+
+            impl Iterator for NonFusedIter {
+                type Item = Result<u32, u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Ok(tmp)),
+                        1 => None,
+                        2 => Some(Err(tmp)),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            let ans = NonFusedIter::new().first_err_or_else(|iter| iter.sum::<u32>());
+
+            assert_eq!(ans, Ok(0));
+        }
+
+        #[test]
+        fn _or_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or("no err");
+
+            assert_eq!(ans, Ok("no err"));
+        }
+
+        #[test]
+        fn _or_with_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or("no err");
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_try_without_err_and_closure_produce_ok() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_try(|iter| iter.nth(1).ok_or(1));
+
+            assert_eq!(ans, Ok(1));
+        }
+
+        #[test]
+        fn _or_try_without_err_and_closure_produce_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_try(|iter| iter.nth(100).ok_or(100));
+
+            assert_eq!(ans, Err(100));
+        }
+
+        #[test]
+        fn _or_try_with_err_and_closure_produce_ok() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_try(|iter| iter.nth(1).ok_or(1));
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_try_with_err_and_closure_produce_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_try(|iter| iter.nth(100).ok_or(100));
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_peekable_peek_at_upcoming_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else_peekable(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.peek(), Some(&1));
+                    assert_eq!(iter.next(), Some(1));
+
+                    // next item is the first `Err`, peek just see the end.
+                    assert_eq!(iter.peek(), None);
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_peekable_peek_then_return() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_err_or_else_peekable(|iter| {
+                    // peeked value can be modified, but never be consumed.
+                    *iter.peek_mut().unwrap() += 10;
+                    iter.peek().copied()
+                });
+
+            assert_eq!(ans, Ok(Some(10)));
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_else_peekable_peek_then_return_with_err_after_peeked() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else_peekable(|iter| iter.peek().copied());
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_peekable_next_if() {
+            let ans = [Ok::<u8, u8>(1), Ok(1), Ok(2), Ok(1)]
+                .into_iter()
+                .first_err_or_else_peekable(|iter| {
+                    let mut ones = 0;
+                    while iter.next_if_eq(&1).is_some() {
+                        ones += 1;
+                    }
+
+                    (ones, iter.sum::<u8>())
+                });
+
+            assert_eq!(ans, Ok((2, 3)));
+        }
+
+        #[test]
+        fn _or_else_rev_report_first_err_from_back() {
+            let cases = [
+                ([Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3)], Ok::<u32, u8>(3210)),
+                ([Err(0), Ok(1), Ok(2), Ok(3)], Err(0)),
+                ([Ok(0), Ok(1), Ok(2), Err(3)], Err(3)),
+                ([Ok(0), Err(1), Err(2), Ok(3)], Err(2)),
+                ([Err(0), Err(1), Err(2), Err(3)], Err(3)),
+            ];
+
+            for (case, expected) in cases {
+                let ans = case
+                    .into_iter()
+                    .first_err_or_else_rev(|iter| iter.fold(0u32, |acc, n| acc * 10 + n as u32));
+
+                assert_eq!(ans, expected);
+            }
+        }
+
+        #[test]
+        fn _or_else_rev_never_scan_front_after_first_err_from_back() {
+            let mut orig_iter_next_back_count = 0;
+
+            let ans = [Err::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_back_count += 1) // side-effect
+                .first_err_or_else_rev(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(orig_iter_next_back_count, 2);
+        }
+
+        #[test]
+        fn _or_else_rev_with_non_fused_back_end() {
+            struct NonFusedBackIter {
+                curr: u32,
+            }
+
+            impl Iterator for NonFusedBackIter {
+                type Item = Result<u32, u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    unreachable!("front-end should never be used")
+                }
+            }
+
+            impl DoubleEndedIterator for NonFusedBackIter {
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Ok(tmp)),
+                        1 => None,           // after produce a None ...
+                        2 => Some(Err(tmp)), // it still can produce more items
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            let ans = NonFusedBackIter { curr: 0 }.first_err_or_else_rev(|iter| iter.sum::<u32>());
+
+            assert_eq!(ans, Ok(0));
+        }
+
+        #[test]
+        fn _or_else_chunks_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4), Ok(5)]
+                .into_iter()
+                .first_err_or_else_chunks(|iter| {
+                    assert_eq!(iter.size_hint(), (2, Some(2)));
+                    iter.map(|[a, b, c]| a + b + c).max()
+                });
+
+            assert_eq!(ans, Ok(Some(12)));
+        }
+
+        #[test]
+        fn _or_else_chunks_with_partial_trailing_chunk() {
+            use crate::ChunkError;
+            use core::cell::Cell;
+
+            struct DropCounter<'a>(&'a Cell<usize>);
+
+            impl Drop for DropCounter<'_> {
+                fn drop(&mut self) {
+                    self.0.set(self.0.get() + 1);
+                }
+            }
+
+            let drops = Cell::new(0);
+
+            // the closure consume the partial chunk.
+            let ans = (0..5)
+                .map(|_| Ok::<_, u8>(DropCounter(&drops)))
+                .first_err_or_else_chunks(|iter| {
+                    let [_, _] = iter.next().unwrap();
+                    let [_, _] = iter.next().unwrap();
+                    assert!(iter.next().is_none());
+                    assert_eq!(drops.get(), 5);
+                });
+
+            assert!(matches!(ans, Err(ChunkError::Partial(1))));
+            assert_eq!(drops.get(), 5);
+
+            // the closure consume nothing.
+            let ans = (0..5)
+                .map(|_| Ok::<_, u8>(DropCounter(&drops)))
+                .first_err_or_else_chunks(|_: &mut crate::FirstErrChunks<_, _, _, 2>| {});
+
+            assert!(matches!(ans, Err(ChunkError::Partial(1))));
+            assert_eq!(drops.get(), 10);
+        }
+
+        #[test]
+        fn _or_else_chunks_with_err_in_mid_chunk() {
+            use crate::ChunkError;
+            use core::cell::Cell;
+
+            struct DropCounter<'a>(&'a Cell<usize>);
+
+            impl Drop for DropCounter<'_> {
+                fn drop(&mut self) {
+                    self.0.set(self.0.get() + 1);
+                }
+            }
+
+            let drops = Cell::new(0);
+
+            let ans = [Ok(0), Ok(1), Ok(2), Ok(3), Err(4), Ok(5)]
+                .into_iter()
+                .map(|res| res.map(|_| DropCounter(&drops)))
+                .first_err_or_else_chunks(|iter| {
+                    let [_, _, _] = iter.next().unwrap();
+                    assert_eq!(drops.get(), 3);
+
+                    // the buffered value in the interrupted chunk is dropped.
+                    assert!(iter.next().is_none());
+                    assert_eq!(drops.get(), 4);
+                });
+
+            assert!(matches!(ans, Err(ChunkError::Err(4))));
+            assert_eq!(drops.get(), 4);
+        }
+
+        #[test]
+        fn _or_else_chunks_err_take_priority_over_partial_chunk() {
+            use crate::ChunkError;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else_chunks(|iter| iter.map(|[a, b]| a + b).sum::<u8>());
+
+            assert_eq!(ans, Err(ChunkError::Err(3)));
+        }
+
+        #[test]
+        fn _or_eq_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Ok(1), Ok(2)]);
+            assert_eq!(ans, Ok(true));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Ok(42), Ok(2)]);
+            assert_eq!(ans, Ok(false));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Ok(1), Ok(2)]);
+            assert_eq!(ans, Ok(false));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Ok(1)]);
+            assert_eq!(ans, Ok(false));
+        }
+
+        #[test]
+        fn _or_eq_err_before_mismatch_wins() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(42)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Ok(1), Ok(2)]);
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(42)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Err(1), Ok(2)]);
+            assert_eq!(ans, Err(1));
+        }
+
+        #[test]
+        fn _or_eq_err_at_mismatch_position_wins() {
+            let ans = [Ok::<u8, u8>(0), Ok(42)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Err(1)]);
+            assert_eq!(ans, Err(1));
+
+            // length mismatch at position 1.
+            let ans = [Ok::<u8, u8>(0)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Err(1)]);
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0), Err(1)]
+                .into_iter()
+                .first_err_or_eq([Ok(0)]);
+            assert_eq!(ans, Err(1));
+
+            // both are `Err`, the current iterator wins.
+            let ans = [Ok::<u8, u8>(0), Err(1)]
+                .into_iter()
+                .first_err_or_eq([Ok(0), Err(2)]);
+            assert_eq!(ans, Err(1));
+        }
+
+        #[test]
+        fn _or_eq_err_after_mismatch_is_ignored() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(42), Err(4)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_err_or_eq([Ok(0), Ok(1), Ok(2), Ok(3), Err(4)]);
+
+            assert_eq!(ans, Ok(false));
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_cmp_without_err() {
+            use core::cmp::Ordering;
+
+            type Case<'a> = (&'a [Result<u8, u8>], &'a [Result<u8, u8>]);
+
+            let cases: [Case; 5] = [
+                (&[Ok(0), Ok(1)], &[Ok(0), Ok(1)]),
+                (&[Ok(0), Ok(1)], &[Ok(0), Ok(2)]),
+                (&[Ok(0), Ok(3)], &[Ok(0), Ok(2)]),
+                (&[Ok(0)], &[Ok(0), Ok(1)]),
+                (&[Ok(0), Ok(1)], &[]),
+            ];
+
+            for (a, b) in cases {
+                let expected = a.iter().flatten().cmp(b.iter().flatten());
+
+                assert_eq!(
+                    a.iter().cloned().first_err_or_cmp(b.iter().cloned()),
+                    Ok(expected)
+                );
+            }
+
+            assert_eq!(
+                [Ok::<u8, u8>(0), Ok(1)]
+                    .into_iter()
+                    .first_err_or_cmp([Ok(0), Ok(2)]),
+                Ok(Ordering::Less),
+            );
+        }
+
+        #[test]
+        fn _or_cmp_ordering_decided_before_err() {
+            use core::cmp::Ordering;
+
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_err_or_cmp([Ok(0), Ok(0), Err(3)]);
+
+            assert_eq!(ans, Ok(Ordering::Greater));
+            assert_eq!(orig_iter_next_count, 2);
+
+            // `Err` after the shorter one exhausted.
+            let ans = [Ok::<u8, u8>(0)]
+                .into_iter()
+                .first_err_or_cmp([Ok(0), Ok(1), Err(3)]);
+
+            assert_eq!(ans, Ok(Ordering::Less));
+        }
+
+        #[test]
+        fn _or_cmp_err_appears_first() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(0)]
+                .into_iter()
+                .first_err_or_cmp([Ok(0), Ok(0), Ok(1)]);
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0), Ok(2)]
+                .into_iter()
+                .first_err_or_cmp([Ok(0), Err(1)]);
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0), Err(1)]
+                .into_iter()
+                .first_err_or_cmp([Ok(0), Err(2)]);
+            assert_eq!(ans, Err(1));
+        }
+
+        #[test]
+        fn _or_is_sorted_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(1), Ok(2)]
+                .into_iter()
+                .first_err_or_is_sorted();
+            assert_eq!(ans, Ok(true));
+
+            let ans = [Ok::<u8, u8>(0), Ok(2), Ok(1), Ok(3)]
+                .into_iter()
+                .first_err_or_is_sorted();
+            assert_eq!(ans, Ok(false));
+
+            let ans = core::iter::empty::<Result<u8, u8>>().first_err_or_is_sorted();
+            assert_eq!(ans, Ok(true));
+        }
+
+        #[test]
+        fn _or_is_sorted_with_err_before_unsorted_pair() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(3), Ok(2)]
+                .into_iter()
+                .first_err_or_is_sorted();
+            assert_eq!(ans, Err(1));
+
+            // `Err` at the first position.
+            let ans = [Err::<u8, u8>(1), Ok(3), Ok(2)]
+                .into_iter()
+                .first_err_or_is_sorted();
+            assert_eq!(ans, Err(1));
+
+            // sorted until the end, so the `Err` always wins.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_is_sorted();
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _or_is_sorted_with_err_after_unsorted_pair() {
+            let mut orig_iter_next_count = 0;
+            let mut compare_count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(2), Ok(1), Err(3), Ok(4)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_err_or_is_sorted_by(|a, b| {
+                    compare_count += 1;
+                    a <= b
+                });
+
+            assert_eq!(ans, Ok(false));
+            assert_eq!(orig_iter_next_count, 3);
+            assert_eq!(compare_count, 2);
+        }
+
+        #[test]
+        fn _or_else_recover_with_all_errs_recoverable() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else_recover(Ok, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(6));
+        }
+
+        #[test]
+        fn _or_else_recover_with_first_recoverable_then_not() {
+            let recover = |e: u8| if e < 10 { Ok(e) } else { Err(e) };
+
+            // unrecoverable `Err` found by the closure.
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(10), Ok(3), Err(11)]
+                .into_iter()
+                .first_err_or_else_recover(recover, |iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.next(), Some(1));
+                    assert_eq!(iter.next(), Some(2));
+                    assert_eq!(iter.next(), None);
+                });
+            assert_eq!(ans, Err(10));
+
+            // unrecoverable `Err` found after the closure.
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(10), Ok(3), Err(11)]
+                .into_iter()
+                .first_err_or_else_recover(recover, |_| {});
+            assert_eq!(ans, Err(10));
+        }
+
+        #[test]
+        fn _or_else_recover_applied_to_errs_in_drain() {
+            let mut recover_count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else_recover(
+                    |e| {
+                        recover_count += 1;
+                        Ok(e)
+                    },
+                    |iter| iter.next(),
+                );
+
+            assert_eq!(ans, Ok(Some(0)));
+            assert_eq!(recover_count, 2);
+        }
+
+        #[test]
+        fn _or_else_recover_changing_the_value() {
+            let ans = [Ok::<u8, u8>(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else_recover(|e| Ok(e * 100), |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(204));
+        }
+
+        #[test]
+        fn _first_and_last_or_else_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _first_and_last_or_else_with_single_err() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+                .into_iter()
+                .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err((1, None)));
+
+            // the only `Err` is the final item.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2)]
+                .into_iter()
+                .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err((2, None)));
+        }
+
+        #[test]
+        fn _first_and_last_or_else_with_two_errs() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_and_last_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err((1, Some(3))));
+        }
+
+        #[test]
+        fn _first_and_last_or_else_with_many_errs() {
+            let mut orig_iter_next_count = 0;
+
+            // the last `Err` is the final item.
+            let ans = [Err::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4), Err(5)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_and_last_err_or_else(|_| {});
+
+            assert_eq!(ans, Err((0, Some(5))));
+            assert_eq!(orig_iter_next_count, 6);
+        }
+
+        #[test]
+        fn _or_else_flat_with_uneven_chunks() {
+            let ans = [Ok::<&[u8], u8>(&[0, 1, 2]), Ok(&[]), Ok(&[3]), Ok(&[4, 5])]
+                .into_iter()
+                .first_err_or_else_flat(|iter| {
+                    assert_eq!(iter.next(), Some(&0));
+                    iter.fold(0u32, |acc, &n| acc * 10 + n as u32)
+                });
+
+            assert_eq!(ans, Ok(12345));
+        }
+
+        #[test]
+        fn _or_else_flat_with_err_between_chunks() {
+            let ans = [
+                Ok::<&[u8], u8>(&[0, 1]),
+                Ok(&[2]),
+                Err(3),
+                Ok(&[4, 5]),
+                Err(6),
+            ]
+            .into_iter()
+            .first_err_or_else_flat(|iter| {
+                assert_eq!(iter.next(), Some(&0));
+                assert_eq!(iter.next(), Some(&1));
+                assert_eq!(iter.next(), Some(&2));
+                assert_eq!(iter.next(), None);
+            });
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _or_else_flat_never_iterate_inside_chunks_in_drain() {
+            use core::cell::Cell;
+
+            let elem_count = Cell::new(0);
+            let mut chunk_count = 0;
+
+            let ans = [Ok::<&[u8], u8>(&[0, 1, 2]), Ok(&[3, 4]), Ok(&[5])]
+                .into_iter()
+                .inspect(|_| chunk_count += 1) // side-effect
+                .map(|res| {
+                    res.map(|chunk| {
+                        chunk
+                            .iter()
+                            .inspect(|_| elem_count.set(elem_count.get() + 1))
+                    })
+                })
+                .first_err_or_else_flat(|iter| iter.next());
+
+            assert_eq!(ans, Ok(Some(&0)));
+            assert_eq!(chunk_count, 3);
+            assert_eq!(elem_count.get(), 1);
+        }
+
+        #[test]
+        fn _first_err_ref_or_else_without_err() {
+            let results = [Ok::<u8, u8>(0), Ok(1), Ok(2)];
+
+            let ans = results
+                .iter()
+                .first_err_ref_or_else(|iter| iter.copied().sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _first_err_ref_or_else_return_ref_from_source() {
+            let results = [Ok::<u8, u8>(0), Err(1), Err(2)];
+
+            let ans = results
+                .iter()
+                .first_err_ref_or_else(|iter| iter.copied().sum::<u8>());
+
+            // the returned reference points into `results` itself.
+            let err = ans.unwrap_err();
+            assert!(core::ptr::eq(err, results[1].as_ref().unwrap_err()));
+        }
+
+        #[test]
+        fn _first_err_copied_or_else() {
+            let results = [Ok::<u8, u8>(0), Ok(1), Err(2), Err(3)];
+
+            let ans = results
+                .iter()
+                .first_err_copied_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _first_err_cloned_or_else_clone_only_consumed_items() {
+            use core::cell::Cell;
+
+            #[derive(Debug, PartialEq)]
+            struct CloneCounter<'a>(u8, &'a Cell<usize>);
+
+            impl Clone for CloneCounter<'_> {
+                fn clone(&self) -> Self {
+                    self.1.set(self.1.get() + 1);
+                    Self(self.0, self.1)
+                }
+            }
+
+            let ok_clones = Cell::new(0);
+            let err_clones = Cell::new(0);
+            let ok = |n| Ok(CloneCounter(n, &ok_clones));
+            let err = |n| Err(CloneCounter(n, &err_clones));
+
+            // the closure consumed nothing, but drain meet many `Ok` and `Err` items.
+            let results = [ok(0), ok(1), err(2), ok(3), err(4), err(5)];
+            let ans = results.iter().first_err_cloned_or_else(|_| ());
+
+            assert_eq!(ans.unwrap_err().0, 2);
+            assert_eq!(ok_clones.get(), 0);
+            assert_eq!(err_clones.get(), 1);
+
+            // the closure consumed all values.
+            ok_clones.set(0);
+            err_clones.set(0);
+            let ans = results
+                .iter()
+                .first_err_cloned_or_else(|iter| iter.map(|c| c.0).sum::<u8>());
+
+            assert_eq!(ans.unwrap_err().0, 2);
+            assert_eq!(ok_clones.get(), 2);
+            assert_eq!(err_clones.get(), 1);
+        }
+
+        #[test]
+        fn _first_err_cloned_or_else_iter_methods() {
+            let results = [Ok::<u8, u8>(0), Ok(1), Err(2), Err(3)];
+
+            let ans = results.iter().first_err_cloned_or_else(|iter| {
+                let sum = iter.fold_ok(0, |acc, n| acc + n);
+                assert_eq!(iter.ok_count(), 2);
+                assert_eq!(iter.source_position(), 3);
+                assert!(iter.found_err());
+                assert_eq!(iter.peek_err(), Some(&2));
+
+                let taken = iter.take_err().unwrap();
+                assert!(core::ptr::eq(taken, results[2].as_ref().unwrap_err()));
+                assert_eq!(iter.check_rest(), Err(&3));
+                sum
+            });
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _into_ok_iter() {
+            use core::convert::Infallible;
+
+            let mut iter = [Ok::<u8, Infallible>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .into_ok_iter();
+
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.next_back(), Some(2));
+            assert_eq!(iter.sum::<u8>(), 1);
+        }
+
+        #[cfg(feature = "nightly-never")]
+        #[test]
+        fn _into_ok_iter_with_never_type() {
+            let iter = [Ok::<u8, !>(0), Ok(1), Ok(2)].into_iter().into_ok_iter();
+
+            assert_eq!(iter.sum::<u8>(), 3);
+        }
+
+        #[test]
+        fn _into_ok_iter_is_fused_when_inner_is_fused() {
+            use core::{convert::Infallible, iter::FusedIterator};
+
+            fn assert_fused<I: FusedIterator>(_: &I) {}
+
+            let iter = [Ok::<u8, Infallible>(0)].into_iter().into_ok_iter();
+
+            assert_fused(&iter);
+        }
+
+        #[test]
+        fn _try_collect_array_exact_fit() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .try_collect_array::<3, _, _>();
+
+            assert_eq!(ans, Ok([0, 1, 2]));
+        }
+
+        #[test]
+        fn _try_collect_array_leave_extra_items_untouched() {
+            let mut orig_iter_next_count = 0;
+
+            let mut iter = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1);
+
+            let ans = iter.by_ref().try_collect_array::<2, _, _>();
+            assert_eq!(ans, Ok([0, 1]));
+
+            assert_eq!(iter.next(), Some(Err(2)));
+            drop(iter);
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _try_collect_array_with_err_at_last_position() {
+            use core::cell::Cell;
+
+            #[derive(Debug)]
+            struct DropCounter<'a>(&'a Cell<usize>);
+
+            impl Drop for DropCounter<'_> {
+                fn drop(&mut self) {
+                    self.0.set(self.0.get() + 1);
+                }
+            }
+
+            let drop_count = Cell::new(0);
+
+            let ans = [
+                Ok(DropCounter(&drop_count)),
+                Ok(DropCounter(&drop_count)),
+                Err(2),
+            ]
+            .into_iter()
+            .try_collect_array::<3, _, _>();
+
+            assert!(matches!(
+                ans,
+                Err(ArrayCollectError::Err { index: 2, error: 2 })
+            ));
+            assert_eq!(drop_count.get(), 2);
+        }
+
+        #[test]
+        fn _try_collect_array_too_short() {
+            let ans = [Ok::<u8, u8>(0), Ok(1)]
+                .into_iter()
+                .try_collect_array::<4, _, _>();
+
+            assert_eq!(ans, Err(ArrayCollectError::TooShort { got: 2 }));
+        }
+
+        #[test]
+        fn _parse_all_or_first_err_without_err() {
+            let ans = ["1", "2", "3"]
+                .into_iter()
+                .parse_all_or_first_err::<u8, _, _>(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(6));
+        }
+
+        #[test]
+        fn _parse_all_or_first_err_report_index_of_first_err() {
+            let ans = ["1", "2", "300", "x"]
+                .into_iter()
+                .parse_all_or_first_err::<u8, _, _>(|iter| iter.sum::<u8>());
+
+            let (index, err) = ans.unwrap_err();
+            assert_eq!(index, 2);
+            assert_eq!(err, "300".parse::<u8>().unwrap_err());
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn _first_err_boxed_or_else_with_different_err_types() {
+            use alloc::{boxed::Box, string::ToString};
+            use core::error::Error;
+
+            fn boxed<E>(results: [Result<u8, E>; 2]) -> Result<u8, Box<dyn Error + Send + Sync>>
+            where
+                E: Into<Box<dyn Error + Send + Sync>>,
+            {
+                results
+                    .into_iter()
+                    .first_err_boxed_or_else(|iter| iter.sum::<u8>())
+            }
+
+            let parse_err = "x".parse::<u8>().unwrap_err();
+
+            assert_eq!(boxed::<&str>([Ok(1), Ok(2)]).unwrap(), 3);
+            assert_eq!(boxed([Ok(1), Err("foo")]).unwrap_err().to_string(), "foo");
+            assert_eq!(
+                boxed([Err(parse_err.clone()), Ok(2)])
+                    .unwrap_err()
+                    .to_string(),
+                parse_err.to_string()
+            );
+        }
+
+        /// A fixed capacity sink to avoid `alloc` crate in tests.
+        #[derive(Debug, Default)]
+        struct ArraySink {
+            buf: [u8; 8],
+            len: usize,
+        }
+
+        impl ArraySink {
+            fn as_slice(&self) -> &[u8] {
+                &self.buf[..self.len]
+            }
+        }
+
+        impl Extend<u8> for ArraySink {
+            fn extend<It: IntoIterator<Item = u8>>(&mut self, iter: It) {
+                for v in iter {
+                    self.buf[self.len] = v;
+                    self.len += 1;
+                }
+            }
+        }
+
+        #[test]
+        fn _errs_or_else_without_err() {
+            let mut sink = ArraySink::default();
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .errs_or_else(&mut sink, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+            assert_eq!(sink.as_slice(), &[]);
+        }
+
+        #[test]
+        fn _errs_or_else_with_errs() {
+            let mut sink = ArraySink::default();
+
+            let ans = [Err::<u8, u8>(0), Ok(1), Err(2), Ok(3), Err(4)]
+                .into_iter()
+                .errs_or_else(&mut sink, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(3));
+            assert_eq!(sink.as_slice(), &[0, 2, 4]);
+        }
+
+        #[test]
+        fn _errs_or_else_record_errs_after_closure_returned() {
+            let mut sink = ArraySink::default();
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+                .into_iter()
+                .errs_or_else(&mut sink, |iter| iter.next());
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(sink.as_slice(), &[1, 3]);
+        }
+
+        #[test]
+        fn _errs_or_else_use_lazy_evaluation() {
+            use core::cell::{Cell, RefCell};
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            enum Trace {
+                None,
+                Outer(Result<u8, u8>),
+                Inner(u8),
+            }
+
+            // if index >= N, it will panic.
+            fn record_trace<const N: usize>(
+                traces: &RefCell<[Trace; N]>,
+                idx: &Cell<usize>,
+                v: Trace,
+            ) {
+                let i = idx.get();
+                traces.borrow_mut()[i] = v;
+                idx.set(i + 1);
+            }
+
+            // already known N = 8 within [_; N] in this test case.
+            let traces = RefCell::new([Trace::None; 8]);
+
+            let index = Cell::new(0);
+
+            let mut sink = ArraySink::default();
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)]
+                .iter()
+                .cloned()
+                // record value from outer
+                .inspect(|&res| record_trace(&traces, &index, Trace::Outer(res)))
+                .errs_or_else(&mut sink, |iter| {
+                    iter
+                        // record value from inner
+                        .inspect(|&n| record_trace(&traces, &index, Trace::Inner(n)))
+                        .sum::<u8>()
+                });
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(sink.as_slice(), &[1, 3]);
+            assert_eq!(
+                traces.into_inner(),
+                [
+                    Trace::Outer(Ok(0)),
+                    Trace::Inner(0),
+                    Trace::Outer(Err(1)),
+                    Trace::Outer(Ok(2)),
+                    Trace::Inner(2),
+                    Trace::Outer(Err(3)),
+                    Trace::Outer(Ok(4)),
+                    Trace::Inner(4),
+                ]
+            );
+        }
+
+        #[test]
+        fn _methods_can_call_through_trait_object() {
+            let mut array_iter = [Ok::<u8, u8>(0), Err(1), Err(2)].into_iter();
+
+            fn take_dyn(iter: &mut dyn Iterator<Item = Result<u8, u8>>) {
+                iter.first_err_or_else(|iter| iter.sum::<u8>()).ok();
+                iter.first_err_or(0).ok();
+                iter.first_err_or_try(|iter| Ok(iter.sum::<u8>())).ok();
+            }
+
+            take_dyn(&mut array_iter);
+        }
+
+        #[test]
+        fn _trait_is_dyn_compatible() {
+            let mut array_iter = [Ok::<u8, u8>(0), Ok(1), Err(2)].into_iter();
+            let iter: &mut dyn FirstErr<Item = Result<u8, u8>> = &mut array_iter;
+
+            assert_eq!(iter.next(), Some(Ok(0)));
+        }
+
+        #[test]
+        fn _or_else_peekable_can_call_through_trait_object() {
+            let mut array_iter = [Ok::<u8, u8>(0), Err(1), Err(2)].into_iter();
+
+            fn take_dyn(iter: &mut dyn Iterator<Item = Result<u8, u8>>) -> Result<u8, u8> {
+                iter.first_err_or_else_peekable(|iter| iter.sum::<u8>())
+            }
+
+            assert_eq!(take_dyn(&mut array_iter), Err(1));
+        }
+
+        #[test]
+        fn _failure_or_else_without_failure() {
+            let ans = [Ok::<Option<u8>, u8>(Some(0)), Ok(Some(1)), Ok(Some(2))]
+                .into_iter()
+                .first_failure_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(Some(3)));
+        }
+
+        #[test]
+        fn _failure_or_else_with_none_first() {
+            let ans = [Ok::<Option<u8>, u8>(Some(0)), Ok(None), Err(2), Ok(Some(3))]
+                .into_iter()
+                .first_failure_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(None));
+        }
+
+        #[test]
+        fn _failure_or_else_with_err_first() {
+            let ans = [Ok::<Option<u8>, u8>(Some(0)), Err(1), Ok(None), Err(3)]
+                .into_iter()
+                .first_failure_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(1));
+        }
+
+        #[test]
+        fn _failure_or_else_not_consume_iter_manually() {
+            let ans = [Ok::<Option<u8>, u8>(Some(0)), Ok(Some(1)), Ok(None), Err(3)]
+                .into_iter()
+                .first_failure_or_else(|_| 42);
+
+            assert_eq!(ans, Ok(None));
+        }
+
+        #[test]
+        fn _failure_or_else_iter_stop_on_first_failure() {
+            let mut count = 0;
+
+            let ans = [Ok::<Option<u8>, u8>(Some(0)), Ok(None), Ok(Some(2)), Err(3)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_failure_or_else(|iter| iter.count());
+
+            assert_eq!(ans, Ok(None));
+            assert_eq!(count, 2);
+        }
+
+        #[test]
+        fn _failure_or_else_is_same_as_manual_nesting_on_prefix() {
+            const MAX_LEN: usize = 5;
+
+            // Enumerate every sequence up to `MAX_LEN` items, each item is one of
+            // `Ok(Some(_))`, `Ok(None)` or `Err(_)`, and payloads are the positions.
+            for len in 0..=MAX_LEN {
+                for code in 0..3_u32.pow(len as u32) {
+                    let mut case = [Ok::<Option<u8>, u8>(None); MAX_LEN];
+                    let mut rest = code;
+                    for (i, item) in case.iter_mut().take(len).enumerate() {
+                        *item = match rest % 3 {
+                            0 => Ok(Some(i as u8)),
+                            1 => Ok(None),
+                            _ => Err(i as u8),
+                        };
+                        rest /= 3;
+                    }
+                    let case = &case[..len];
+
+                    let ans = case.iter().copied().first_failure_or_else(|iter| {
+                        iter.fold(0_u32, |acc, x| acc * 10 + x as u32 + 1)
+                    });
+
+                    // The nesting can only be trusted until the first failure, because the
+                    // outer one will keep looking for an `Err` after the inner one stopped.
+                    let prefix_len = case
+                        .iter()
+                        .position(|item| !matches!(item, Ok(Some(_))))
+                        .map_or(len, |pos| pos + 1);
+                    let nested = case[..prefix_len]
+                        .iter()
+                        .copied()
+                        .first_err_or_else(|iter1| {
+                            iter1.first_none_or_else(|iter2| {
+                                iter2.fold(0_u32, |acc, x| acc * 10 + x as u32 + 1)
+                            })
+                        });
+
+                    assert_eq!(ans, nested, "case: {:?}", case);
+                }
+            }
+        }
+
+        #[test]
+        fn _err_into_none_keep_first_err_in_slot() {
+            let mut slot = None;
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+                .into_iter()
+                .err_into_none(Some(&mut slot))
+                .first_none_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+            assert_eq!(slot, Some(1));
+        }
+
+        #[test]
+        fn _err_into_none_map_every_item() {
+            let mut slot = None;
+            let mut iter = [Err::<u8, u8>(0), Ok(1), Err(2)]
+                .into_iter()
+                .err_into_none(Some(&mut slot));
+
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            assert_eq!(iter.next(), Some(None));
+            assert_eq!(iter.next(), Some(Some(1)));
+            assert_eq!(iter.next(), Some(None));
+            assert_eq!(iter.next(), None);
+            assert_eq!(slot, Some(0));
+        }
+
+        #[test]
+        fn _err_into_none_without_slot() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+                .into_iter()
+                .err_into_none(None)
+                .first_none_or_else(|iter| iter.count());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _err_into_none_not_touch_slot_without_err() {
+            let mut slot = Some(42);
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .err_into_none(Some(&mut slot))
+                .first_none_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(3));
+            assert_eq!(slot, Some(42));
+        }
+
+        /// A fixed-capacity error bundle, for testing `collect_errs_or_else()`.
+        #[derive(Debug, Default, PartialEq)]
+        struct ErrorBundle {
+            buf: [u8; 8],
+            len: usize,
+        }
+
+        impl ErrorBundle {
+            fn as_slice(&self) -> &[u8] {
+                &self.buf[..self.len]
+            }
+        }
+
+        impl Extend<u8> for ErrorBundle {
+            fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+                for e in iter {
+                    self.buf[self.len] = e;
+                    self.len += 1;
+                }
+            }
+        }
+
+        #[test]
+        fn _collect_errs_or_else_with_zero_err() {
+            let ans: Result<u8, ErrorBundle> = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .collect_errs_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _collect_errs_or_else_with_one_err() {
+            let ans: Result<u8, ErrorBundle> = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+                .into_iter()
+                .collect_errs_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans.unwrap_err().as_slice(), [1]);
+        }
+
+        #[test]
+        fn _collect_errs_or_else_with_many_errs() {
+            let mut oks = [0; 8];
+            let mut oks_len = 0;
+
+            let ans: Result<(), ErrorBundle> =
+                [Err::<u8, u8>(0), Ok(1), Err(2), Err(3), Ok(4), Err(5)]
+                    .into_iter()
+                    .collect_errs_or_else(|iter| {
+                        for t in iter {
+                            oks[oks_len] = t;
+                            oks_len += 1;
+                        }
+                    });
+
+            assert_eq!(ans.unwrap_err().as_slice(), [0, 2, 3, 5]);
+            assert_eq!(oks[..oks_len], [1, 4]);
+        }
+
+        #[test]
+        fn _collect_errs_or_else_not_consume_iter_manually() {
+            let mut count = 0;
+
+            let ans: Result<u8, ErrorBundle> = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .collect_errs_or_else(|_| 42);
+
+            assert_eq!(ans.unwrap_err().as_slice(), [1, 3]);
+            assert_eq!(count, 4);
+        }
+
+        /// An error with a severity level, for testing `worst_err_or_else()`.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Leveled {
+            level: u8,
+            id: u8,
+        }
+
+        impl crate::Severity for Leveled {
+            type Level = u8;
+
+            fn severity(&self) -> u8 {
+                self.level
+            }
+        }
+
+        const fn lv(level: u8, id: u8) -> Result<u8, Leveled> {
+            Err(Leveled { level, id })
+        }
+
+        #[test]
+        fn _worst_err_or_else_without_err() {
+            let ans = [Ok::<u8, Leveled>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .worst_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _worst_err_or_else_later_more_severe_err_wins() {
+            let ans = [Ok(0), lv(1, 1), Ok(2), lv(3, 3), lv(2, 4)]
+                .into_iter()
+                .worst_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(Leveled { level: 3, id: 3 }));
+        }
+
+        #[test]
+        fn _worst_err_or_else_earliest_wins_on_tie() {
+            let ans = [lv(1, 0), lv(2, 1), Ok(2), lv(2, 3), lv(1, 4), lv(2, 5)]
+                .into_iter()
+                .worst_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(Leveled { level: 2, id: 1 }));
+        }
+
+        #[test]
+        fn _worst_err_or_else_closure_consume_every_ok() {
+            let mut sum = 0;
+
+            let ans = [Ok(1), lv(1, 1), Ok(2), lv(1, 3), Ok(4)]
+                .into_iter()
+                .worst_err_or_else(|iter| sum = iter.sum::<u8>());
+
+            assert_eq!(ans, Err(Leveled { level: 1, id: 1 }));
+            assert_eq!(sum, 7);
+        }
+
+        #[test]
+        fn _worst_err_or_else_scan_whole_iter() {
+            let mut count = 0;
+
+            let ans = [Ok(0), lv(1, 1), Ok(2), lv(2, 3)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .worst_err_or_else(|iter| iter.next());
+
+            assert_eq!(ans, Err(Leveled { level: 2, id: 3 }));
+            assert_eq!(count, 4);
+        }
+
+        #[test]
+        fn _or_else_iter_debug_without_debug_inner() {
+            use super::FmtBuf;
+
+            // A closure-based inner iterator and a non-`Debug` error type.
+            struct NonDebug;
+
+            let mut items = [Ok::<u8, NonDebug>(0), Err(NonDebug)].into_iter();
+            let inner = core::iter::from_fn(move || items.next());
+
+            let ans = inner.first_err_or_else(|iter| {
+                assert_eq!(
+                    FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                    "FirstErrIter { state: Active, ok_count: 0 }"
+                );
+                assert_eq!(iter.next(), Some(0));
+                assert_eq!(iter.next(), None);
+                assert_eq!(
+                    FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                    "FirstErrIter { state: FoundFirstErr, ok_count: 1 }"
+                );
+            });
+
+            assert!(ans.is_err());
+
+            [Ok::<u8, u8>(0)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    iter.for_each(drop);
+                    assert_eq!(
+                        FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                        "FirstErrIter { state: Exhausted, ok_count: 1 }"
+                    );
+                })
+                .ok();
+        }
+
+        #[test]
+        fn _or_else_iter_ok_count() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.ok_count(), 0);
+
+                    // partial consumption.
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.ok_count(), 1);
+                    assert_eq!(iter.nth(1), Some(2));
+                    assert_eq!(iter.ok_count(), 3);
+
+                    // after the first err.
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.ok_count(), 3);
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.ok_count(), 3);
+
+                    // after the taken one.
+                    assert_eq!(iter.take_err(), Some(3));
+                    assert_eq!(iter.ok_count(), 3);
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.ok_count(), 3);
+                });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_ok_count_with_internal_iteration() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    // break early.
+                    assert_eq!(iter.find(|n| *n == 1), Some(1));
+                    assert_eq!(iter.ok_count(), 2);
+
+                    // skip over the err.
+                    assert_eq!(iter.nth(5), None);
+                    assert_eq!(iter.ok_count(), 3);
+
+                    assert_eq!(iter.take_err(), Some(3));
+                    assert_eq!(iter.fold_ok(0, |acc, n| acc + n), 0);
+                    assert_eq!(iter.ok_count(), 3);
+                });
+
+            assert_eq!(ans, Ok(()));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.sum::<u8>(), 3);
+                    iter.ok_count()
+                });
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _or_else_iter_source_position() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Err(4), Ok(5)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.source_position(), 0);
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.source_position(), 1);
+
+                    // include the err.
+                    assert_eq!(iter.nth(3), None);
+                    assert_eq!(iter.source_position(), 3);
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.source_position(), 3);
+
+                    // include the taken err.
+                    assert_eq!(iter.take_err(), Some(2));
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.source_position(), 3);
+                    assert_eq!(iter.check_rest(), Err(&4));
+                    assert_eq!(iter.source_position(), 5);
+                    assert_eq!(iter.take_err(), Some(4));
+                    assert_eq!(iter.source_position(), 5);
+
+                    // exhausted
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert_eq!(iter.source_position(), 6);
+                });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_positioned() {
+            // closure consume nothing.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else_positioned(|_| ());
+            assert_eq!(ans, Err((2, 2)));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(3)]
+                .into_iter()
+                .first_err_or_else_positioned(|_| ());
+            assert_eq!(ans, Ok(((), 3)));
+
+            // closure found the err.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else_positioned(|iter| iter.for_each(drop));
+            assert_eq!(ans, Err((2, 2)));
+
+            // drain after the taken err.
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else_positioned(|iter| {
+                    iter.for_each(drop);
+                    iter.take_err()
+                });
+            assert_eq!(ans, Err((3, 3)));
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Ok(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else_positioned(|iter| {
+                    iter.for_each(drop);
+                    iter.take_err()
+                });
+            assert_eq!(ans, Ok((Some(1), 5)));
+        }
+
+        #[test]
+        fn _or_else_positioned_equivalent_to_first_err_or_else() {
+            let items = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Err(4)];
+
+            for len in 0..=items.len() {
+                for consume in 0..=len {
+                    let ans = items[..len]
+                        .iter()
+                        .copied()
+                        .first_err_or_else_positioned(|iter| iter.take(consume).count());
+                    let expected = items[..len]
+                        .iter()
+                        .copied()
+                        .first_err_or_else(|iter| iter.take(consume).count());
+                    let position = items[..len].iter().position(Result::is_err);
+
+                    match (ans, expected, position) {
+                        (Ok((o, pos)), Ok(expected), None) => {
+                            assert_eq!(o, expected);
+                            assert_eq!(pos, len);
+                        }
+                        (Err((e, pos)), Err(expected), Some(position)) => {
+                            assert_eq!(e, expected);
+                            assert_eq!(pos, position);
+                        }
+                        _ => panic!("len: {len}, consume: {consume}"),
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn _or_else_iter_check_rest_before_consuming() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Err(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.check_rest(), Err(&2));
+                    assert!(iter.found_err());
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.ok_count(), 0);
+                    assert_eq!(iter.source_position(), 3);
+                });
+
+            assert_eq!(ans, Err(2));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert!(iter.is_exhausted());
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.source_position(), 2);
+                });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_check_rest_after_partial_consumption() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.check_rest(), Err(&2));
+                    assert_eq!(iter.ok_count(), 1);
+                    assert_eq!(iter.source_position(), 3);
+
+                    // continue after the taken one.
+                    assert_eq!(iter.take_err(), Some(2));
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert_eq!(iter.source_position(), 4);
+                });
+
+            assert_eq!(ans, Ok(()));
+
+            // after exhausted or found err.
+            let ans = [Ok::<u8, u8>(0), Err(1)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    iter.for_each(drop);
+                    assert_eq!(iter.check_rest(), Err(&1));
+                });
+
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0)].into_iter().first_err_or_else(|iter| {
+                iter.for_each(drop);
+                assert_eq!(iter.check_rest(), Ok(()));
+            });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_check_rest_twice() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Err(2)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.check_rest(), Err(&1));
+                    assert_eq!(iter.check_rest(), Err(&1));
+                    assert_eq!(iter.source_position(), 2);
+                });
+
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert!(iter.is_exhausted());
+                    assert_eq!(iter.source_position(), 2);
+                });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_abort_ignore_errs_beyond() {
+            use super::FmtBuf;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    iter.abort();
+
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+                    assert!(!iter.found_err());
+                    assert!(!iter.is_exhausted());
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert_eq!(iter.take_err(), None);
+                    assert_eq!(
+                        FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                        "FirstErrIter { state: Aborted, ok_count: 1 }"
+                    );
+                    "aborted"
+                });
+
+            assert_eq!(ans, Ok("aborted"));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else_positioned(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    iter.abort();
+                });
+
+            assert_eq!(ans, Ok(((), 1)));
+        }
+
+        #[test]
+        fn _or_else_iter_abort_skip_drain() {
+            use core::cell::Cell;
+
+            let pulled = Cell::new(0);
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3)]
+                .into_iter()
+                .inspect(|_| pulled.set(pulled.get() + 1))
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    iter.abort();
+                });
+
+            assert_eq!(ans, Ok(()));
+            assert_eq!(pulled.get(), 1);
+        }
+
+        #[test]
+        fn _or_else_iter_abort_after_found_err() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    iter.for_each(drop);
+                    iter.abort();
+                    assert!(iter.found_err());
+                });
+
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0)].into_iter().first_err_or_else(|iter| {
+                iter.for_each(drop);
+                iter.abort();
+                assert!(iter.is_exhausted());
+            });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _iter_standalone_rebuild_from_parts() {
+            let source = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Err(4), Ok(5)].into_iter();
+
+            let mut sums = [0; 3];
+            let mut errs = [None; 3];
+            let mut inner = Some(source);
+
+            for (sum, err) in sums.iter_mut().zip(errs.iter_mut()) {
+                let mut iter = FirstErrIter::new(inner.take().unwrap());
+                *sum = iter.by_ref().sum::<u8>();
+
+                let (rest, first_err, back_err) = iter.into_parts();
+                assert_eq!(back_err, None);
+                *err = first_err;
+                inner = rest;
+            }
+
+            assert_eq!(sums, [1, 3, 5]);
+            assert_eq!(errs, [Some(2), Some(4), None]);
+            assert!(inner.is_none());
+        }
+
+        #[test]
+        fn _iter_standalone_into_parts() {
+            // active
+            let mut iter = FirstErrIter::new([Ok::<u8, u8>(0), Err(1), Ok(2)].into_iter());
+            assert_eq!(iter.next(), Some(0));
+
+            let (inner, err, _) = iter.into_parts();
+            assert_eq!(err, None);
+
+            // rebuild with a pipeline
+            let ans = inner
+                .unwrap()
+                .map(|res| res.map(|n| n * 10))
+                .first_err_or_else(|iter| iter.sum::<u8>());
+            assert_eq!(ans, Err(1));
+
+            // not consumed
+            let iter = FirstErrIter::new([Ok::<u8, u8>(0), Err(1), Ok(2)].into_iter());
+            let (inner, err, _) = iter.into_parts();
+            assert_eq!(inner.map(Iterator::count), Some(3));
+            assert_eq!(err, None);
+
+            // exhausted
+            let mut iter = FirstErrIter::new([Ok::<u8, u8>(0)].into_iter());
+            assert_eq!(iter.by_ref().count(), 1);
+            let (inner, err, _) = iter.into_parts();
+            assert!(inner.is_none());
+            assert_eq!(err, None);
+
+            // aborted
+            let mut iter = FirstErrIter::new([Ok::<u8, u8>(0), Err(1)].into_iter());
+            iter.abort();
+            let (inner, err, _) = iter.into_parts();
+            assert!(inner.is_none());
+            assert_eq!(err, None);
+        }
+
+        #[test]
+        fn _or_else_iter_double_ended_front_only() {
+            let ans = [Err::<u8, u8>(0), Ok(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.next_back(), None);
+                });
+
+            assert_eq!(ans, Err(0));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _or_else_iter_double_ended_back_only() {
+            // the err from the back is not the first one.
+            let ans = [Err::<u8, u8>(0), Ok(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(4));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next_back(), None);
+                    assert!(!iter.found_err());
+                    assert_eq!(iter.peek_err(), None);
+                });
+
+            assert_eq!(ans, Err(0));
+
+            // the err from the back is the first one.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| iter.rev().fold(0, |acc, n| acc * 10 + n));
+
+            assert_eq!(ans, Err(3));
+
+            // everything is ok.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    let ans = iter.rev().fold(0, |acc, n| acc * 10 + n);
+                    assert!(iter.is_exhausted());
+                    ans
+                });
+
+            assert_eq!(ans, Ok(210));
+        }
+
+        #[test]
+        fn _or_else_iter_double_ended_mixed() {
+            // the err from the back only stops the back.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), None);
+                    assert!(!iter.found_err());
+                    assert!(!iter.is_exhausted());
+                    assert_eq!(iter.peek_err(), None);
+
+                    // the front keeps going until the err from the back.
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next(), Some(1));
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+                    // confirmed as the first one.
+                    assert!(iter.found_err());
+                    assert_eq!(iter.peek_err(), Some(&2));
+                });
+
+            assert_eq!(ans, Err(2));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), None);
+                    assert!(iter.by_ref().eq([0, 1]));
+                    assert!(iter.found_err());
+                });
+
+            assert_eq!(ans, Err(2));
+
+            // confirmed by check_rest.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.check_rest(), Err(&2));
+                    assert!(iter.found_err());
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Err(2));
+
+            // the front meets an earlier err.
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(4));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.peek_err(), Some(&1));
+                });
+
+            assert_eq!(ans, Err(1));
+
+            // errors at both ends.
+            let ans = [Err::<u8, u8>(0), Ok(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.check_rest(), Err(&0));
+
+                    // check the rest to the err from the back.
+                    assert_eq!(iter.take_err(), Some(0));
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.check_rest(), Err(&3));
+                    assert_eq!(iter.take_err(), Some(3));
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Ok(()));
+
+            let ans = [Err::<u8, u8>(0), Ok(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Err(0));
+
+            // the front and the back meet without err.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), Some(2));
+                    assert_eq!(iter.next(), Some(1));
+                    assert_eq!(iter.next_back(), None);
+                    assert!(iter.is_exhausted());
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_double_ended_with_other_methods() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.check_rest(), Err(&2));
+                    assert_eq!(iter.source_position(), 4);
+                });
+
+            assert_eq!(ans, Err(2));
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.fold_ok(0, |acc, n| acc + n), 1);
+                    assert!(iter.found_err());
+                    assert_eq!(iter.nth(1), None);
+                });
+
+            assert_eq!(ans, Err(2));
+
+            // abort drops the err from the back.
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), None);
+                    iter.abort();
+                });
+
+            assert_eq!(ans, Ok(()));
+
+            // into_parts returns the err from the back with the unchecked items.
+            let items = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)];
+
+            let mut iter = FirstErrIter::new(items.into_iter());
+            assert_eq!(iter.next_back(), Some(4));
+            assert_eq!(iter.next_back(), None);
+
+            let (inner, err, back_err) = iter.into_parts();
+            assert_eq!(err, None);
+            assert_eq!(back_err, Some(3));
+
+            let rest = inner.unwrap().chain(back_err.map(Err));
+            assert!(rest.filter_map(Result::err).eq([1, 3]));
+
+            // and with the err from the front.
+            let mut iter = FirstErrIter::new(items.into_iter());
+            assert_eq!(iter.next_back(), Some(4));
+            assert_eq!(iter.next_back(), None);
+            assert_eq!(iter.check_rest(), Err(&1));
+
+            let (inner, err, back_err) = iter.into_parts();
+            assert_eq!(err, Some(1));
+            assert_eq!(back_err, Some(3));
+
+            let rest = inner.unwrap().chain(back_err.map(Err));
+            assert_eq!(rest.first_err_or(()), Err(3));
+        }
+
+        #[test]
+        fn _or_else_iter_double_ended_keep_fused() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    // each end stays stopped after it returned `None`.
+                    assert_eq!(iter.next_back(), Some(3));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.next_back(), None);
+                    assert_eq!(iter.next(), Some(1));
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.next_back(), None);
+                });
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_iter_double_ended_exhaustive() {
+            // Each item is `Ok(i)` or `Err(i)`, where `i` is the index.
+            for len in 0..=5_u8 {
+                for errs in 0..(1_u8 << len) {
+                    let items = (0..len).map(|i| if errs & (1 << i) == 0 { Ok(i) } else { Err(i) });
+                    let first_err = items.clone().find_map(Result::err);
+
+                    // `1` bits are from the back.
+                    for ops_len in 0..=(len + 1) {
+                        for ops in 0..(1_u8 << ops_len) {
+                            let mut front_last = None;
+                            let mut back_last = None;
+
+                            let ans = items.clone().first_err_or_else_positioned(|iter| {
+                                for op in 0..ops_len {
+                                    if ops & (1 << op) == 0 {
+                                        if let Some(i) = iter.next() {
+                                            // producing the `Ok` values in order.
+                                            assert_eq!(i, front_last.map_or(0, |n| n + 1));
+                                            assert!(first_err.iter().all(|&e| i < e));
+                                            front_last = Some(i);
+                                        } else {
+                                            // only stops at an err, or where the back is.
+                                            let at = front_last.map_or(0, |n| n + 1);
+                                            assert!(
+                                                errs & (1 << at) != 0
+                                                    || at == back_last.unwrap_or(len)
+                                            );
+                                        }
+                                    } else if let Some(i) = iter.next_back() {
+                                        // producing the `Ok` values in reverse order.
+                                        assert_eq!(i, back_last.map_or(len - 1, |n| n - 1));
+                                        assert!(front_last.iter().all(|&n| n < i));
+                                        back_last = Some(i);
+                                    }
+                                }
+                            });
+
+                            match first_err {
+                                Some(e) => assert_eq!(ans, Err((e, e as usize))),
+                                None => assert_eq!(ans, Ok(((), len as usize))),
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn _or_else_iter_found_err_and_is_exhausted() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    // active
+                    assert!(!iter.found_err());
+                    assert!(!iter.is_exhausted());
+
+                    assert_eq!(iter.next(), Some(0));
+                    assert!(!iter.found_err());
+                    assert!(!iter.is_exhausted());
+
+                    // found first err
+                    assert_eq!(iter.next(), None);
+                    assert!(iter.found_err());
+                    assert!(!iter.is_exhausted());
+                });
+
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0)].into_iter().first_err_or_else(|iter| {
+                assert_eq!(iter.next(), Some(0));
+                assert_eq!(iter.next(), None);
+
+                // exhausted
+                assert!(!iter.found_err());
+                assert!(iter.is_exhausted());
+            });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_peek_err() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    // active
+                    assert_eq!(iter.peek_err(), None);
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.peek_err(), None);
+
+                    // found first err
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.peek_err(), Some(&1));
+
+                    // not disturb the state.
+                    assert_eq!(iter.peek_err(), Some(&1));
+                    assert!(iter.found_err());
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.peek_err(), Some(&1));
+                    "unused"
+                });
+
+            assert_eq!(ans, Err(1));
+
+            let ans = [Ok::<u8, u8>(0)].into_iter().first_err_or_else(|iter| {
+                assert_eq!(iter.next(), Some(0));
+                assert_eq!(iter.next(), None);
+
+                // exhausted
+                assert_eq!(iter.peek_err(), None);
+                "ok"
+            });
+
+            assert_eq!(ans, Ok("ok"));
+        }
+
+        #[test]
+        fn _or_else_iter_take_err_and_later_err_exists() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.take_err(), None);
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.next(), None);
+
+                    // stay stopped after the taken one.
+                    assert_eq!(iter.take_err(), Some(1));
+                    assert!(!iter.found_err());
+                    assert!(!iter.is_exhausted());
+                    assert_eq!(iter.peek_err(), None);
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+                    assert_eq!(iter.take_err(), None);
+                });
+
+            // drained after the closure.
+            assert_eq!(ans, Err(3));
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3), Ok(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    let sum = iter.sum::<u8>();
+
+                    let mut taken = 0;
+                    while iter.check_rest().is_err() {
+                        taken += iter.take_err().unwrap();
+                    }
+                    (sum, taken)
+                });
+
+            // all errors taken.
+            assert_eq!(ans, Ok((0, 4)));
+        }
+
+        #[test]
+        fn _or_else_iter_take_err_and_no_more_errs() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    let sum = iter.sum::<u8>();
+                    assert_eq!(iter.take_err(), Some(1));
+                    assert_eq!(iter.take_err(), None);
+                    sum
+                });
+
+            // not consumed by closure, but drained.
+            assert_eq!(ans, Ok(0));
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    let sum = iter.sum::<u8>();
+                    assert_eq!(iter.take_err(), Some(1));
+                    assert_eq!(iter.sum::<u8>(), 0);
+                    assert_eq!(iter.check_rest(), Ok(()));
+                    assert!(iter.is_exhausted());
+                    assert_eq!(iter.take_err(), None);
+                    sum
+                });
+
+            assert_eq!(ans, Ok(0));
+        }
+
+        #[test]
+        fn _or_else_iter_take_err_with_internal_iteration() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Ok(3), Err(4), Ok(5)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.fold_ok(0, |acc, n| acc + n), 0);
+                    assert_eq!(iter.take_err(), Some(1));
+                    assert_eq!(iter.nth(1), None);
+                    assert_eq!(iter.fold_ok(0, |acc, n| acc + n), 0);
+                    assert_eq!(iter.ok_count(), 1);
+                });
+
+            assert_eq!(ans, Err(4));
+        }
+
+        #[test]
+        fn _or_else_iter_take_err_keep_fused() {
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Ok(3)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    let mut fused = iter.by_ref().fuse();
+                    assert_eq!(fused.next(), Some(0));
+                    assert_eq!(fused.next(), None);
+                    assert_eq!(iter.take_err(), Some(1));
+
+                    // with or without `Fuse`, nothing more after the taken one.
+                    assert_eq!(iter.by_ref().fuse().next(), None);
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_iter_drop_inner_after_return() {
+            use core::cell::Cell;
+
+            struct DropFlag<'a, I> {
+                inner: I,
+                dropped: &'a Cell<bool>,
+            }
+
+            impl<I: Iterator> Iterator for DropFlag<'_, I> {
+                type Item = I::Item;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    self.inner.next()
+                }
+            }
+
+            impl<I> Drop for DropFlag<'_, I> {
+                fn drop(&mut self) {
+                    self.dropped.set(true);
+                }
+            }
+
+            let dropped = Cell::new(false);
+            let inner = DropFlag {
+                inner: [Ok::<u8, u8>(0), Err(1), Ok(2)].into_iter(),
+                dropped: &dropped,
+            };
+
+            let ans = inner.first_err_or_else(|iter| {
+                assert_eq!(iter.next(), Some(0));
+                assert_eq!(iter.next(), None);
+
+                // still alive after the first `Err` found.
+                assert!(!dropped.get());
+            });
+
+            assert_eq!(ans, Err(1));
+            assert!(dropped.get());
+        }
+
+        #[test]
+        fn _or_else_iter_is_exhausted_with_non_fused_iterator() {
+            struct NonFusedIter {
+                curr: u32,
+            }
+
+            impl Iterator for NonFusedIter {
+                type Item = Result<u32, u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Ok(tmp)),
+                        1 => None,
+                        2 => Some(Err(tmp)),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            let ans = NonFusedIter { curr: 0 }.first_err_or_else(|iter| {
+                assert_eq!(iter.next(), Some(0));
+
+                // `None` from inner means exhausted, even an `Err` will come after it.
+                assert_eq!(iter.next(), None);
+                assert!(!iter.found_err());
+                assert!(iter.is_exhausted());
+            });
+
+            assert_eq!(ans, Ok(()));
+        }
+
+        #[test]
+        fn _or_else_clone_iter_for_two_aggregations() {
+            let ans = [Ok::<u8, u8>(1), Ok(2), Ok(3), Err(4), Ok(5)]
+                .iter()
+                .copied()
+                .first_err_or_else(|iter| {
+                    let max = iter.clone().max();
+                    let sum = iter.sum::<u8>();
+                    (max, sum)
+                });
+
+            assert_eq!(ans, Err(4));
+
+            let ans = [Ok::<u8, u8>(1), Ok(2), Ok(3)]
+                .iter()
+                .copied()
+                .first_err_or_else(|iter| {
+                    let max = iter.clone().max();
+                    let sum = iter.sum::<u8>();
+                    (max, sum)
+                });
+
+            assert_eq!(ans, Ok((Some(3), 6)));
+        }
+
+        #[test]
+        fn _or_else_clone_iter_is_independent() {
+            let ans = [Ok::<u8, u8>(1), Ok(2), Err(3), Ok(4)]
+                .iter()
+                .copied()
+                .first_err_or_else(|iter| {
+                    let mut cloned = iter.clone();
+                    assert_eq!(cloned.by_ref().count(), 2);
+                    assert_eq!(cloned.next(), None);
+
+                    // the original one is not affected.
+                    assert_eq!(iter.next(), Some(1));
+
+                    // the clone of a stopped one is also stopped.
+                    assert_eq!(cloned.clone().next(), None);
+                });
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _or_else_clone_iter_found_err_not_reported_by_itself() {
+            let count = core::cell::Cell::new(0);
+
+            let ans = [Ok::<u8, u8>(1), Ok(2), Err(3), Ok(4)]
+                .iter()
+                .copied()
+                .inspect(|_| count.set(count.get() + 1))
+                .first_err_or_else(|iter| {
+                    // only the clone found the `Err`.
+                    for _ in iter.clone() {}
+                    assert_eq!(count.get(), 3);
+                });
+
+            // the `Err` is found again by draining the original one.
+            assert_eq!(ans, Err(3));
+            assert_eq!(count.get(), 6);
+        }
+
+        #[test]
+        fn _or_else_nth_find_err_in_skipped_range() {
+            let mut count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4), Ok(5)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.nth(4), None);
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(count, 3);
+        }
+
+        #[test]
+        fn _or_else_nth_without_err() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4), Err(5)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.nth(1), Some(1));
+                    assert_eq!(iter.nth(1), Some(3));
+                    assert_eq!(iter.next(), Some(4));
+                    assert_eq!(iter.nth(1), None);
+                    assert_eq!(iter.nth(1), None);
+                });
+
+            assert_eq!(ans, Err(5));
+        }
+
+        #[test]
+        fn _or_else_nth_out_of_range() {
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .first_err_or_else(|iter| iter.nth(3));
+
+            assert_eq!(ans, Ok(None));
+        }
+
+        #[test]
+        fn _or_else_fold_ok_stop_on_first_err() {
+            let mut count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Err(3), Ok(4), Err(5)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_err_or_else(|iter| {
+                    let sum = iter.fold_ok(0, |acc, n| acc + n);
+
+                    // already stopped.
+                    assert_eq!(iter.next(), None);
+                    assert_eq!(iter.fold_ok(42, |acc, n| acc + n), 42);
+
+                    sum
+                });
+
+            assert_eq!(ans, Err(3));
+            assert_eq!(count, 4);
+        }
+
+        #[test]
+        fn _or_else_fold_ok_without_err() {
+            let mut count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_err_or_else(|iter| {
+                    let sum = iter.fold_ok(0, |acc, n| acc + n);
+                    assert_eq!(iter.next(), None);
+                    sum
+                });
+
+            assert_eq!(ans, Ok(3));
+            assert_eq!(count, 3);
+        }
+
+        #[test]
+        fn _or_else_fold_ok_after_next() {
+            let ans = [Ok::<u8, u8>(1), Ok(2), Ok(3), Err(4)]
+                .into_iter()
+                .first_err_or_else(|iter| {
+                    assert_eq!(iter.next(), Some(1));
+                    iter.fold_ok(0, |acc, n| acc * 10 + n)
+                });
+
+            assert_eq!(ans, Err(4));
+        }
+
+        #[test]
+        fn _or_else_fold_ok_never_call_next_on_non_fused_iter_after_end() {
+            struct NonFusedIter {
+                curr: u32,
+            }
+
+            impl Iterator for NonFusedIter {
+                type Item = Result<u32, u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Ok(tmp)),
+                        1 => None,
+                        2 => Some(Err(tmp)),
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            let ans = NonFusedIter { curr: 0 }.first_err_or_else(|iter| {
+                let sum = iter.fold_ok(0, |acc, n| acc + n);
+                assert_eq!(iter.next(), None);
+                sum
+            });
+
+            assert_eq!(ans, Ok(0));
+        }
+    }
+
+    mod test_first_none {
+        //! Test first_none_* methods.
+
+        use crate::FirstErr;
+
+        #[test]
+        fn _or_else_with_1_layer_data_and_without_none() {
+            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_else(|iter1| iter1.sum::<u8>());
+
+            assert_eq!(ans, Some(10));
+        }
+
+        #[test]
+        fn _or_else_with_1_layer_data_and_with_none() {
+            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_else(|iter1| iter1.sum::<u8>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_with_2_layer_data_and_outmost_none_in_layer_1() {
+            let ans = [
+                Some(Some(0u8)),
+                Some(None),
+                None,
+                Some(Some(3)),
+                Some(Some(4)),
+            ]
+            .into_iter()
+            .first_none_or_else(|iter1| {
+                iter1
+                    // could chain other ops
+                    .first_none_or_else(|iter2| iter2.sum::<u8>())
+            });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_with_2_layer_data_and_outmost_none_in_layer_2() {
+            let ans = [
+                Some(Some(0u8)),
+                Some(Some(1)),
+                Some(None),
+                Some(Some(3)),
+                Some(Some(4)),
+            ]
+            .into_iter()
+            .first_none_or_else(|iter1| {
+                iter1
+                    // could chain other ops
+                    .first_none_or_else(|iter2| iter2.sum::<u8>())
+            });
+
+            assert_eq!(ans, Some(None));
+        }
+
+        #[test]
+        fn _or_else_with_3_layer_data_and_outmost_none_in_layer_2() {
+            let ans = [
+                Some(Some(Some(0))),
+                Some(Some(Some(1))),
+                Some(Some(None)),
+                Some(None),
+                Some(Some(Some(4))),
+            ]
+            .into_iter()
+            .first_none_or_else(|iter1| {
+                iter1
+                    // could chain other ops
+                    .first_none_or_else(|iter2| {
+                        iter2
+                            // could chain other ops
+                            .first_none_or_else(|iter3| iter3.sum::<u8>())
+                    })
+            });
+
+            assert_eq!(ans, Some(None));
+        }
+
+        #[test]
+        fn _or_else_not_need_to_consume_iter_manually() {
+            let ans = [Some(0), None, None]
+                .into_iter()
+                .first_none_or_else(|_iter| {});
+
+            assert_eq!(ans, None);
+        }
+
+        /// In most cases, API users should not be concerned about how many times the original
+        /// iterator's `.next()` method is called, as it gets consumed after
+        /// `first_none_or_else()` is called.
+        ///
+        /// However, if the inner iterator has some side-effect, this behavior is still
+        /// observable, and users may rely on it.
+        ///
+        /// This test is designed to ensure that this behavior remains consistent even when
+        /// the code changes.
+        #[test]
+        fn _or_else_never_call_next_on_orig_iter_after_first_none_found() {
+            let mut orig_iter_next_count = 0;
+
+            [Some(0), None, None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_none_or_else(|iter| {
+                    // exhaust whole iter.
+                    for _ in &mut *iter {}
+
+                    // call iter.next() after the iter already exhausted.
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_else_use_lazy_evaluation() {
+            use core::cell::{Cell, RefCell};
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            enum Trace {
+                None,
+                Outer(Option<u8>),
+                Inner(u8),
+            }
+
+            // if index >= N, it will panic.
+            fn record_trace<const N: usize>(
+                traces: &RefCell<[Trace; N]>,
+                idx: &Cell<usize>,
+                v: Trace,
+            ) {
+                let i = idx.get();
+                traces.borrow_mut()[i] = v;
+                idx.set(i + 1);
+            }
+
+            // already known N = 5 within [_; N] in this test case.
+            // We don't use Vec here just bacause want to avoid `alloc` crate.
+            let traces = RefCell::new([Trace::None; 5]);
+
+            let index = Cell::new(0);
+
+            let ans = [Some(0u8), Some(1), None, Some(3)]
+                .iter()
+                .cloned()
+                // record value from outer
+                .inspect(|&opt| record_trace(&traces, &index, Trace::Outer(opt)))
+                .first_none_or_else(|iter| {
+                    iter
+                        // record value from inner
+                        .inspect(|&n| record_trace(&traces, &index, Trace::Inner(n)))
+                        .sum::<u8>()
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(
+                traces.into_inner(),
+                [
+                    Trace::Outer(Some(0)),
+                    Trace::Inner(0),
+                    Trace::Outer(Some(1)),
+                    Trace::Inner(1),
+                    Trace::Outer(None)
+                ]
+            );
+        }
+
+        #[test]
+        fn _or_else_with_non_fused_iterator() {
+            struct NonFusedIter {
+                curr: u32,
+            }
+
+            impl NonFusedIter {
+                fn new() -> Self {
+                    Self { curr: 0 }
+                }
+            }
+
+            impl Iterator for NonFusedIter {
+                type Item = Option<u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Some(tmp)),
+                        1 => None,       // after produce a None ...
+                        2 => Some(None), // it still can produce Some(value)
+                        _ => unreachable!(),
+                    }
+                }
+            }
+
+            let ans = NonFusedIter::new().first_none_or_else(|iter| iter.sum::<u32>());
+
+            assert_eq!(ans, Some(0));
+        }
+
+        #[test]
+        fn _or_without_none() {
+            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
+                .into_iter()
+                .first_none_or("no none");
+
+            assert_eq!(ans, Some("no none"));
+        }
+
+        #[test]
+        fn _or_with_none() {
+            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+                .into_iter()
+                .first_none_or("no none");
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_try_without_none_and_closure_produce_some() {
+            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_try(|iter| iter.nth(1));
+
+            assert_eq!(ans, Some(1));
+        }
+
+        #[test]
+        fn _or_try_without_none_and_closure_produce_none() {
+            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_try(|iter| iter.nth(100));
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_try_with_none_and_closure_produce_some() {
+            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_try(|iter| iter.nth(1));
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_try_with_none_and_closure_produce_none() {
+            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_try(|iter| iter.nth(100));
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_default_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_default::<_, u8>();
+
+            assert_eq!(ans, Some(0));
+        }
+
+        #[test]
+        fn _or_default_never_construct_default_when_none_found() {
+            use core::sync::atomic::{AtomicUsize, Ordering};
+
+            static DEFAULT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+            #[derive(Debug, PartialEq)]
+            struct Summary;
+
+            impl Default for Summary {
+                fn default() -> Self {
+                    DEFAULT_COUNT.fetch_add(1, Ordering::Relaxed);
+                    Self
+                }
+            }
+
+            let ans = [Some::<u8>(0), None, Some(2)]
+                .into_iter()
+                .first_none_or_default::<_, Summary>();
+
+            assert_eq!(ans, None);
+            assert_eq!(DEFAULT_COUNT.load(Ordering::Relaxed), 0);
+
+            let ans = [Some::<u8>(0), Some(1)]
+                .into_iter()
+                .first_none_or_default::<_, Summary>();
+
+            assert_eq!(ans, Some(Summary));
+            assert_eq!(DEFAULT_COUNT.load(Ordering::Relaxed), 1);
+        }
+
+        #[test]
+        fn _or_else_indexed_without_none() {
+            let ans = [Some::<u8>(10), Some(11), Some(12)]
+                .into_iter()
+                .first_none_or_else_indexed(|iter| iter.map(|(i, n)| i as u8 * n).sum::<u8>());
+
+            assert_eq!(ans, Some(35));
+        }
+
+        #[test]
+        fn _or_else_indexed_with_none() {
+            let mut last_index = None;
+
+            let ans = [Some::<u8>(10), Some(11), None, Some(13)]
+                .into_iter()
+                .first_none_or_else_indexed(|iter| {
+                    for (i, _) in iter {
+                        last_index = Some(i);
+                    }
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(last_index, Some(1));
+        }
+
+        #[test]
+        fn _or_else_indexed_with_2_layer_data() {
+            let ans = [
+                [Some::<u8>(0), Some(1)].into_iter(),
+                [Some::<u8>(2), None].into_iter(),
+            ]
+            .into_iter()
+            .map(|iter| iter.first_none_or_else_indexed(|iter| iter.count()))
+            .first_none_or_else_indexed(|iter| iter.map(|(i, n)| i + n).sum::<usize>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_indexed_not_need_to_consume_iter_manually() {
+            let ans = [Some::<u8>(0), Some(1), None]
+                .into_iter()
+                .first_none_or_else_indexed(|_| 0);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _position_or_else_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_position_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _position_or_else_with_none_at_first() {
+            let ans = [None, Some::<u8>(1), None]
+                .into_iter()
+                .first_none_position_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(0));
+        }
+
+        #[test]
+        fn _position_or_else_with_none_at_last_found_by_closure() {
+            let ans = [Some::<u8>(0), Some(1), None]
+                .into_iter()
+                .first_none_position_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _position_or_else_with_none_at_last_found_by_drain() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .first_none_position_or_else(|iter| iter.next());
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _last_none_or_else_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .last_none_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _last_none_or_else_with_interleaved_gaps() {
+            let mut values = [0u8; 4];
+
+            let ans = [
+                None,
+                Some::<u8>(1),
+                None,
+                None,
+                Some(2),
+                Some(3),
+                None,
+                Some(4),
+            ]
+            .into_iter()
+            .last_none_or_else(|iter| {
+                for (slot, n) in values.iter_mut().zip(iter) {
+                    *slot = n;
+                }
+            });
+
+            assert_eq!(ans, Err(4));
+            assert_eq!(values, [1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn _last_none_or_else_count_gaps_after_closure_returned() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), None, Some(2), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .last_none_or_else(|iter| iter.next());
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_fold_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_fold(42, |acc, n| acc + n);
+
+            assert_eq!(ans, Some(42));
+        }
+
+        #[test]
+        fn _or_fold_never_call_next_on_orig_iter_after_first_none_found() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), None, Some(3), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_fold(0, |acc, n| acc + n);
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _or_try_fold_without_none() {
+            let ans = [Some::<u8>(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_try_fold(0u8, |acc, n| acc.checked_add(n));
+
+            assert_eq!(ans, Some(6));
+        }
+
+        #[test]
+        fn _or_try_fold_stop_at_source_none() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(1), None, Some(255), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_try_fold(0u8, |acc, n| acc.checked_add(n));
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_try_fold_drain_until_source_none_after_fold_none() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(1), Some(255), Some(3), None, Some(5)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_try_fold(0u8, |acc, n| acc.checked_add(n));
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_for_each_without_none() {
+            let mut sum = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_for_each(|n| sum += n);
+
+            assert_eq!(ans, Some(()));
+            assert_eq!(sum, 3);
+        }
+
+        #[test]
+        fn _or_for_each_use_lazy_evaluation() {
+            use core::cell::{Cell, RefCell};
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            enum Trace {
+                None,
+                Outer(Option<u8>),
+                Inner(u8),
+            }
+
+            // if index >= N, it will panic.
+            fn record_trace<const N: usize>(
+                traces: &RefCell<[Trace; N]>,
+                idx: &Cell<usize>,
+                v: Trace,
+            ) {
+                let i = idx.get();
+                traces.borrow_mut()[i] = v;
+                idx.set(i + 1);
+            }
+
+            // already known N = 5 within [_; N] in this test case.
+            let traces = RefCell::new([Trace::None; 5]);
+
+            let index = Cell::new(0);
+
+            let ans = [Some(0u8), Some(1), None, Some(3)]
+                .iter()
+                .cloned()
+                // record value from outer
+                .inspect(|&opt| record_trace(&traces, &index, Trace::Outer(opt)))
+                // record value in for_each
+                .first_none_or_for_each(|n| record_trace(&traces, &index, Trace::Inner(n)));
+
+            assert_eq!(ans, None);
+            assert_eq!(
+                traces.into_inner(),
+                [
+                    Trace::Outer(Some(0)),
+                    Trace::Inner(0),
+                    Trace::Outer(Some(1)),
+                    Trace::Inner(1),
+                    Trace::Outer(None)
+                ]
+            );
+        }
+
+        #[test]
+        fn _or_try_for_each_without_failure() {
+            let mut sum = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_try_for_each(|n| {
+                    sum += n;
+                    Some(())
+                });
+
+            assert_eq!(ans, Some(()));
+            assert_eq!(sum, 3);
+        }
+
+        #[test]
+        fn _or_try_for_each_with_action_fails_first() {
+            let mut orig_iter_next_count = 0;
+            let mut call_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_try_for_each(|n| {
+                    call_count += 1;
+                    (n < 1).then_some(())
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(call_count, 2);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_try_for_each_with_source_none_first() {
+            let mut orig_iter_next_count = 0;
+            let mut call_count = 0;
+
+            let ans = [Some::<u8>(0), None, Some(2), Some(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_try_for_each(|n| {
+                    call_count += 1;
+                    (n < 2).then_some(())
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(call_count, 1);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_sum_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_sum::<_, u8>();
+
+            assert_eq!(ans, Some(0));
+        }
+
+        #[test]
+        fn _or_sum_with_none_at_first() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [None, Some::<u8>(1), Some(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_sum::<_, u8>();
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 1);
+        }
+
+        #[test]
+        #[cfg_attr(debug_assertions, should_panic)]
+        fn _or_sum_overflow_as_iterator_sum() {
+            // Panic in debug mode, and wrapping in release mode, the same as `Iterator::sum()`.
+            let ans = [Some::<u8>(255), Some(1)]
+                .into_iter()
+                .first_none_or_sum::<_, u8>();
+
+            assert_eq!(ans, Some(0));
+        }
+
+        #[test]
+        fn _or_product_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_product::<_, u8>();
+
+            assert_eq!(ans, Some(1));
+        }
+
+        #[test]
+        fn _or_product_with_none_at_last() {
+            let ans = [Some::<u8>(2), Some(3), None]
+                .into_iter()
+                .first_none_or_product::<_, u8>();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_product_stop_at_first_none() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(2), None, Some(3), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_product::<_, u8>();
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_count_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_count();
+
+            assert_eq!(ans, Some(4));
+        }
+
+        #[test]
+        fn _or_count_with_none_at_first() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [None, Some::<u8>(1), Some(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_count();
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 1);
+        }
+
+        #[test]
+        fn _or_min_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_min();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_min_with_single_gap() {
+            let ans = [Some::<u8>(2), None, Some(1)]
+                .into_iter()
+                .first_none_or_min();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_min_with_gap_after_minimum() {
+            let ans = [Some::<u8>(2), Some(1), Some(3), None]
+                .into_iter()
+                .first_none_or_min();
+
+            assert_eq!(ans, None);
+
+            let ans = [Some::<u8>(2), Some(1), Some(3)]
+                .into_iter()
+                .first_none_or_min();
+
+            assert_eq!(ans, Some(1));
+        }
+
+        #[test]
+        fn _or_max_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_max();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_max_with_gap_after_maximum() {
+            let ans = [Some::<u8>(2), Some(9), Some(3), None]
+                .into_iter()
+                .first_none_or_max();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_max_return_last_maximum() {
+            use core::cmp::Ordering;
+
+            /// Only compared by the number part.
+            #[derive(Debug)]
+            struct Tagged(u8, char);
+
+            impl PartialEq for Tagged {
+                fn eq(&self, other: &Self) -> bool {
+                    self.0 == other.0
+                }
+            }
+
+            impl Eq for Tagged {}
+
+            impl PartialOrd for Tagged {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for Tagged {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.cmp(&other.0)
+                }
+            }
+
+            let ans = [
+                Some(Tagged(9, 'a')),
+                Some(Tagged(3, 'b')),
+                Some(Tagged(9, 'c')),
+            ]
+            .into_iter()
+            .first_none_or_max();
+
+            assert_eq!(ans.map(|tagged| tagged.1), Some('c'));
+        }
+
+        #[test]
+        fn _or_min_by_key_without_none() {
+            let ans = [Some::<i8>(-3), Some(2), Some(-2)]
+                .into_iter()
+                .first_none_or_min_by_key(|n| n.abs());
+
+            assert_eq!(ans, Some(2));
+        }
+
+        #[test]
+        fn _or_min_by_key_use_lazy_evaluation() {
+            use core::cell::{Cell, RefCell};
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            enum Trace {
+                None,
+                Outer(Option<u8>),
+                Key(u8),
+            }
+
+            // if index >= N, it will panic.
+            fn record_trace<const N: usize>(
+                traces: &RefCell<[Trace; N]>,
+                idx: &Cell<usize>,
+                v: Trace,
+            ) {
+                let i = idx.get();
+                traces.borrow_mut()[i] = v;
+                idx.set(i + 1);
+            }
+
+            // already known N = 5 within [_; N] in this test case.
+            let traces = RefCell::new([Trace::None; 5]);
+
+            let index = Cell::new(0);
+
+            let ans = [Some(0u8), Some(1), None, Some(3)]
+                .iter()
+                .cloned()
+                // record value from outer
+                .inspect(|&opt| record_trace(&traces, &index, Trace::Outer(opt)))
+                .first_none_or_min_by_key(|&n| {
+                    // record value in key function
+                    record_trace(&traces, &index, Trace::Key(n));
+                    n
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(
+                traces.into_inner(),
+                [
+                    Trace::Outer(Some(0)),
+                    Trace::Key(0),
+                    Trace::Outer(Some(1)),
+                    Trace::Key(1),
+                    Trace::Outer(None)
+                ]
+            );
+        }
+
+        #[test]
+        fn _or_max_by_key_return_last_maximum() {
+            let ans = [Some::<i8>(-3), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_max_by_key(|n| n.abs());
+
+            assert_eq!(ans, Some(3));
+        }
+
+        #[test]
+        fn _or_max_by_key_with_none_after_maximum() {
+            let mut key_call_count = 0;
+
+            let ans = [Some::<i8>(-3), Some(9), Some(2), None, Some(1)]
+                .into_iter()
+                .first_none_or_max_by_key(|n| {
+                    key_call_count += 1;
+                    n.abs()
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(key_call_count, 3);
+        }
+
+        #[test]
+        fn _or_last_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_last();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_last_with_non_fused_iterator() {
+            struct NonFusedIter<const N: usize> {
+                items: [Option<Option<u32>>; N],
+                curr: usize,
+            }
+
+            impl<const N: usize> Iterator for NonFusedIter<N> {
+                type Item = Option<u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let tmp = self.items.get(self.curr).copied().flatten();
+                    self.curr += 1;
+                    tmp
+                }
+            }
+
+            // the `Some` value after the end can not resurrect the result.
+            let ans = NonFusedIter {
+                items: [Some(Some(0)), Some(Some(1)), None, Some(Some(3))],
+                curr: 0,
+            }
+            .first_none_or_last();
+
+            assert_eq!(ans, Some(1));
+
+            // the `Some` value after the `None` item can not resurrect the result.
+            let ans = NonFusedIter {
+                items: [Some(Some(0)), Some(None), None, Some(Some(3))],
+                curr: 0,
+            }
+            .first_none_or_last();
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_nth_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_nth(2);
+
+            assert_eq!(ans, Some(2));
+        }
+
+        #[test]
+        fn _or_nth_with_none_after_n() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_nth(1);
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 5);
+        }
+
+        #[test]
+        fn _or_nth_out_of_range() {
+            let ans = [Some::<u8>(0), Some(1)].into_iter().first_none_or_nth(2);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_find_without_match() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_find(|&n| n > 5);
+
+            assert_eq!(ans, Some(None));
+        }
+
+        #[test]
+        fn _or_find_stop_calling_predicate_after_matched() {
+            let mut orig_iter_next_count = 0;
+            let mut predicate_call_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3), Some(4)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_find(|&n| {
+                    predicate_call_count += 1;
+                    n == 1
+                });
+
+            assert_eq!(ans, Some(Some(1)));
+            assert_eq!(predicate_call_count, 2);
+            assert_eq!(orig_iter_next_count, 5);
+        }
+
+        #[test]
+        fn _or_find_with_none_after_matched() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .first_none_or_find(|&n| n == 1);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_position_of_without_match() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_position_of(|n| n > 5);
+
+            assert_eq!(ans, Some(None));
+        }
+
+        #[test]
+        fn _or_position_of_with_match() {
+            let ans = [Some::<u8>(5), Some(6), Some(7), Some(8)]
+                .into_iter()
+                .first_none_or_position_of(|n| n % 2 == 1);
+
+            assert_eq!(ans, Some(Some(0)));
+
+            let ans = [Some::<u8>(5), Some(6), Some(7), Some(8)]
+                .into_iter()
+                .first_none_or_position_of(|n| n == 7);
+
+            assert_eq!(ans, Some(Some(2)));
+        }
+
+        #[test]
+        fn _or_position_of_with_none_after_matched() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .first_none_or_position_of(|n| n == 1);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_any_stop_calling_predicate_after_answer_known() {
+            let mut orig_iter_next_count = 0;
+            let mut predicate_call_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_any(|n| {
+                    predicate_call_count += 1;
+                    n == 1
+                });
+
+            assert_eq!(ans, Some(true));
+            assert_eq!(predicate_call_count, 2);
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_any_with_none_after_answer_known() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .first_none_or_any(|n| n == 1);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_any_without_match() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_any(|n| n > 5);
+
+            assert_eq!(ans, Some(false));
+        }
+
+        #[test]
+        fn _or_all_without_none_and_without_false() {
+            let ans = [Some::<u8>(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_all(|n| n > 0);
+
+            assert_eq!(ans, Some(true));
+        }
+
+        #[test]
+        fn _or_all_without_none_and_with_false() {
+            let mut orig_iter_next_count = 0;
+            let mut predicate_call_count = 0;
+
+            let ans = [Some::<u8>(1), Some(0), Some(3), Some(4)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_all(|n| {
+                    predicate_call_count += 1;
+                    n > 0
+                });
+
+            assert_eq!(ans, Some(false));
+            assert_eq!(predicate_call_count, 2);
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_all_with_none_and_without_false() {
+            let ans = [Some::<u8>(1), None, Some(3)]
+                .into_iter()
+                .first_none_or_all(|n| n > 0);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_all_with_none_and_with_false() {
+            let ans = [Some::<u8>(1), Some(0), Some(3), None]
+                .into_iter()
+                .first_none_or_all(|n| n > 0);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_reduce_with_empty_iter() {
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_reduce(|a, b| a + b);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_reduce_with_single_element() {
+            let ans = [Some::<u8>(7)]
+                .into_iter()
+                .first_none_or_reduce(|a, b| a + b);
+
+            assert_eq!(ans, Some(7));
+        }
+
+        #[test]
+        fn _or_reduce_with_none_after_complete_reduction() {
+            let mut reduce_call_count = 0;
+
+            // Here the closure consumed all the `Some` values before the `None` found.
+            let ans = [Some::<u8>(1), Some(2), Some(3), None]
+                .into_iter()
+                .first_none_or_reduce(|a, b| {
+                    reduce_call_count += 1;
+                    a + b
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(reduce_call_count, 2);
+        }
+
+        #[test]
+        fn _or_collect_forward_size_hint() {
+            #[derive(Debug, PartialEq)]
+            struct SizeHint((usize, Option<usize>));
+
+            impl FromIterator<u8> for SizeHint {
+                fn from_iter<It: IntoIterator<Item = u8>>(iter: It) -> Self {
+                    Self(iter.into_iter().size_hint())
+                }
+            }
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_collect::<_, SizeHint>();
+
+            assert_eq!(ans, Some(SizeHint((3, Some(3)))));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn _or_collect_into_string() {
+            use alloc::string::String;
+
+            let ans = [Some('f'), Some('o'), Some('o')]
+                .into_iter()
+                .first_none_or_collect::<_, String>();
+
+            assert_eq!(ans.as_deref(), Some("foo"));
+
+            let ans = [Some('f'), None, Some('o')]
+                .into_iter()
+                .first_none_or_collect::<_, String>();
+
+            assert_eq!(ans, None);
+        }
+
+        /// A sink which only counts the pushed values.
+        #[derive(Debug, Default)]
+        struct CountingSink {
+            count: usize,
+        }
+
+        impl<T> Extend<T> for CountingSink {
+            fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+                self.count += iter.into_iter().count();
+            }
+        }
+
+        #[test]
+        fn _or_extend_into_without_none() {
+            let mut sink = CountingSink::default();
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_extend_into(&mut sink);
+
+            assert_eq!(ans, Some(()));
+            assert_eq!(sink.count, 3);
+        }
+
+        #[test]
+        fn _or_extend_into_never_push_after_first_none() {
+            let mut sink = CountingSink::default();
+
+            let ans = [Some::<u8>(0), Some(1), None, Some(3), Some(4)]
+                .into_iter()
+                .first_none_or_extend_into(&mut sink);
+
+            assert_eq!(ans, None);
+            assert_eq!(sink.count, 2);
+        }
+
+        #[test]
+        fn _or_partition_into_without_none() {
+            let mut a = CountingSink::default();
+            let mut b = CountingSink::default();
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(4)]
+                .into_iter()
+                .first_none_or_partition_into(|n| n % 2 == 0, &mut a, &mut b);
+
+            assert_eq!(ans, Some((3, 1)));
+            assert_eq!(a.count, 3);
+            assert_eq!(b.count, 1);
+        }
+
+        #[test]
+        fn _or_partition_into_never_push_after_first_none() {
+            let mut a = CountingSink::default();
+            let mut b = CountingSink::default();
+
+            let ans = [Some::<u8>(0), Some(1), None, Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_partition_into(|n| n % 2 == 0, &mut a, &mut b);
+
+            assert_eq!(ans, None);
+            assert_eq!(a.count, 1);
+            assert_eq!(b.count, 1);
+        }
+
+        #[test]
+        fn _or_else_counted_with_closure_consume_nothing() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_else_counted(|_| ());
+
+            assert_eq!(ans, Ok(((), 3)));
+        }
+
+        #[test]
+        fn _or_else_counted_with_none_found_by_drain() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), None, Some(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_else_counted(|_| ());
+
+            assert_eq!(ans, Err(2));
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _or_else_counted_with_none_found_by_closure() {
+            let ans = [None, Some::<u8>(1), Some(2)]
+                .into_iter()
+                .first_none_or_else_counted(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(0));
+        }
+
+        #[test]
+        fn _counted_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_counted();
+
+            assert_eq!(ans, Ok(4));
+        }
+
+        #[test]
+        fn _counted_scan_whole_iter_after_first_none() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [None, Some::<u8>(1), None, Some(3), None, Some(5)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_counted();
+
+            assert_eq!(ans, Err(3));
+            assert_eq!(orig_iter_next_count, 6);
+        }
+
+        #[test]
+        fn _or_else_partial_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_else_partial(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _or_else_partial_with_none_found_by_closure() {
+            let ans = [Some::<u8>(1), Some(2), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_partial(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(3));
+        }
+
+        #[test]
+        fn _or_else_partial_with_none_found_by_drain() {
+            let ans = [Some::<u8>(1), Some(2), Some(3), None]
+                .into_iter()
+                .first_none_or_else_partial(|iter| iter.next());
+
+            assert_eq!(ans, Err(Some(1)));
+        }
+
+        #[test]
+        fn _indices_or_else_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_indices_or_else::<2, _, _, _>(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
+        }
+
+        #[test]
+        fn _indices_or_else_with_exactly_n_nones() {
+            let mut consumed = 0;
+
+            let ans = [Some::<u8>(0), Some(1), None, Some(3), None]
+                .into_iter()
+                .first_none_indices_or_else::<2, _, _, _>(|iter| consumed = iter.count());
+
+            let gaps = ans.unwrap_err();
+            assert_eq!(gaps.as_slice(), &[2, 4]);
+            assert_eq!(gaps.total(), 2);
+            assert!(!gaps.is_truncated());
+            assert_eq!(consumed, 2);
+        }
+
+        #[test]
+        fn _indices_or_else_with_more_than_n_nones() {
+            let ans = [None, None, Some::<u8>(2), None]
+                .into_iter()
+                .first_none_indices_or_else::<2, _, _, _>(|iter| iter.count());
+
+            let gaps = ans.unwrap_err();
+            assert_eq!(gaps.as_slice(), &[0, 1]);
+            assert_eq!(gaps.total(), 3);
+            assert!(gaps.is_truncated());
+        }
+
+        #[test]
+        fn _indices_or_else_with_none_from_back() {
+            let ans = [Some::<u8>(0), None, Some(2), None, Some(4), None]
+                .into_iter()
+                .first_none_indices_or_else::<4, _, _, _>(|iter| iter.next_back());
+
+            let gaps = ans.unwrap_err();
+            assert_eq!(gaps.as_slice(), &[1, 3, 5]);
+            assert_eq!(gaps.total(), 3);
+        }
+
+        #[test]
+        fn _none2_or_else_equal_to_manual_nesting() {
+            // Every item is one of `None`, `Some(None)` or `Some(Some(_))`.
+            fn item(kind: usize, n: u8) -> Option<Option<u8>> {
+                match kind {
+                    0 => None,
+                    1 => Some(None),
+                    _ => Some(Some(n)),
+                }
+            }
+
+            const LEN: u32 = 4;
+
+            // enumerate every combination of items.
+            for mut code in 0..3usize.pow(LEN) {
+                let mut items = [None; LEN as usize];
+                for (i, slot) in items.iter_mut().enumerate() {
+                    *slot = item(code % 3, i as u8);
+                    code /= 3;
+                }
+
+                let nested = items.into_iter().first_none_or_else(|iter1| {
+                    iter1.first_none_or_else(|iter2| iter2.sum::<u8>())
+                });
+                let flat = items
+                    .into_iter()
+                    .first_none2_or_else(|iter| iter.sum::<u8>());
+
+                assert_eq!(flat, nested.flatten(), "items: {items:?}");
+            }
+        }
+
+        #[test]
+        fn _none3_or_else_with_none_in_every_layer_and_position() {
+            const LEN: usize = 4;
+
+            for layer in 1..=3 {
+                for pos in 0..LEN {
+                    let mut items = [Some(Some(Some(1u8))); LEN];
+                    items[pos] = match layer {
+                        1 => None,
+                        2 => Some(None),
+                        _ => Some(Some(None)),
+                    };
+
+                    let mut orig_iter_next_count = 0;
+
+                    let ans = items
+                        .into_iter()
+                        .inspect(|_| orig_iter_next_count += 1)
+                        .first_none3_or_else(|iter| iter.sum::<u8>());
+
+                    // stop at the outermost layer `None`, otherwise check all items.
+                    let expected_count = if layer == 1 { pos + 1 } else { LEN };
+
+                    assert_eq!(ans, None, "layer: {layer}, pos: {pos}");
+                    assert_eq!(
+                        orig_iter_next_count, expected_count,
+                        "layer: {layer}, pos: {pos}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn _none3_or_else_without_none() {
+            let ans = [Some(Some(Some(1u8))); 4]
+                .into_iter()
+                .first_none3_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(4));
+        }
+
+        #[test]
+        fn _or_else_resumable_resume_after_some() {
+            let mut iter = [Some::<u8>(0), Some(1), Some(2), Some(3), None].into_iter();
+
+            let ans = iter.first_none_or_else_resumable(|iter| iter.take(2).sum::<u8>());
+            assert_eq!(ans, Some(1));
+
+            // the `None` in the rest items is not checked.
+            let ans = iter.first_none_or_else_resumable(|iter| iter.take(2).sum::<u8>());
+            assert_eq!(ans, Some(5));
+
+            let ans = iter.first_none_or_else_resumable(|iter| iter.sum::<u8>());
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_resumable_resume_after_none() {
+            let mut iter = [Some::<u8>(0), None, Some(2), Some(3)].into_iter();
+
+            let ans = iter.first_none_or_else_resumable(|iter| iter.sum::<u8>());
+            assert_eq!(ans, None);
+
+            // continue right after the first `None`.
+            let ans = iter.first_none_or_else_resumable(|iter| iter.sum::<u8>());
+            assert_eq!(ans, Some(5));
+        }
+
+        #[test]
+        fn _or_else_no_drain_with_infinite_iter() {
+            let ans = core::iter::repeat(Some(1u8))
+                .first_none_or_else_no_drain(|iter| iter.take(3).sum::<u8>());
+
+            assert_eq!(ans, Some(3));
+        }
+
+        #[test]
+        fn _or_else_no_drain_with_none_consumed_by_closure() {
+            let ans = [Some(1u8), None]
+                .into_iter()
+                .chain(core::iter::repeat(Some(1u8)))
+                .first_none_or_else_no_drain(|iter| iter.take(3).sum::<u8>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_drain_all_call_next_on_orig_iter_after_first_none_found() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some(0), None, None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_none_or_else_drain_all(|iter| {
+                    // exhaust whole iter.
+                    for _ in &mut *iter {}
+
+                    // call iter.next() after the iter already exhausted.
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _or_else_drain_all_without_none() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_else_drain_all(|iter| iter.next());
+
+            assert_eq!(ans, Some(Some(0)));
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _or_else_while_with_none_before_cutoff() {
+            let ans = [Some::<u8>(0), None, Some(2), Some(9)]
+                .into_iter()
+                .first_none_or_else_while(|&n| n < 5, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_while_with_none_after_cutoff() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(9), None, Some(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_else_while(|&n| n < 5, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(1));
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _or_else_while_with_predicate_false_immediately() {
+            let ans = [Some::<u8>(9), None, Some(1)]
+                .into_iter()
+                .first_none_or_else_while(|&n| n < 5, |iter| iter.count());
+
+            assert_eq!(ans, Some(0));
+        }
+
+        #[test]
+        fn _none_if() {
+            let mut iter = [Some::<u8>(0), None, Some(2), Some(3)]
+                .into_iter()
+                .none_if(|n| n % 2 == 1);
+
+            assert_eq!(iter.size_hint(), (4, Some(4)));
+            assert_eq!(iter.next(), Some(Some(0)));
+            assert_eq!(iter.next(), Some(None));
+            assert_eq!(iter.next(), Some(Some(2)));
+            assert_eq!(iter.next(), Some(None));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _none_if_is_fused_when_inner_is_fused() {
+            use core::iter::FusedIterator;
+
+            fn assert_fused<I: FusedIterator>(_: &I) {}
+
+            let iter = [Some::<u8>(0)].into_iter().none_if(|&n| n > 0);
+
+            assert_fused(&iter);
+        }
+
+        #[test]
+        fn _map_or_else_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .map_first_none_or_else(|n| n.checked_add(1), |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(6));
+        }
+
+        #[test]
+        fn _map_or_else_with_source_none_first() {
+            let mut map_call_count = 0;
+
+            let ans = [Some::<u8>(0), None, Some(255)]
+                .into_iter()
+                .map_first_none_or_else(
+                    |n| {
+                        map_call_count += 1;
+                        n.checked_add(1)
+                    },
+                    |iter| iter.sum::<u8>(),
+                );
+
+            assert_eq!(ans, None);
+            assert_eq!(map_call_count, 1);
+        }
+
+        #[test]
+        fn _map_or_else_with_map_none_first() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(255), None, Some(1)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .map_first_none_or_else(|n| n.checked_add(1), |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _zip_without_none() {
+            let ans = [Some::<u8>(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_else_zip([Some::<u8>(4), Some(5), Some(6)].into_iter(), |iter| {
+                    iter.map(|(a, b)| a + b).sum::<u8>()
+                });
+
+            assert_eq!(ans, Some(21));
+        }
+
+        #[test]
+        fn _zip_with_none_in_left() {
+            let ans = [Some::<u8>(1), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_zip([Some::<u8>(4), Some(5), Some(6)].into_iter(), |iter| {
+                    iter.map(|(a, b)| a + b).sum::<u8>()
+                });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _zip_with_none_in_right() {
+            let ans = [Some::<u8>(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_else_zip([Some::<u8>(4), None, Some(6)].into_iter(), |iter| {
+                    iter.map(|(a, b)| a + b).sum::<u8>()
+                });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _zip_left_wins_tie() {
+            let mut right_next_count = 0;
+
+            let ans = [Some::<u8>(1), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_zip(
+                    [Some::<u8>(4), None, Some(6)]
+                        .into_iter()
+                        .inspect(|_| right_next_count += 1),
+                    |iter| iter.count(),
+                );
+
+            assert_eq!(ans, None);
+            // the zip stops at the left `None`, then the drain stops at the right `None`
+            assert_eq!(right_next_count, 2);
+        }
+
+        #[test]
+        fn _zip_drain_remainder_of_longer_left() {
+            let ans = [Some::<u8>(1), Some(2), None]
+                .into_iter()
+                .first_none_or_else_zip([Some::<u8>(4)].into_iter(), |iter| {
+                    iter.map(|(a, b)| a + b).sum::<u8>()
+                });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _zip_drain_remainder_of_longer_right() {
+            let ans = [Some::<u8>(1)]
+                .into_iter()
+                .first_none_or_else_zip([Some::<u8>(4), Some(5), None].into_iter(), |iter| {
+                    iter.map(|(a, b)| a + b).sum::<u8>()
+                });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _zip_drain_after_early_stop() {
+            let ans = [Some::<u8>(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_else_zip([Some::<u8>(4), Some(5), None].into_iter(), |iter| {
+                    iter.next()
+                });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _chain_without_none() {
+            let ans = [Some::<u8>(0), Some(1)]
+                .into_iter()
+                .first_none_or_else_chain([Some(2), Some(3)], |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(6));
+        }
+
+        #[test]
+        fn _chain_with_none_in_second() {
+            let ans = [Some::<u8>(0), Some(1)]
+                .into_iter()
+                .first_none_or_else_chain([None, Some(3)], |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _chain_not_touch_second_when_first_has_none() {
+            let second = core::iter::from_fn(|| -> Option<Option<u8>> {
+                panic!("the second iterator should not be touched")
+            });
+
+            let ans = [Some::<u8>(0), None, Some(2)]
+                .into_iter()
+                .first_none_or_else_chain(second, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _chain_not_touch_second_when_drain_meet_none_in_first() {
+            let second = core::iter::from_fn(|| -> Option<Option<u8>> {
+                panic!("the second iterator should not be touched")
+            });
+
+            let ans = [Some::<u8>(0), Some(1), None]
+                .into_iter()
+                .first_none_or_else_chain(second, |iter| iter.next());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _unzip_without_none() {
+            let ans = [Some((1u8, 10u16)), Some((2, 20)), Some((3, 30))]
+                .into_iter()
+                .first_none_or_else_unzip((0u8, 1u16), |acc, a| acc + a, |acc, b| acc * b);
+
+            assert_eq!(ans, Some((6, 6000)));
+        }
+
+        #[test]
+        fn _unzip_with_none() {
+            let mut fold_b_count = 0;
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some((1u8, 10u16)), None, Some((3, 30))]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .first_none_or_else_unzip(
+                    (0u8, 0u16),
+                    |acc, a| acc + a,
+                    |acc, b| {
+                        fold_b_count += 1;
+                        acc + b
+                    },
+                );
+
+            assert_eq!(ans, None);
+            assert_eq!(fold_b_count, 1);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _unzip_call_order() {
+            let order = core::cell::RefCell::new([0u8; 4]);
+            let idx = core::cell::Cell::new(0);
+            let record = |v: u8| {
+                order.borrow_mut()[idx.get()] = v;
+                idx.set(idx.get() + 1);
+            };
+
+            let ans = [Some((1u8, 10u8)), Some((2, 20))]
+                .into_iter()
+                .first_none_or_else_unzip(((), ()), |_, a| record(a), |_, b| record(b));
+
+            assert_eq!(ans, Some(((), ())));
+            assert_eq!(order.into_inner(), [1, 10, 2, 20]);
+        }
+
+        #[test]
+        fn _check_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .check_first_none();
+
+            assert_eq!(ans, Some(()));
+        }
+
+        #[test]
+        fn _check_with_none() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), None, Some(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .check_first_none();
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _check_same_as_first_none_or_unit() {
+            let cases: [&[Option<u8>]; 4] = [&[], &[None], &[Some(0), Some(1)], &[Some(0), None]];
+
+            for case in cases {
+                assert_eq!(
+                    case.iter().copied().check_first_none(),
+                    case.iter().copied().first_none_or(()),
+                );
+            }
+        }
+
+        #[test]
+        fn _or_else_peekable_peek_at_upcoming_none() {
+            let ans = [Some::<u8>(0), Some(1), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_peekable(|iter| {
+                    assert_eq!(iter.next(), Some(0));
+                    assert_eq!(iter.peek(), Some(&1));
+                    assert_eq!(iter.next(), Some(1));
+
+                    // next item is the first `None`, peek just see the end.
+                    assert_eq!(iter.peek(), None);
+                    assert_eq!(iter.size_hint(), (0, Some(0)));
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_peekable_peek_then_return() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_none_or_else_peekable(|iter| {
+                    // peeked value can be modified, but never be consumed.
+                    *iter.peek_mut().unwrap() += 10;
+                    iter.peek().copied()
+                });
+
+            assert_eq!(ans, Some(Some(10)));
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_else_peekable_peek_then_return_with_none_after_peeked() {
+            let ans = [Some::<u8>(0), Some(1), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_peekable(|iter| iter.peek().copied());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_peekable_next_if() {
+            let ans = [Some::<u8>(1), Some(1), Some(2), Some(1)]
+                .into_iter()
+                .first_none_or_else_peekable(|iter| {
+                    let mut ones = 0;
+                    while iter.next_if_eq(&1).is_some() {
+                        ones += 1;
+                    }
+
+                    (ones, iter.sum::<u8>())
+                });
+
+            assert_eq!(ans, Some((2, 3)));
+        }
+
+        #[test]
+        fn _or_else_rev_is_equivalent_to_rev_then_or_else() {
+            let cases: [[Option<u8>; 4]; 5] = [
+                [Some(0), Some(1), Some(2), Some(3)],
+                [None, Some(1), Some(2), Some(3)],
+                [Some(0), Some(1), Some(2), None],
+                [Some(0), None, None, Some(3)],
+                [None, None, None, None],
+            ];
+
+            for case in cases {
+                let f = |iter: &mut dyn Iterator<Item = u8>| {
+                    iter.fold(0u32, |acc, n| acc * 10 + n as u32)
+                };
+
+                assert_eq!(
+                    case.into_iter().first_none_or_else_rev(|iter| f(iter)),
+                    case.into_iter().rev().first_none_or_else(|iter| f(iter)),
+                );
+            }
+        }
+
+        #[test]
+        fn _or_else_rev_never_scan_front_after_first_none_from_back() {
+            let mut orig_iter_next_back_count = 0;
+
+            let ans = [None, Some::<u8>(1), None, Some(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_back_count += 1) // side-effect
+                .first_none_or_else_rev(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+            assert_eq!(orig_iter_next_back_count, 2);
+        }
+
+        #[test]
+        fn _or_else_rev_with_non_fused_back_end() {
+            struct NonFusedBackIter {
+                curr: u32,
+            }
+
+            impl Iterator for NonFusedBackIter {
+                type Item = Option<u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    unreachable!("front-end should never be used")
+                }
+            }
+
+            impl DoubleEndedIterator for NonFusedBackIter {
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Some(tmp)),
+                        1 => None,       // after produce a None ...
+                        2 => Some(None), // it still can produce more items
+                        _ => unreachable!(),
                     }
-                },
-                State::FoundFirstErr(_) => None,
-                State::Exhausted => None,
+                }
+            }
+
+            let ans = NonFusedBackIter { curr: 0 }.first_none_or_else_rev(|iter| iter.sum::<u32>());
+
+            assert_eq!(ans, Some(0));
+        }
+
+        #[test]
+        fn _or_else_chunks_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(3), Some(4), Some(5)]
+                .into_iter()
+                .first_none_or_else_chunks(|iter| {
+                    assert_eq!(iter.size_hint(), (2, Some(2)));
+                    iter.map(|[a, b, c]| a + b + c).max()
+                });
+
+            assert_eq!(ans, Ok(Some(12)));
+        }
+
+        #[test]
+        fn _or_else_chunks_with_partial_trailing_chunk() {
+            use crate::ChunkGap;
+            use core::cell::Cell;
+
+            struct DropCounter<'a>(&'a Cell<usize>);
+
+            impl Drop for DropCounter<'_> {
+                fn drop(&mut self) {
+                    self.0.set(self.0.get() + 1);
+                }
+            }
+
+            let drops = Cell::new(0);
+
+            // the closure consume the partial chunk.
+            let ans = (0..5)
+                .map(|_| Some(DropCounter(&drops)))
+                .first_none_or_else_chunks(|iter| {
+                    let [_, _] = iter.next().unwrap();
+                    let [_, _] = iter.next().unwrap();
+                    assert!(iter.next().is_none());
+                    assert_eq!(drops.get(), 5);
+                });
+
+            assert_eq!(ans, Err(ChunkGap::Partial(1)));
+            assert_eq!(drops.get(), 5);
+
+            // the closure consume nothing.
+            let ans = (0..5)
+                .map(|_| Some(DropCounter(&drops)))
+                .first_none_or_else_chunks(|_: &mut crate::FirstNoneChunks<_, _, 2>| {});
+
+            assert_eq!(ans, Err(ChunkGap::Partial(1)));
+            assert_eq!(drops.get(), 10);
+        }
+
+        #[test]
+        fn _or_else_chunks_with_none_in_mid_chunk() {
+            use crate::ChunkGap;
+            use core::cell::Cell;
+
+            struct DropCounter<'a>(&'a Cell<usize>);
+
+            impl Drop for DropCounter<'_> {
+                fn drop(&mut self) {
+                    self.0.set(self.0.get() + 1);
+                }
+            }
+
+            let drops = Cell::new(0);
+
+            let ans = [Some(0), Some(1), Some(2), Some(3), None, Some(5)]
+                .into_iter()
+                .map(|opt| opt.map(|_| DropCounter(&drops)))
+                .first_none_or_else_chunks(|iter| {
+                    let [_, _, _] = iter.next().unwrap();
+                    assert_eq!(drops.get(), 3);
+
+                    // the buffered value in the interrupted chunk is dropped.
+                    assert!(iter.next().is_none());
+                    assert_eq!(drops.get(), 4);
+                });
+
+            assert_eq!(ans, Err(ChunkGap::None));
+            assert_eq!(drops.get(), 4);
+        }
+
+        #[test]
+        fn _or_else_chunks_none_take_priority_over_partial_chunk() {
+            use crate::ChunkGap;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .first_none_or_else_chunks(|iter| iter.map(|[a, b]| a + b).sum::<u8>());
+
+            assert_eq!(ans, Err(ChunkGap::None));
+        }
+
+        #[test]
+        fn _or_eq_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_eq([Some(0), Some(1), Some(2)]);
+            assert_eq!(ans, Some(true));
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_eq([Some(0), Some(42), Some(2)]);
+            assert_eq!(ans, Some(false));
+
+            let ans =
+                [Some::<u8>(0), Some(1)]
+                    .into_iter()
+                    .first_none_or_eq([Some(0), Some(1), Some(2)]);
+            assert_eq!(ans, Some(false));
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_eq([Some(0), Some(1)]);
+            assert_eq!(ans, Some(false));
+        }
+
+        #[test]
+        fn _or_eq_none_before_mismatch_wins() {
+            let ans = [Some::<u8>(0), None, Some(42)]
+                .into_iter()
+                .first_none_or_eq([Some(0), Some(1), Some(2)]);
+            assert_eq!(ans, None);
+
+            let ans = [Some::<u8>(0), Some(1), Some(42)]
+                .into_iter()
+                .first_none_or_eq([Some(0), None, Some(2)]);
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_eq_none_at_mismatch_position_wins() {
+            let ans = [Some::<u8>(0), Some(42)]
+                .into_iter()
+                .first_none_or_eq([Some(0), None]);
+            assert_eq!(ans, None);
+
+            // length mismatch at position 1.
+            let ans = [Some::<u8>(0)]
+                .into_iter()
+                .first_none_or_eq([Some(0), None]);
+            assert_eq!(ans, None);
+
+            let ans = [Some::<u8>(0), None]
+                .into_iter()
+                .first_none_or_eq([Some(0)]);
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_eq_none_in_current_stops_other_at_same_position() {
+            let mut other_next_count = 0;
+
+            let ans = [Some::<u8>(0), None].into_iter().first_none_or_eq(
+                [Some(0), Some(1)]
+                    .into_iter()
+                    .inspect(|_| other_next_count += 1),
+            );
+
+            assert_eq!(ans, None);
+            assert_eq!(other_next_count, 1);
+        }
+
+        #[test]
+        fn _or_eq_none_after_mismatch_is_ignored() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2), Some(42), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_none_or_eq([Some(0), Some(1), Some(2), Some(3), None]);
+
+            assert_eq!(ans, Some(false));
+            assert_eq!(orig_iter_next_count, 4);
+        }
+
+        #[test]
+        fn _or_cmp_without_none() {
+            use core::cmp::Ordering;
+
+            type Case<'a> = (&'a [Option<u8>], &'a [Option<u8>]);
+
+            let cases: [Case; 5] = [
+                (&[Some(0), Some(1)], &[Some(0), Some(1)]),
+                (&[Some(0), Some(1)], &[Some(0), Some(2)]),
+                (&[Some(0), Some(3)], &[Some(0), Some(2)]),
+                (&[Some(0)], &[Some(0), Some(1)]),
+                (&[Some(0), Some(1)], &[]),
+            ];
+
+            for (a, b) in cases {
+                let expected = a.iter().flatten().cmp(b.iter().flatten());
+
+                assert_eq!(
+                    a.iter().cloned().first_none_or_cmp(b.iter().cloned()),
+                    Some(expected)
+                );
+            }
+
+            assert_eq!(
+                [Some::<u8>(0), Some(1)]
+                    .into_iter()
+                    .first_none_or_cmp([Some(0), Some(2)]),
+                Some(Ordering::Less),
+            );
+        }
+
+        #[test]
+        fn _or_cmp_ordering_decided_before_none() {
+            use core::cmp::Ordering;
+
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), None]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_none_or_cmp([Some(0), Some(0), None]);
+
+            assert_eq!(ans, Some(Ordering::Greater));
+            assert_eq!(orig_iter_next_count, 2);
+
+            // `None` after the shorter one exhausted.
+            let ans = [Some::<u8>(0)]
+                .into_iter()
+                .first_none_or_cmp([Some(0), Some(1), None]);
+
+            assert_eq!(ans, Some(Ordering::Less));
+        }
+
+        #[test]
+        fn _or_cmp_none_appears_first() {
+            let ans = [Some::<u8>(0), None, Some(0)]
+                .into_iter()
+                .first_none_or_cmp([Some(0), Some(0), Some(1)]);
+            assert_eq!(ans, None);
+
+            let ans = [Some::<u8>(0), Some(2)]
+                .into_iter()
+                .first_none_or_cmp([Some(0), None]);
+            assert_eq!(ans, None);
+
+            // `None` at the position where the shorter one exhausted.
+            let ans = [Some::<u8>(0)]
+                .into_iter()
+                .first_none_or_cmp([Some(0), None]);
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_is_sorted_without_none() {
+            let ans = [Some::<u8>(0), Some(1), Some(1), Some(2)]
+                .into_iter()
+                .first_none_or_is_sorted();
+            assert_eq!(ans, Some(true));
+
+            let ans = [Some::<u8>(0), Some(2), Some(1), Some(3)]
+                .into_iter()
+                .first_none_or_is_sorted();
+            assert_eq!(ans, Some(false));
+
+            let ans = core::iter::empty::<Option<u8>>().first_none_or_is_sorted();
+            assert_eq!(ans, Some(true));
+        }
+
+        #[test]
+        fn _or_is_sorted_with_none_before_unsorted_pair() {
+            let ans = [Some::<u8>(0), None, Some(3), Some(2)]
+                .into_iter()
+                .first_none_or_is_sorted();
+            assert_eq!(ans, None);
+
+            // `None` at the first position.
+            let ans = [None, Some::<u8>(3), Some(2)]
+                .into_iter()
+                .first_none_or_is_sorted();
+            assert_eq!(ans, None);
+
+            // sorted until the end, so the `None` always wins.
+            let ans = [Some::<u8>(0), Some(1), Some(2), None]
+                .into_iter()
+                .first_none_or_is_sorted();
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_is_sorted_with_none_after_unsorted_pair() {
+            let mut orig_iter_next_count = 0;
+            let mut compare_count = 0;
+
+            let ans = [Some::<u8>(0), Some(2), Some(1), None, Some(4)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_none_or_is_sorted_by(|a, b| {
+                    compare_count += 1;
+                    a <= b
+                });
+
+            assert_eq!(ans, Some(false));
+            assert_eq!(orig_iter_next_count, 3);
+            assert_eq!(compare_count, 2);
+        }
+
+        #[test]
+        fn _or_else_recover_with_all_nones_recoverable() {
+            let ans = [Some::<u8>(0), None, Some(2), None]
+                .into_iter()
+                .first_none_or_else_recover(|| Some(10), |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(22));
+        }
+
+        #[test]
+        fn _or_else_recover_with_budget_of_one() {
+            // unrecoverable `None` found by the closure.
+            let mut budget = 1;
+            let ans = [Some::<u8>(0), None, Some(2), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_recover(
+                    || {
+                        if budget > 0 {
+                            budget -= 1;
+                            Some(1)
+                        } else {
+                            None
+                        }
+                    },
+                    |iter| {
+                        assert_eq!(iter.next(), Some(0));
+                        assert_eq!(iter.next(), Some(1));
+                        assert_eq!(iter.next(), Some(2));
+                        assert_eq!(iter.next(), None);
+                    },
+                );
+            assert_eq!(ans, None);
+
+            // unrecoverable `None` found in the drain after the closure.
+            let mut budget = 1;
+            let ans = [Some::<u8>(0), None, Some(2), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_recover(
+                    || {
+                        if budget > 0 {
+                            budget -= 1;
+                            Some(1)
+                        } else {
+                            None
+                        }
+                    },
+                    |iter| iter.next(),
+                );
+            assert_eq!(ans, None);
+            assert_eq!(budget, 0);
+
+            // only one `None`, recovered in the drain after the closure.
+            let mut budget = 1;
+            let ans = [Some::<u8>(0), Some(2), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_recover(
+                    || {
+                        if budget > 0 {
+                            budget -= 1;
+                            Some(1)
+                        } else {
+                            None
+                        }
+                    },
+                    |iter| iter.next(),
+                );
+            assert_eq!(ans, Some(Some(0)));
+            assert_eq!(budget, 0);
+        }
+
+        #[test]
+        fn _or_else_flat_with_uneven_chunks() {
+            let ans = [
+                Some::<&[u8]>(&[0, 1, 2]),
+                Some(&[]),
+                Some(&[3]),
+                Some(&[4, 5]),
+            ]
+            .into_iter()
+            .first_none_or_else_flat(|iter| {
+                assert_eq!(iter.next(), Some(&0));
+                iter.fold(0u32, |acc, &n| acc * 10 + n as u32)
+            });
+
+            assert_eq!(ans, Some(12345));
+        }
+
+        #[test]
+        fn _or_else_flat_with_none_between_chunks() {
+            let ans = [
+                Some::<&[u8]>(&[0, 1]),
+                Some(&[2]),
+                None,
+                Some(&[4, 5]),
+                None,
+            ]
+            .into_iter()
+            .first_none_or_else_flat(|iter| {
+                assert_eq!(iter.next(), Some(&0));
+                assert_eq!(iter.next(), Some(&1));
+                assert_eq!(iter.next(), Some(&2));
+                assert_eq!(iter.next(), None);
+            });
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_flat_never_iterate_inside_chunks_in_drain() {
+            use core::cell::Cell;
+
+            let elem_count = Cell::new(0);
+            let mut chunk_count = 0;
+
+            let ans = [Some::<&[u8]>(&[0, 1, 2]), Some(&[3, 4]), Some(&[5]), None]
+                .into_iter()
+                .inspect(|_| chunk_count += 1) // side-effect
+                .map(|opt| {
+                    opt.map(|chunk| {
+                        chunk
+                            .iter()
+                            .inspect(|_| elem_count.set(elem_count.get() + 1))
+                    })
+                })
+                .first_none_or_else_flat(|iter| iter.next());
+
+            assert_eq!(ans, None);
+            assert_eq!(chunk_count, 4);
+            assert_eq!(elem_count.get(), 1);
+        }
+
+        #[test]
+        fn _first_none_ref_or_else_without_none() {
+            let options = [Some::<u8>(0), Some(1), Some(2)];
+
+            let ans = options
+                .iter()
+                .first_none_ref_or_else(|iter| iter.copied().sum::<u8>());
+
+            assert_eq!(ans, Some(3));
+        }
+
+        #[test]
+        fn _first_none_ref_or_else_with_none() {
+            let options = [Some::<u8>(0), None, Some(2)];
+
+            let ans = options
+                .iter()
+                .first_none_ref_or_else(|iter| iter.copied().sum::<u8>());
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _first_none_ref_or_else_output_ref_from_source() {
+            let options = [Some::<u8>(0), Some(1), Some(2)];
+
+            let ans = options.iter().first_none_ref_or_else(|iter| iter.last());
+
+            // the returned reference points into `options` itself.
+            let last = ans.flatten().unwrap();
+            assert!(core::ptr::eq(last, options[2].as_ref().unwrap()));
+        }
+
+        #[test]
+        fn _first_none_copied_or_else() {
+            let options = [Some::<u8>(0), Some(1), None, Some(3)];
+
+            let ans = options
+                .iter()
+                .first_none_copied_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+
+            let ans = options[..2]
+                .iter()
+                .first_none_copied_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(1));
+        }
+
+        #[test]
+        fn _first_none_cloned_or_else_clone_only_consumed_items() {
+            use core::cell::Cell;
+
+            #[derive(Debug, PartialEq)]
+            struct CloneCounter<'a>(u8, &'a Cell<usize>);
+
+            impl Clone for CloneCounter<'_> {
+                fn clone(&self) -> Self {
+                    self.1.set(self.1.get() + 1);
+                    Self(self.0, self.1)
+                }
+            }
+
+            let clones = Cell::new(0);
+            let some = |n| Some(CloneCounter(n, &clones));
+
+            // the closure consumed nothing, but drain meet many `Some` items.
+            let options = [some(0), some(1), some(2), None, some(4)];
+            let ans = options.iter().first_none_cloned_or_else(|_| ());
+
+            assert_eq!(ans, None);
+            assert_eq!(clones.get(), 0);
+
+            // the closure consumed one value only.
+            let ans = options
+                .iter()
+                .first_none_cloned_or_else(|iter| iter.next().map(|c| c.0));
+
+            assert_eq!(ans, None);
+            assert_eq!(clones.get(), 1);
+
+            // the closure consumed all values.
+            clones.set(0);
+            let ans = options[..3]
+                .iter()
+                .first_none_cloned_or_else(|iter| iter.map(|c| c.0).sum::<u8>());
+
+            assert_eq!(ans, Some(3));
+            assert_eq!(clones.get(), 3);
+        }
+
+        #[test]
+        fn _or_else_with_fn_item_and_moved_state() {
+            use crate::FirstNoneIter;
+
+            /// A non-`Copy` state.
+            #[derive(Debug, PartialEq)]
+            struct Acc {
+                total: u32,
+                count: usize,
+            }
+
+            fn accumulate<I>(mut acc: Acc, iter: &mut FirstNoneIter<I, u8>) -> Acc
+            where
+                I: Iterator<Item = Option<u8>>,
+            {
+                for n in iter {
+                    acc.total += n as u32;
+                    acc.count += 1;
+                }
+                acc
+            }
+
+            let init = Acc {
+                total: 100,
+                count: 0,
+            };
+            let ans = [Some(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_else_with(init, accumulate);
+
+            assert_eq!(
+                ans,
+                Some(Acc {
+                    total: 106,
+                    count: 3
+                })
+            );
+
+            let init = Acc {
+                total: 100,
+                count: 0,
+            };
+            let ans = [Some(1), None, Some(3)]
+                .into_iter()
+                .first_none_or_else_with(init, accumulate);
+
+            assert_eq!(ans, None);
+        }
+
+        #[test]
+        fn _or_else_with_fn_pointer() {
+            use crate::FirstNoneIter;
+
+            type Iter = FirstNoneIter<core::array::IntoIter<Option<u8>, 3>, u8>;
+
+            let f: fn(u8, &mut Iter) -> u8 = |offset, iter| iter.map(|n| n + offset).sum();
+
+            let ans = [Some(1), Some(2), Some(3)]
+                .into_iter()
+                .first_none_or_else_with(1, f);
+
+            assert_eq!(ans, Some(9));
+        }
+
+        #[test]
+        fn _inspect_or_else_without_none() {
+            let mut hook_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .inspect_first_none_or_else(|| hook_count += 1, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Some(3));
+            assert_eq!(hook_count, 0);
+        }
+
+        #[test]
+        fn _inspect_or_else_with_none_found_by_closure() {
+            let mut hook_count = 0;
+
+            let ans = [Some::<u8>(0), None, Some(2), None]
+                .into_iter()
+                .inspect_first_none_or_else(|| hook_count += 1, |iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+            assert_eq!(hook_count, 1);
+        }
+
+        #[test]
+        fn _inspect_or_else_with_none_found_by_drain() {
+            let mut hook_count = 0;
+
+            let ans = [Some::<u8>(0), Some(1), None, None]
+                .into_iter()
+                .inspect_first_none_or_else(|| hook_count += 1, |iter| iter.next());
+
+            assert_eq!(ans, None);
+            assert_eq!(hook_count, 1);
+        }
+
+        #[test]
+        fn _until_none_without_none() {
+            let mut flag = true;
+
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .until_none(&mut flag)
+                .sum::<u8>();
+
+            assert_eq!(ans, 3);
+            assert!(!flag);
+        }
+
+        #[test]
+        fn _until_none_with_none() {
+            let mut flag = false;
+            let mut orig_iter_next_count = 0;
+
+            let mut iter = [Some::<u8>(0), None, Some(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1)
+                .until_none(&mut flag);
+
+            assert_eq!(iter.next(), Some(0));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.next(), None);
+            drop(iter);
+
+            assert!(flag);
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _until_none_with_partial_consumption() {
+            let mut flag = false;
+
+            let ans = [Some::<u8>(0), Some(1), None]
+                .into_iter()
+                .until_none(&mut flag)
+                .take(2)
+                .sum::<u8>();
+
+            // the `None` was never hit by the consumer.
+            assert_eq!(ans, 1);
+            assert!(!flag);
+        }
+
+        #[test]
+        fn _collect_array_or_none_exact_fit() {
+            let ans = [Some::<u8>(0), Some(1), Some(2)]
+                .into_iter()
+                .collect_array_or_none::<3, _>();
+
+            assert_eq!(ans, Ok([0, 1, 2]));
+        }
+
+        #[test]
+        fn _collect_array_or_none_leave_extra_items_untouched() {
+            let mut orig_iter_next_count = 0;
+
+            let mut iter = [Some::<u8>(0), Some(1), None, Some(3)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1);
+
+            let ans = iter.by_ref().collect_array_or_none::<2, _>();
+            assert_eq!(ans, Ok([0, 1]));
+
+            assert_eq!(iter.next(), Some(None));
+            drop(iter);
+            assert_eq!(orig_iter_next_count, 3);
+        }
+
+        #[test]
+        fn _collect_array_or_none_with_none_at_last_position() {
+            use crate::ArrayCollectGap;
+            use core::cell::Cell;
+
+            #[derive(Debug)]
+            struct DropCounter<'a>(&'a Cell<usize>);
+
+            impl Drop for DropCounter<'_> {
+                fn drop(&mut self) {
+                    self.0.set(self.0.get() + 1);
+                }
+            }
+
+            let drop_count = Cell::new(0);
+
+            let ans = [
+                Some(DropCounter(&drop_count)),
+                Some(DropCounter(&drop_count)),
+                None,
+            ]
+            .into_iter()
+            .collect_array_or_none::<3, _>();
+
+            assert!(matches!(ans, Err(ArrayCollectGap::None { index: 2 })));
+            assert_eq!(drop_count.get(), 2);
+        }
+
+        #[test]
+        fn _collect_array_or_none_too_short() {
+            use crate::ArrayCollectGap;
+
+            let ans = [Some::<u8>(0), Some(1)]
+                .into_iter()
+                .collect_array_or_none::<4, _>();
+
+            assert_eq!(ans, Err(ArrayCollectGap::TooShort { got: 2 }));
+        }
+
+        #[test]
+        fn _methods_can_call_through_trait_object() {
+            let mut array_iter = [Some(0u8), None, None].into_iter();
+
+            fn take_dyn(iter: &mut dyn Iterator<Item = Option<u8>>) {
+                iter.first_none_or_else(|iter| iter.sum::<u8>());
+                iter.first_none_or(0);
+                iter.first_none_or_try(|iter| Some(iter.sum::<u8>()));
             }
+
+            take_dyn(&mut array_iter);
+        }
+
+        #[test]
+        fn _or_else_iter_debug_without_debug_inner() {
+            use super::FmtBuf;
+
+            // A closure-based inner iterator and a non-`Debug` value type.
+            struct NonDebug;
+
+            let mut items = [Some(NonDebug), None].into_iter();
+            let inner = core::iter::from_fn(move || items.next());
+
+            let ans = inner.first_none_or_else(|iter| {
+                assert_eq!(
+                    FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                    "FirstNoneIter { state: Active }"
+                );
+                assert!(iter.next().is_some());
+                assert!(iter.next().is_none());
+                assert_eq!(
+                    FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                    "FirstNoneIter { state: FoundFirstNone }"
+                );
+            });
+
+            assert!(ans.is_none());
+
+            [Some(0_u8)].into_iter().first_none_or_else(|iter| {
+                iter.for_each(drop);
+                assert_eq!(
+                    FmtBuf::format(format_args!("{iter:?}")).as_str(),
+                    "FirstNoneIter { state: Exhausted }"
+                );
+            });
+        }
+
+        #[test]
+        fn _gap_or_else_without_gap() {
+            let ans = [Some(Ok::<u8, u8>(0)), Some(Ok(1)), Some(Ok(2))]
+                .into_iter()
+                .first_gap_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(3));
         }
 
-        #[inline]
-        fn size_hint(&self) -> (usize, Option<usize>) {
-            match &self.state {
-                State::Active(inner) => inner.size_hint(),
-                State::FoundFirstErr(_) => (0, Some(0)),
-                State::Exhausted => (0, Some(0)),
-            }
+        #[test]
+        fn _gap_or_else_with_none_first() {
+            let ans = [Some(Ok::<u8, u8>(0)), None, Some(Err(2)), Some(Ok(3))]
+                .into_iter()
+                .first_gap_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(crate::Gap::None));
+        }
+
+        #[test]
+        fn _gap_or_else_with_err_first() {
+            let ans = [Some(Ok::<u8, u8>(0)), Some(Err(1)), None, Some(Err(3))]
+                .into_iter()
+                .first_gap_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(crate::Gap::Err(1)));
         }
-    }
 
-    impl<I, T, E> FusedIterator for FirstErrIter<I, T, E> where I: Iterator<Item = Result<T, E>> {}
+        #[test]
+        fn _gap_or_else_not_consume_iter_manually() {
+            let ans = [Some(Ok::<u8, u8>(0)), Some(Ok(1)), Some(Err(2)), None]
+                .into_iter()
+                .first_gap_or_else(|_| 42);
 
-    /// Internal state of [`FirstErrIter`].
-    #[derive(Debug)]
-    enum State<I, T, E>
-    where
-        I: Iterator<Item = Result<T, E>>,
-    {
-        Active(I),
-        FoundFirstErr(E),
-        Exhausted,
-    }
-}
+            assert_eq!(ans, Err(crate::Gap::Err(2)));
+        }
 
-mod option {
-    use core::iter::FusedIterator;
+        #[test]
+        fn _gap_or_else_iter_stop_on_first_gap() {
+            let mut count = 0;
 
-    /// An `Iterator` can take first `None` from another iterator.
-    ///
-    /// See [`FirstErr::first_none_or_else()`](crate::FirstErr::first_none_or_else) for more details.
-    #[derive(Debug)]
-    pub struct FirstNoneIter<I, T>
-    where
-        I: Iterator<Item = Option<T>>,
-    {
-        state: State<I, T>,
-    }
+            let ans = [Some(Ok::<u8, u8>(0)), None, Some(Ok(2)), Some(Err(3))]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_gap_or_else(|iter| iter.count());
 
-    impl<I, T> FirstNoneIter<I, T>
-    where
-        I: Iterator<Item = Option<T>>,
-    {
-        #[inline]
-        pub(super) fn first_none_or_else<O, F>(inner: I, f: F) -> Option<O>
-        where
-            F: FnOnce(&mut Self) -> O,
-        {
-            let mut me = Self {
-                state: State::Active(inner),
-            };
+            assert_eq!(ans, Err(crate::Gap::None));
+            assert_eq!(count, 2);
+        }
 
-            let output = f(&mut me);
+        #[test]
+        fn _gap_or_else_is_mirror_of_failure_or_else_through_transpose() {
+            use crate::Gap;
 
-            // Take first None, if not found and not exhausted yet, find it.
-            // If just not found finally, return output.
-            match me.state {
-                State::Active(inner) => {
-                    for opt in inner {
-                        let _ = opt?;
+            const MAX_LEN: usize = 5;
+
+            // Enumerate every sequence up to `MAX_LEN` items, each item is one of
+            // `Some(Ok(_))`, `None` or `Some(Err(_))`, and payloads are the positions.
+            for len in 0..=MAX_LEN {
+                for code in 0..3_u32.pow(len as u32) {
+                    let mut case = [None::<Result<u8, u8>>; MAX_LEN];
+                    let mut rest = code;
+                    for (i, item) in case.iter_mut().take(len).enumerate() {
+                        *item = match rest % 3 {
+                            0 => Some(Ok(i as u8)),
+                            1 => None,
+                            _ => Some(Err(i as u8)),
+                        };
+                        rest /= 3;
                     }
-                    Some(output)
+                    let case = &case[..len];
+
+                    let by_gap = case.iter().copied().first_gap_or_else(|iter| {
+                        iter.fold(0_u32, |acc, x| acc * 10 + x as u32 + 1)
+                    });
+                    let by_failure = case
+                        .iter()
+                        .copied()
+                        .map(Option::transpose)
+                        .first_failure_or_else(|iter| {
+                            iter.fold(0_u32, |acc, x| acc * 10 + x as u32 + 1)
+                        });
+
+                    let expected = match by_failure {
+                        Ok(Some(output)) => Ok(output),
+                        Ok(None) => Err(Gap::None),
+                        Err(e) => Err(Gap::Err(e)),
+                    };
+                    assert_eq!(by_gap, expected, "case: {:?}", case);
                 }
-                State::Exhausted => Some(output),
-                State::FoundFirstNone => None,
             }
         }
+
+        #[test]
+        fn _none_into_err_map_every_item() {
+            let mut count = 0;
+            let mut iter = [None, Some::<u8>(1), None].into_iter().none_into_err(|| {
+                count += 1;
+                count
+            });
+
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            assert_eq!(iter.next(), Some(Err(1)));
+            assert_eq!(iter.next(), Some(Ok(1)));
+            assert_eq!(iter.next(), Some(Err(2)));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _none_into_err_compose_with_first_err_or_else() {
+            let ans = [Some::<u8>(0), None, Some(2), None]
+                .into_iter()
+                .none_into_err(|| "missing")
+                .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err("missing"));
+        }
+
+        #[test]
+        fn _none_into_err_and_err_into_none_round_trip() {
+            let mut slot = None;
+
+            let ans = [Some::<u8>(0), Some(1), None]
+                .into_iter()
+                .none_into_err(|| "missing")
+                .err_into_none(Some(&mut slot))
+                .first_none_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, None);
+            assert_eq!(slot, Some("missing"));
+        }
     }
 
-    impl<I, T> Iterator for FirstNoneIter<I, T>
-    where
-        I: Iterator<Item = Option<T>>,
-    {
-        type Item = T;
+    mod test_first_break {
+        //! Test first_break_* methods.
 
-        #[inline]
-        fn next(&mut self) -> Option<Self::Item> {
-            match &mut self.state {
-                State::Active(inner) => match inner.next() {
-                    Some(Some(t)) => Some(t),
-                    Some(None) => {
-                        self.state = State::FoundFirstNone;
-                        None
-                    }
-                    None => {
-                        self.state = State::Exhausted;
-                        None
-                    }
-                },
-                State::FoundFirstNone => None,
-                State::Exhausted => None,
+        use crate::FirstErr;
+        use core::ops::ControlFlow::{self, Break, Continue};
+
+        #[test]
+        fn _or_else_with_1_layer_data_and_without_break() {
+            let ans = [Continue::<u8, u8>(0), Continue(1), Continue(2), Continue(3)]
+                .into_iter()
+                .first_break_or_else(|iter1| iter1.sum::<u8>());
+
+            assert_eq!(ans, Continue(6));
+        }
+
+        #[test]
+        fn _or_else_with_1_layer_data_and_with_break() {
+            let ans = [Continue::<u8, u8>(0), Continue(1), Break(2), Continue(3)]
+                .into_iter()
+                .first_break_or_else(|iter1| iter1.sum::<u8>());
+
+            assert_eq!(ans, Break(2));
+        }
+
+        #[test]
+        fn _or_else_with_2_layer_data_and_outmost_break_in_layer_2() {
+            let ans = [
+                Continue::<u8, ControlFlow<u8, u8>>(Continue(0)),
+                Continue(Break(1)),
+                Continue(Break(2)),
+                Continue(Continue(3)),
+            ]
+            .into_iter()
+            .first_break_or_else(|iter1| iter1.first_break_or_else(|iter2| iter2.sum::<u8>()));
+
+            assert_eq!(ans, Continue(Break(1)));
+        }
+
+        #[test]
+        fn _or_else_not_need_to_consume_iter_manually() {
+            let ans = [Continue::<u8, u8>(0), Break(1), Break(2)]
+                .into_iter()
+                .first_break_or_else(|_iter| {});
+
+            assert_eq!(ans, Break(1));
+        }
+
+        #[test]
+        fn _or_else_never_call_next_on_orig_iter_after_first_break_found() {
+            let mut orig_iter_next_count = 0;
+
+            let ans = [Continue::<u8, u8>(0), Break(1), Break(2)]
+                .into_iter()
+                .inspect(|_| orig_iter_next_count += 1) // side-effect
+                .first_break_or_else(|iter| {
+                    // exhaust whole iter.
+                    for _ in &mut *iter {}
+
+                    // call iter.next() after the iter already exhausted.
+                    assert_eq!(iter.next(), None);
+                });
+
+            assert_eq!(ans, Break(1));
+            assert_eq!(orig_iter_next_count, 2);
+        }
+
+        #[test]
+        fn _or_else_use_lazy_evaluation() {
+            use core::cell::{Cell, RefCell};
+
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            enum Trace {
+                None,
+                Outer(ControlFlow<u8, u8>),
+                Inner(u8),
+            }
+
+            // if index >= N, it will panic.
+            fn record_trace<const N: usize>(
+                traces: &RefCell<[Trace; N]>,
+                idx: &Cell<usize>,
+                v: Trace,
+            ) {
+                let i = idx.get();
+                traces.borrow_mut()[i] = v;
+                idx.set(i + 1);
             }
+
+            // already known N = 5 within [_; N] in this test case.
+            let traces = RefCell::new([Trace::None; 5]);
+
+            let index = Cell::new(0);
+
+            let ans = [Continue::<u8, u8>(0), Continue(1), Break(2), Continue(3)]
+                .iter()
+                .cloned()
+                // record value from outer
+                .inspect(|&flow| record_trace(&traces, &index, Trace::Outer(flow)))
+                .first_break_or_else(|iter| {
+                    iter
+                        // record value from inner
+                        .inspect(|&n| record_trace(&traces, &index, Trace::Inner(n)))
+                        .sum::<u8>()
+                });
+
+            assert_eq!(ans, Break(2));
+            assert_eq!(
+                traces.into_inner(),
+                [
+                    Trace::Outer(Continue(0)),
+                    Trace::Inner(0),
+                    Trace::Outer(Continue(1)),
+                    Trace::Inner(1),
+                    Trace::Outer(Break(2))
+                ]
+            );
         }
 
-        #[inline]
-        fn size_hint(&self) -> (usize, Option<usize>) {
-            match &self.state {
-                State::Active(inner) => inner.size_hint(),
-                State::FoundFirstNone => (0, Some(0)),
-                State::Exhausted => (0, Some(0)),
+        #[test]
+        fn _or_try_without_break_and_closure_produce_continue() {
+            let ans = [Continue::<u8, u8>(0), Continue(1), Continue(2)]
+                .into_iter()
+                .first_break_or_try(|iter| match iter.nth(1) {
+                    Some(n) => Continue(n),
+                    None => Break(1),
+                });
+
+            assert_eq!(ans, Continue(1));
+        }
+
+        #[test]
+        fn _or_try_without_break_and_closure_produce_break() {
+            let ans = [Continue::<u8, u8>(0), Continue(1), Continue(2)]
+                .into_iter()
+                .first_break_or_try(|iter| match iter.nth(100) {
+                    Some(n) => Continue(n),
+                    None => Break(100),
+                });
+
+            assert_eq!(ans, Break(100));
+        }
+
+        #[test]
+        fn _or_try_with_break_and_closure_produce_continue() {
+            let ans = [Continue::<u8, u8>(0), Continue(1), Break(2), Continue(3)]
+                .into_iter()
+                .first_break_or_try(|iter| match iter.nth(1) {
+                    Some(n) => Continue(n),
+                    None => Break(1),
+                });
+
+            assert_eq!(ans, Break(2));
+        }
+
+        #[test]
+        fn _or_try_with_break_and_closure_produce_break() {
+            let ans = [Continue::<u8, u8>(0), Continue(1), Break(2), Continue(3)]
+                .into_iter()
+                .first_break_or_try(|iter| match iter.nth(100) {
+                    Some(n) => Continue(n),
+                    None => Break(100),
+                });
+
+            assert_eq!(ans, Break(2));
+        }
+
+        #[test]
+        fn _or_else_with_non_fused_iterator() {
+            struct NonFusedIter {
+                curr: u32,
+            }
+
+            impl Iterator for NonFusedIter {
+                type Item = ControlFlow<u32, u32>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    let tmp = self.curr;
+                    self.curr += 1;
+
+                    match tmp % 3 {
+                        0 => Some(Continue(tmp)),
+                        1 => None,
+                        2 => Some(Break(tmp)),
+                        _ => unreachable!(),
+                    }
+                }
             }
+
+            let ans = NonFusedIter { curr: 0 }.first_break_or_else(|iter| iter.sum::<u32>());
+
+            assert_eq!(ans, Continue(0));
         }
-    }
 
-    impl<I, T> FusedIterator for FirstNoneIter<I, T> where I: Iterator<Item = Option<T>> {}
+        #[test]
+        fn _methods_can_call_through_trait_object() {
+            let mut array_iter = [Continue::<u8, u8>(0), Break(1), Break(2)].into_iter();
 
-    /// Internal state of [`FirstNoneIter`].
-    #[derive(Debug)]
-    enum State<I, T>
-    where
-        I: Iterator<Item = Option<T>>,
-    {
-        Active(I),
-        FoundFirstNone,
-        Exhausted,
+            fn take_dyn(iter: &mut dyn Iterator<Item = ControlFlow<u8, u8>>) {
+                let _ = iter.first_break_or_else(|iter| iter.sum::<u8>());
+                let _ = iter.first_break_or_try(|iter| Continue(iter.sum::<u8>()));
+            }
+
+            take_dyn(&mut array_iter);
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    mod test_first_err {
-        //! Test first_err_* methods.
+    mod test_first_false {
+        //! Test first_false_* methods.
 
         use crate::FirstErr;
 
         #[test]
-        fn _or_else_with_1_layer_data_and_without_err() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+        fn _or_else_without_false() {
+            let ans = [true, true, true, true]
                 .into_iter()
-                .first_err_or_else(|iter1| iter1.sum::<u8>());
+                .first_false_or_else(|iter| iter.sum::<usize>());
 
-            assert_eq!(ans, Ok(10));
+            assert_eq!(ans, Ok(6));
         }
 
         #[test]
-        fn _or_else_with_1_layer_data_and_with_err() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
+        fn _or_else_with_false() {
+            let ans = [true, true, false, true, false]
                 .into_iter()
-                .first_err_or_else(|iter1| iter1.sum::<u8>());
+                .first_false_or_else(|iter| iter.sum::<usize>());
 
             assert_eq!(ans, Err(2));
         }
 
         #[test]
-        fn _or_else_with_2_layer_data_and_outmost_err_in_layer_1() {
-            let ans = [
-                Ok::<Result<u8, u8>, u8>(Ok(0)),
-                Ok(Err(1)),
-                Err(2),
-                Ok(Ok(3)),
-                Ok(Ok(4)),
-            ]
-            .into_iter()
-            .first_err_or_else(|iter1| {
-                iter1
-                    // could chain other ops
-                    .first_err_or_else(|iter2| iter2.sum::<u8>())
-            });
+        fn _or_else_with_false_at_first() {
+            let ans = [false, true, true]
+                .into_iter()
+                .first_false_or_else(|iter| iter.count());
 
-            assert_eq!(ans, Err(2));
+            assert_eq!(ans, Err(0));
         }
 
         #[test]
-        fn _or_else_with_2_layer_data_and_outmost_err_in_layer_2() {
-            let ans = [
-                Ok::<Result<u8, u8>, u8>(Ok(0)),
-                Ok(Ok(1)),
-                Ok(Err(2)),
-                Ok(Err(3)),
-                Ok(Ok(4)),
-            ]
-            .into_iter()
-            .first_err_or_else(|iter1| {
-                iter1
-                    // could chain other ops
-                    .first_err_or_else(|iter2| iter2.sum::<u8>())
-            });
+        fn _or_else_with_empty_iter() {
+            let ans = core::iter::empty().first_false_or_else(|iter| iter.count());
 
-            assert_eq!(ans, Ok(Err(2)));
+            assert_eq!(ans, Ok(0));
         }
 
         #[test]
-        fn _or_else_with_3_layer_data_and_outmost_err_in_layer_2() {
-            let ans = [
-                Ok::<Result<Result<u8, u8>, u8>, u8>(Ok(Ok(0))),
-                Ok(Ok(Ok(1))),
-                Ok(Ok(Err(2))),
-                Ok(Err(3)),
-                Ok(Ok(Ok(4))),
-            ]
-            .into_iter()
-            .first_err_or_else(|iter1| {
-                iter1
-                    // could chain other ops
-                    .first_err_or_else(|iter2| {
-                        iter2
-                            // could chain other ops
-                            .first_err_or_else(|iter3| iter3.sum::<u8>())
-                    })
-            });
+        fn _or_else_not_need_to_consume_iter_manually() {
+            let ans = [true, true, true, false, false]
+                .into_iter()
+                .first_false_or_else(|_iter| {});
 
-            assert_eq!(ans, Ok(Err(3)));
+            assert_eq!(ans, Err(3));
         }
 
         #[test]
-        fn _or_else_not_need_to_consume_iter_manually() {
-            let ans = [Ok::<u8, u8>(0), Err(1), Err(2)]
+        fn _or_else_find_index_after_closure_stopped() {
+            let ans = [true, true, true, false, false]
                 .into_iter()
-                .first_err_or_else(|_iter| {});
+                .first_false_or_else(|iter| iter.next());
 
-            assert_eq!(ans, Err(1));
+            assert_eq!(ans, Err(3));
         }
 
-        /// In most cases, API users should not be concerned about how many times the original
-        /// iterator's `.next()` method is called, as it gets consumed after
-        /// `first_err_or_else()` is called.
-        ///
-        /// However, if the inner iterator has some side-effect, this behavior is still
-        /// observable, and users may rely on it.
-        ///
-        /// This test is designed to ensure that this behavior remains consistent even when
-        /// the code changes.
         #[test]
-        fn _or_else_never_call_next_on_orig_iter_after_first_err_found() {
+        fn _or_else_never_call_next_on_orig_iter_after_first_false_found() {
             let mut orig_iter_next_count = 0;
 
-            [Ok::<u8, u8>(0), Err(1), Err(2)]
+            [true, false, false]
                 .into_iter()
                 .inspect(|_| orig_iter_next_count += 1) // side-effect
-                .first_err_or_else(|iter| {
+                .first_false_or_else(|iter| {
                     // exhaust whole iter.
                     for _ in &mut *iter {}
 
@@ -847,8 +14686,8 @@ mod tests {
             #[derive(Debug, Clone, Copy, PartialEq, Eq)]
             enum Trace {
                 None,
-                Outer(Result<u8, u8>),
-                Inner(u8),
+                Outer(bool),
+                Inner(usize),
             }
 
             // if index >= N, it will panic.
@@ -863,32 +14702,31 @@ mod tests {
             }
 
             // already known N = 5 within [_; N] in this test case.
-            // We don't use Vec here just bacause want to avoid `alloc` crate.
             let traces = RefCell::new([Trace::None; 5]);
 
             let index = Cell::new(0);
 
-            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3)]
+            let ans = [true, true, false, true]
                 .iter()
                 .cloned()
                 // record value from outer
-                .inspect(|&res| record_trace(&traces, &index, Trace::Outer(res)))
-                .first_err_or_else(|iter| {
+                .inspect(|&b| record_trace(&traces, &index, Trace::Outer(b)))
+                .first_false_or_else(|iter| {
                     iter
                         // record value from inner
                         .inspect(|&n| record_trace(&traces, &index, Trace::Inner(n)))
-                        .sum::<u8>()
+                        .sum::<usize>()
                 });
 
             assert_eq!(ans, Err(2));
             assert_eq!(
                 traces.into_inner(),
                 [
-                    Trace::Outer(Ok(0)),
+                    Trace::Outer(true),
                     Trace::Inner(0),
-                    Trace::Outer(Ok(1)),
+                    Trace::Outer(true),
                     Trace::Inner(1),
-                    Trace::Outer(Err(2))
+                    Trace::Outer(false)
                 ]
             );
         }
@@ -899,369 +14737,795 @@ mod tests {
                 curr: u32,
             }
 
-            impl NonFusedIter {
-                fn new() -> Self {
-                    Self { curr: 0 }
-                }
-            }
-
             impl Iterator for NonFusedIter {
-                type Item = Result<u32, u32>;
+                type Item = bool;
 
                 fn next(&mut self) -> Option<Self::Item> {
                     let tmp = self.curr;
                     self.curr += 1;
 
                     match tmp % 3 {
-                        0 => Some(Ok(tmp)),
+                        0 => Some(true),
                         1 => None,
-                        2 => Some(Err(tmp)),
+                        2 => Some(false),
                         _ => unreachable!(),
                     }
                 }
             }
 
-            let ans = NonFusedIter::new().first_err_or_else(|iter| iter.sum::<u32>());
+            let ans = NonFusedIter { curr: 0 }.first_false_or_else(|iter| iter.sum::<usize>());
 
             assert_eq!(ans, Ok(0));
         }
 
         #[test]
-        fn _or_without_err() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+        fn _methods_can_call_through_trait_object() {
+            let mut array_iter = [true, false, false].into_iter();
+
+            fn take_dyn(iter: &mut dyn Iterator<Item = bool>) {
+                let _ = iter.first_false_or_else(|iter| iter.sum::<usize>());
+            }
+
+            take_dyn(&mut array_iter);
+        }
+    }
+
+    mod test_first_failure_of {
+        //! Test first_failure_of_* methods.
+
+        use crate::{Fallible, FirstErr};
+        use core::ops::ControlFlow::{self, Break, Continue};
+
+        /// A third-party-style enum.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Tri {
+            Good(u8),
+            Soft(u8),
+            Hard(u8),
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum TriFailure {
+            Soft(u8),
+            Hard(u8),
+        }
+
+        impl Fallible for Tri {
+            type Ok = u8;
+            type Failure = TriFailure;
+
+            fn into_parts(self) -> Result<u8, TriFailure> {
+                match self {
+                    Tri::Good(n) => Ok(n),
+                    Tri::Soft(w) => Err(TriFailure::Soft(w)),
+                    Tri::Hard(e) => Err(TriFailure::Hard(e)),
+                }
+            }
+        }
+
+        #[test]
+        fn _or_else_with_custom_type_and_without_failure() {
+            let ans = [Tri::Good(0), Tri::Good(1), Tri::Good(2)]
                 .into_iter()
-                .first_err_or("no err");
+                .first_failure_of_or_else(|iter| iter.sum::<u8>());
 
-            assert_eq!(ans, Ok("no err"));
+            assert_eq!(ans, Ok(3));
         }
 
         #[test]
-        fn _or_with_err() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
+        fn _or_else_with_custom_type_and_with_failure() {
+            let ans = [Tri::Good(0), Tri::Soft(1), Tri::Hard(2), Tri::Good(3)]
                 .into_iter()
-                .first_err_or("no err");
+                .first_failure_of_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(TriFailure::Soft(1)));
+
+            let ans = [Tri::Good(0), Tri::Hard(1), Tri::Soft(2), Tri::Good(3)]
+                .into_iter()
+                .first_failure_of_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(TriFailure::Hard(1)));
+        }
+
+        #[test]
+        fn _or_else_with_2_layer_data() {
+            let ans = [Ok::<Tri, u8>(Tri::Good(0)), Ok(Tri::Soft(1)), Err(2)]
+                .into_iter()
+                .first_failure_of_or_else(|iter1| {
+                    iter1.first_failure_of_or_else(|iter2| iter2.sum::<u8>())
+                });
+
+            assert_eq!(ans, Err(2));
+        }
+
+        #[test]
+        fn _or_else_not_need_to_consume_iter_manually() {
+            let mut count = 0;
+
+            let ans = [Tri::Good(0), Tri::Hard(1), Tri::Soft(2)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_failure_of_or_else(|_| {});
+
+            assert_eq!(ans, Err(TriFailure::Hard(1)));
+            assert_eq!(count, 2);
+        }
+
+        #[test]
+        fn _or_else_is_same_as_first_err_or_else() {
+            let cases: [[Result<u8, u8>; 3]; 4] = [
+                [Ok(0), Ok(1), Ok(2)],
+                [Err(0), Ok(1), Ok(2)],
+                [Ok(0), Err(1), Err(2)],
+                [Ok(0), Ok(1), Err(2)],
+            ];
+
+            for case in cases {
+                assert_eq!(
+                    case.into_iter()
+                        .first_failure_of_or_else(|iter| iter.sum::<u8>()),
+                    case.into_iter().first_err_or_else(|iter| iter.sum::<u8>()),
+                );
+            }
+        }
+
+        #[test]
+        fn _or_else_is_same_as_first_none_or_else() {
+            let cases: [[Option<u8>; 3]; 4] = [
+                [Some(0), Some(1), Some(2)],
+                [None, Some(1), Some(2)],
+                [Some(0), None, None],
+                [Some(0), Some(1), None],
+            ];
+
+            for case in cases {
+                assert_eq!(
+                    case.into_iter()
+                        .first_failure_of_or_else(|iter| iter.sum::<u8>())
+                        .ok(),
+                    case.into_iter().first_none_or_else(|iter| iter.sum::<u8>()),
+                );
+            }
+        }
+
+        #[test]
+        fn _or_else_is_same_as_first_break_or_else() {
+            let cases: [[ControlFlow<u8, u8>; 3]; 4] = [
+                [Continue(0), Continue(1), Continue(2)],
+                [Break(0), Continue(1), Continue(2)],
+                [Continue(0), Break(1), Break(2)],
+                [Continue(0), Continue(1), Break(2)],
+            ];
+
+            for case in cases {
+                let by_failure = match case
+                    .into_iter()
+                    .first_failure_of_or_else(|iter| iter.sum::<u8>())
+                {
+                    Ok(c) => Continue(c),
+                    Err(b) => Break(b),
+                };
+
+                assert_eq!(
+                    by_failure,
+                    case.into_iter()
+                        .first_break_or_else(|iter| iter.sum::<u8>()),
+                );
+            }
+        }
+
+        #[test]
+        fn _methods_can_call_through_trait_object() {
+            let mut array_iter = [Tri::Good(0), Tri::Hard(1), Tri::Soft(2)].into_iter();
+
+            fn take_dyn(iter: &mut dyn Iterator<Item = Tri>) {
+                let _ = iter.first_failure_of_or_else(|iter| iter.sum::<u8>());
+            }
+
+            take_dyn(&mut array_iter);
+        }
+    }
+
+    mod test_sources {
+        //! Test fallible iterator constructors.
+
+        use crate::FirstErr;
+
+        /// A closure produce `Ok(0)`, `Err(1)`, `None`, `Ok(3)`, ... repeatedly.
+        fn cycle() -> impl FnMut() -> Option<Result<u8, u8>> {
+            let mut curr = 0;
+
+            move || {
+                let tmp = curr;
+                curr += 1;
+
+                match tmp % 3 {
+                    0 => Some(Ok(tmp)),
+                    1 => Some(Err(tmp)),
+                    _ => None,
+                }
+            }
+        }
+
+        #[test]
+        fn _try_from_fn_produce_items() {
+            let mut iter = crate::try_from_fn(cycle());
+
+            assert_eq!(iter.next(), Some(Ok(0)));
+            assert_eq!(iter.next(), Some(Err(1)));
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _try_from_fn_is_not_fused() {
+            let mut iter = crate::try_from_fn(cycle());
+
+            assert_eq!(iter.by_ref().count(), 2);
+            assert_eq!(iter.next(), Some(Ok(3)));
+
+            let mut iter = crate::try_from_fn(cycle()).fuse();
+
+            assert_eq!(iter.by_ref().count(), 2);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _try_from_fn_with_first_err_or_else() {
+            let mut calls = 0;
+            let mut f = cycle();
+
+            let ans = crate::try_from_fn(|| {
+                calls += 1;
+                f()
+            })
+            .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(1));
+            assert_eq!(calls, 2);
+        }
+
+        #[test]
+        fn _try_from_fn_with_first_err_or_else_without_err() {
+            let mut curr = 0;
+
+            let ans = crate::try_from_fn(|| {
+                curr += 1;
+                (curr <= 3).then_some(Ok::<u8, u8>(curr))
+            })
+            .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(6));
+        }
+
+        #[test]
+        fn _try_successors_end_on_ok_none() {
+            let mut iter =
+                crate::try_successors(Some(1_u8), |&n| Ok::<_, u8>((n < 8).then_some(n * 2)));
+
+            assert_eq!(iter.next(), Some(Ok(1)));
+            assert_eq!(iter.next(), Some(Ok(2)));
+            assert_eq!(iter.next(), Some(Ok(4)));
+            assert_eq!(iter.next(), Some(Ok(8)));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _try_successors_end_after_err() {
+            let calls = core::cell::Cell::new(0);
+
+            let mut iter = crate::try_successors(Some(1_u8), |&n| {
+                calls.set(calls.get() + 1);
+                if n < 4 {
+                    Ok(Some(n * 2))
+                } else {
+                    Err(n)
+                }
+            });
+
+            assert_eq!(iter.size_hint(), (1, None));
+            assert_eq!(iter.next(), Some(Ok(1)));
+            assert_eq!(iter.next(), Some(Ok(2)));
+            assert_eq!(iter.next(), Some(Ok(4)));
+            assert_eq!(iter.size_hint(), (1, Some(1)));
+            assert_eq!(iter.next(), Some(Err(4)));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+
+            assert_eq!(calls.get(), 3);
+        }
+
+        #[test]
+        fn _try_successors_without_first() {
+            let mut iter =
+                crate::try_successors(None, |_: &u8| -> Result<Option<u8>, u8> { unreachable!() });
+
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _try_successors_with_first_err_or_else() {
+            let ans =
+                crate::try_successors(
+                    Some(1_u8),
+                    |&n| if n < 4 { Ok(Some(n + 1)) } else { Err(n) },
+                )
+                .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(4));
+
+            let ans = crate::try_successors(Some(1_u8), |&n| Ok::<_, u8>((n < 4).then_some(n + 1)))
+                .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Ok(10));
+        }
+
+        #[test]
+        fn _try_unfold_end_on_ok_none() {
+            let mut iter = crate::try_unfold(0_u8, |n| {
+                *n += 1;
+                Ok::<_, u8>((*n <= 3).then_some(*n))
+            });
+
+            assert_eq!(iter.next(), Some(Ok(1)));
+            assert_eq!(iter.next(), Some(Ok(2)));
+            assert_eq!(iter.next(), Some(Ok(3)));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn _try_unfold_is_fused_after_err() {
+            let calls = core::cell::Cell::new(0);
+
+            // The closure will produce `Ok` values again after the `Err`.
+            let mut iter = crate::try_unfold(0_u8, |n| {
+                calls.set(calls.get() + 1);
+                *n += 1;
+                match *n {
+                    2 => Err(*n),
+                    _ => Ok(Some(*n)),
+                }
+            });
+
+            assert_eq!(iter.next(), Some(Ok(1)));
+            assert_eq!(iter.next(), Some(Err(2)));
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+            assert_eq!(calls.get(), 2);
+        }
+
+        #[test]
+        fn _try_unfold_with_first_err_or_else() {
+            let ans = crate::try_unfold(0_u8, |n| {
+                *n += 1;
+                match *n {
+                    4 => Err(*n),
+                    _ => Ok(Some(*n)),
+                }
+            })
+            .first_err_or_else(|iter| iter.sum::<u8>());
+
+            assert_eq!(ans, Err(4));
+        }
+    }
+
+    mod test_decode {
+        //! Test decoders.
+
+        use crate::Utf8DecodeError;
+
+        /// Check the result against `core::str::from_utf8()`.
+        fn check(bytes: &[u8]) {
+            let ans = crate::decode_utf8_or_else(bytes.iter().copied(), |chars| {
+                // Compare with the valid prefix.
+                let valid_up_to = match core::str::from_utf8(bytes) {
+                    Ok(_) => bytes.len(),
+                    Err(e) => e.valid_up_to(),
+                };
+                let prefix = core::str::from_utf8(&bytes[..valid_up_to]).unwrap();
+
+                chars.eq(prefix.chars())
+            });
+
+            let expected = match core::str::from_utf8(bytes) {
+                Ok(_) => Ok(true),
+                Err(e) => match e.error_len() {
+                    Some(_) => Err(Utf8DecodeError::Invalid {
+                        index: e.valid_up_to(),
+                    }),
+                    None => Err(Utf8DecodeError::Truncated {
+                        index: e.valid_up_to(),
+                    }),
+                },
+            };
+
+            assert_eq!(ans, expected, "bytes: {:x?}", bytes);
+        }
+
+        #[test]
+        fn _decode_utf8_or_else_with_valid_str() {
+            let s = "a¢€𐍈 mixed 中文";
+
+            let ans = crate::decode_utf8_or_else(s.bytes(), |chars| chars.eq(s.chars()));
+
+            assert_eq!(ans, Ok(true));
+        }
+
+        #[test]
+        fn _decode_utf8_or_else_is_same_as_from_utf8_with_1_or_2_bytes() {
+            for a in 0..=u8::MAX {
+                check(&[a]);
+
+                for b in 0..=u8::MAX {
+                    check(&[a, b]);
+                }
+            }
+        }
+
+        #[test]
+        fn _decode_utf8_or_else_is_same_as_from_utf8_with_3_or_4_bytes() {
+            let leads = [
+                0xE0, 0xE1, 0xEC, 0xED, 0xEE, 0xEF, 0xF0, 0xF1, 0xF3, 0xF4, 0xF5,
+            ];
+            let conts = [
+                0x00, 0x41, 0x7F, 0x80, 0x8F, 0x90, 0x9F, 0xA0, 0xBF, 0xC0, 0xFF,
+            ];
+
+            for a in leads {
+                for b in conts {
+                    for c in conts {
+                        check(&[a, b, c]);
+                        check(&[b'x', a, b, c]);
 
-            assert_eq!(ans, Err(2));
+                        for d in conts {
+                            check(&[a, b, c, d]);
+                        }
+                    }
+                }
+            }
         }
 
         #[test]
-        fn _or_try_without_err_and_closure_produce_ok() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
-                .into_iter()
-                .first_err_or_try(|iter| iter.nth(1).ok_or(1));
+        fn _decode_utf8_or_else_with_truncated_end() {
+            let ans = crate::decode_utf8_or_else(*b"ab\xF0\x9F\x98", |chars| chars.count());
 
-            assert_eq!(ans, Ok(1));
+            assert_eq!(ans, Err(Utf8DecodeError::Truncated { index: 2 }));
         }
 
         #[test]
-        fn _or_try_without_err_and_closure_produce_err() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Ok(2), Ok(3), Ok(4)]
+        fn _decode_utf8_or_else_stop_on_first_invalid_byte() {
+            let mut count = 0;
+
+            let bytes = [b'a', 0xC3, b'b', b'c', 0xFF]
                 .into_iter()
-                .first_err_or_try(|iter| iter.nth(100).ok_or(100));
+                .inspect(|_| count += 1);
+            let ans = crate::decode_utf8_or_else(bytes, |chars| chars.count());
 
-            assert_eq!(ans, Err(100));
+            assert_eq!(ans, Err(Utf8DecodeError::Invalid { index: 1 }));
+            assert_eq!(count, 3);
         }
 
         #[test]
-        fn _or_try_with_err_and_closure_produce_ok() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
-                .into_iter()
-                .first_err_or_try(|iter| iter.nth(1).ok_or(1));
+        fn _try_decode_utf8_or_else_with_source_err() {
+            // The source error in the middle of a multi-byte sequence.
+            let bytes = [Ok(b'a'), Ok(0xE2), Ok(0x82), Err("oops"), Ok(0xAC)];
 
-            assert_eq!(ans, Err(2));
+            let ans = crate::try_decode_utf8_or_else(bytes, |chars| chars.count());
+
+            assert_eq!(
+                ans,
+                Err(Utf8DecodeError::Source {
+                    index: 3,
+                    error: "oops"
+                })
+            );
+
+            // The source error at a char boundary.
+            let bytes = [Ok(0xE2), Ok(0x82), Ok(0xAC), Err("oops")];
+
+            let ans = crate::try_decode_utf8_or_else(bytes, |chars| chars.next());
+
+            assert_eq!(
+                ans,
+                Err(Utf8DecodeError::Source {
+                    index: 3,
+                    error: "oops"
+                })
+            );
         }
 
         #[test]
-        fn _or_try_with_err_and_closure_produce_err() {
-            let ans = [Ok::<u8, u8>(0), Ok(1), Err(2), Ok(3), Ok(4)]
-                .into_iter()
-                .first_err_or_try(|iter| iter.nth(100).ok_or(100));
+        fn _try_decode_utf8_or_else_without_err() {
+            let bytes = "€uro".bytes().map(Ok::<u8, u8>);
 
-            assert_eq!(ans, Err(2));
+            let ans = crate::try_decode_utf8_or_else(bytes, |chars| chars.last());
+
+            assert_eq!(ans, Ok(Some('o')));
         }
 
         #[test]
-        fn _methods_can_call_through_trait_object() {
-            let mut array_iter = [Ok::<u8, u8>(0), Err(1), Err(2)].into_iter();
-
-            fn take_dyn(iter: &mut dyn Iterator<Item = Result<u8, u8>>) {
-                iter.first_err_or_else(|iter| iter.sum::<u8>()).ok();
-                iter.first_err_or(0).ok();
-                iter.first_err_or_try(|iter| Ok(iter.sum::<u8>())).ok();
-            }
+        fn _decode_utf8_or_else_iter_stop_after_err() {
+            let ans = crate::decode_utf8_or_else(*b"\xFFa", |chars| {
+                assert_eq!(chars.next(), None);
+                assert_eq!(chars.next(), None);
+            });
 
-            take_dyn(&mut array_iter);
+            assert_eq!(ans, Err(Utf8DecodeError::Invalid { index: 0 }));
         }
     }
 
-    mod test_first_none {
-        //! Test first_none_* methods.
+    #[cfg(feature = "either")]
+    mod test_first_left {
+        //! Test first_left_* and first_right_* methods.
 
         use crate::FirstErr;
+        use either::Either::{self, Left, Right};
 
         #[test]
-        fn _or_else_with_1_layer_data_and_without_none() {
-            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
+        fn _left_or_else_with_1_layer_data_and_without_left() {
+            let ans = [Right::<u8, u8>(0), Right(1), Right(2), Right(3)]
                 .into_iter()
-                .first_none_or_else(|iter1| iter1.sum::<u8>());
+                .first_left_or_else(|iter1| iter1.sum::<u8>());
 
-            assert_eq!(ans, Some(10));
+            assert_eq!(ans, Right(6));
         }
 
         #[test]
-        fn _or_else_with_1_layer_data_and_with_none() {
-            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+        fn _left_or_else_with_1_layer_data_and_with_left() {
+            let ans = [Right::<u8, u8>(0), Left(1), Right(2), Left(3)]
                 .into_iter()
-                .first_none_or_else(|iter1| iter1.sum::<u8>());
+                .first_left_or_else(|iter1| iter1.sum::<u8>());
 
-            assert_eq!(ans, None);
+            assert_eq!(ans, Left(1));
         }
 
         #[test]
-        fn _or_else_with_2_layer_data_and_outmost_none_in_layer_1() {
+        fn _left_or_else_with_2_layer_data() {
             let ans = [
-                Some(Some(0u8)),
-                Some(None),
-                None,
-                Some(Some(3)),
-                Some(Some(4)),
+                Right::<u8, Either<u8, u8>>(Right(0)),
+                Right(Left(1)),
+                Right(Right(2)),
             ]
             .into_iter()
-            .first_none_or_else(|iter1| {
-                iter1
-                    // could chain other ops
-                    .first_none_or_else(|iter2| iter2.sum::<u8>())
-            });
+            .first_left_or_else(|iter1| iter1.first_left_or_else(|iter2| iter2.sum::<u8>()));
 
-            assert_eq!(ans, None);
+            assert_eq!(ans, Right(Left(1)));
         }
 
         #[test]
-        fn _or_else_with_2_layer_data_and_outmost_none_in_layer_2() {
-            let ans = [
-                Some(Some(0u8)),
-                Some(Some(1)),
-                Some(None),
-                Some(Some(3)),
-                Some(Some(4)),
-            ]
-            .into_iter()
-            .first_none_or_else(|iter1| {
-                iter1
-                    // could chain other ops
-                    .first_none_or_else(|iter2| iter2.sum::<u8>())
-            });
+        fn _left_or_else_not_consume_iter_manually() {
+            let ans = [Right::<u8, u8>(0), Right(1), Left(2)]
+                .into_iter()
+                .first_left_or_else(|_| 42);
 
-            assert_eq!(ans, Some(None));
+            assert_eq!(ans, Left(2));
         }
 
         #[test]
-        fn _or_else_with_3_layer_data_and_outmost_none_in_layer_2() {
-            let ans = [
-                Some(Some(Some(0))),
-                Some(Some(Some(1))),
-                Some(Some(None)),
-                Some(None),
-                Some(Some(Some(4))),
-            ]
-            .into_iter()
-            .first_none_or_else(|iter1| {
-                iter1
-                    // could chain other ops
-                    .first_none_or_else(|iter2| {
-                        iter2
-                            // could chain other ops
-                            .first_none_or_else(|iter3| iter3.sum::<u8>())
-                    })
-            });
+        fn _left_or_else_iter_keep_consume_after_closure_stopped() {
+            let mut count = 0;
 
-            assert_eq!(ans, Some(None));
+            let ans = [Right::<u8, u8>(0), Right(1), Right(2), Left(3), Left(4)]
+                .into_iter()
+                .inspect(|_| count += 1)
+                .first_left_or_else(|iter| iter.next());
+
+            assert_eq!(ans, Left(3));
+            assert_eq!(count, 4);
         }
 
         #[test]
-        fn _or_else_not_need_to_consume_iter_manually() {
-            let ans = [Some(0), None, None]
+        fn _right_or_else_with_1_layer_data_and_without_right() {
+            let ans = [Left::<u8, u8>(0), Left(1), Left(2), Left(3)]
                 .into_iter()
-                .first_none_or_else(|_iter| {});
+                .first_right_or_else(|iter1| iter1.sum::<u8>());
 
-            assert_eq!(ans, None);
+            assert_eq!(ans, Left(6));
         }
 
-        /// In most cases, API users should not be concerned about how many times the original
-        /// iterator's `.next()` method is called, as it gets consumed after
-        /// `first_none_or_else()` is called.
-        ///
-        /// However, if the inner iterator has some side-effect, this behavior is still
-        /// observable, and users may rely on it.
-        ///
-        /// This test is designed to ensure that this behavior remains consistent even when
-        /// the code changes.
         #[test]
-        fn _or_else_never_call_next_on_orig_iter_after_first_none_found() {
-            let mut orig_iter_next_count = 0;
-
-            [Some(0), None, None]
+        fn _right_or_else_with_1_layer_data_and_with_right() {
+            let ans = [Left::<u8, u8>(0), Right(1), Left(2), Right(3)]
                 .into_iter()
-                .inspect(|_| orig_iter_next_count += 1) // side-effect
-                .first_none_or_else(|iter| {
-                    // exhaust whole iter.
-                    for _ in &mut *iter {}
+                .first_right_or_else(|iter1| iter1.sum::<u8>());
 
-                    // call iter.next() after the iter already exhausted.
-                    assert_eq!(iter.next(), None);
-                });
+            assert_eq!(ans, Right(1));
+        }
 
-            assert_eq!(orig_iter_next_count, 2);
+        #[test]
+        fn _right_or_else_not_consume_iter_manually() {
+            let ans = [Left::<u8, u8>(0), Left(1), Right(2)]
+                .into_iter()
+                .first_right_or_else(|_| 42);
+
+            assert_eq!(ans, Right(2));
         }
 
         #[test]
-        fn _or_else_use_lazy_evaluation() {
-            use core::cell::{Cell, RefCell};
+        fn _left_or_else_is_same_as_first_err_or_else_through_from_and_into() {
+            let cases: [[Result<u8, u8>; 3]; 4] = [
+                [Ok(0), Ok(1), Ok(2)],
+                [Err(0), Ok(1), Ok(2)],
+                [Ok(0), Err(1), Err(2)],
+                [Ok(0), Ok(1), Err(2)],
+            ];
 
-            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-            enum Trace {
-                None,
-                Outer(Option<u8>),
-                Inner(u8),
-            }
+            for case in cases {
+                let by_either: Result<u8, u8> = case
+                    .into_iter()
+                    .map(Either::from)
+                    .first_left_or_else(|iter| iter.sum::<u8>())
+                    .into();
+                let by_result = case.into_iter().first_err_or_else(|iter| iter.sum::<u8>());
 
-            // if index >= N, it will panic.
-            fn record_trace<const N: usize>(
-                traces: &RefCell<[Trace; N]>,
-                idx: &Cell<usize>,
-                v: Trace,
-            ) {
-                let i = idx.get();
-                traces.borrow_mut()[i] = v;
-                idx.set(i + 1);
+                assert_eq!(by_either, by_result);
             }
+        }
 
-            // already known N = 5 within [_; N] in this test case.
-            // We don't use Vec here just bacause want to avoid `alloc` crate.
-            let traces = RefCell::new([Trace::None; 5]);
-
-            let index = Cell::new(0);
+        #[test]
+        fn _right_or_else_is_same_as_first_err_or_else_through_flip() {
+            let cases: [[Result<u8, u8>; 3]; 4] = [
+                [Ok(0), Ok(1), Ok(2)],
+                [Err(0), Ok(1), Ok(2)],
+                [Ok(0), Err(1), Err(2)],
+                [Ok(0), Ok(1), Err(2)],
+            ];
 
-            let ans = [Some(0u8), Some(1), None, Some(3)]
-                .iter()
-                .cloned()
-                // record value from outer
-                .inspect(|&opt| record_trace(&traces, &index, Trace::Outer(opt)))
-                .first_none_or_else(|iter| {
-                    iter
-                        // record value from inner
-                        .inspect(|&n| record_trace(&traces, &index, Trace::Inner(n)))
-                        .sum::<u8>()
-                });
+            for case in cases {
+                let by_either: Result<u8, u8> = case
+                    .into_iter()
+                    .map(|res| Either::from(res).flip())
+                    .first_right_or_else(|iter| iter.sum::<u8>())
+                    .flip()
+                    .into();
+                let by_result = case.into_iter().first_err_or_else(|iter| iter.sum::<u8>());
 
-            assert_eq!(ans, None);
-            assert_eq!(
-                traces.into_inner(),
-                [
-                    Trace::Outer(Some(0)),
-                    Trace::Inner(0),
-                    Trace::Outer(Some(1)),
-                    Trace::Inner(1),
-                    Trace::Outer(None)
-                ]
-            );
+                assert_eq!(by_either, by_result);
+            }
         }
 
         #[test]
-        fn _or_else_with_non_fused_iterator() {
-            struct NonFusedIter {
-                curr: u32,
-            }
+        fn _methods_can_call_through_trait_object() {
+            let mut array_iter = [Right::<u8, u8>(0), Left(1), Left(2)].into_iter();
 
-            impl NonFusedIter {
-                fn new() -> Self {
-                    Self { curr: 0 }
-                }
+            fn take_dyn(iter: &mut dyn Iterator<Item = Either<u8, u8>>) {
+                let _ = iter.first_left_or_else(|iter| iter.sum::<u8>());
+                let _ = iter.first_right_or_else(|iter| iter.sum::<u8>());
             }
 
-            impl Iterator for NonFusedIter {
-                type Item = Option<u32>;
+            take_dyn(&mut array_iter);
+        }
+    }
 
-                fn next(&mut self) -> Option<Self::Item> {
-                    let tmp = self.curr;
-                    self.curr += 1;
+    #[cfg(feature = "nightly-try")]
+    mod test_first_residual {
+        //! Test first_residual_* methods.
 
-                    match tmp % 3 {
-                        0 => Some(Some(tmp)),
-                        1 => None,       // after produce a None ...
-                        2 => Some(None), // it still can produce Some(value)
-                        _ => unreachable!(),
-                    }
-                }
-            }
+        use crate::FirstErr;
+        use core::{
+            ops::ControlFlow::{self, Break, Continue},
+            task::Poll,
+        };
 
-            let ans = NonFusedIter::new().first_none_or_else(|iter| iter.sum::<u32>());
+        #[test]
+        fn _or_else_with_result_is_same_as_first_err_or_else() {
+            let cases: [[Result<u8, u8>; 3]; 4] = [
+                [Ok(0), Ok(1), Ok(2)],
+                [Err(0), Ok(1), Ok(2)],
+                [Ok(0), Err(1), Err(2)],
+                [Ok(0), Ok(1), Err(2)],
+            ];
 
-            assert_eq!(ans, Some(0));
+            for case in cases {
+                assert_eq!(
+                    case.into_iter()
+                        .first_residual_or_else(|iter| iter.sum::<u8>()),
+                    case.into_iter().first_err_or_else(|iter| iter.sum::<u8>()),
+                );
+            }
         }
 
         #[test]
-        fn _or_without_none() {
-            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
-                .into_iter()
-                .first_none_or("no none");
+        fn _or_else_with_option_is_same_as_first_none_or_else() {
+            let cases: [[Option<u8>; 3]; 4] = [
+                [Some(0), Some(1), Some(2)],
+                [None, Some(1), Some(2)],
+                [Some(0), None, None],
+                [Some(0), Some(1), None],
+            ];
 
-            assert_eq!(ans, Some("no none"));
+            for case in cases {
+                assert_eq!(
+                    case.into_iter()
+                        .first_residual_or_else(|iter| iter.sum::<u8>()),
+                    case.into_iter().first_none_or_else(|iter| iter.sum::<u8>()),
+                );
+            }
         }
 
         #[test]
-        fn _or_with_none() {
-            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
-                .into_iter()
-                .first_none_or("no none");
+        fn _or_else_with_control_flow_is_same_as_first_break_or_else() {
+            let cases: [[ControlFlow<u8, u8>; 3]; 4] = [
+                [Continue(0), Continue(1), Continue(2)],
+                [Break(0), Continue(1), Continue(2)],
+                [Continue(0), Break(1), Break(2)],
+                [Continue(0), Continue(1), Break(2)],
+            ];
 
-            assert_eq!(ans, None);
+            for case in cases {
+                assert_eq!(
+                    case.into_iter()
+                        .first_residual_or_else(|iter| iter.sum::<u8>()),
+                    case.into_iter()
+                        .first_break_or_else(|iter| iter.sum::<u8>()),
+                );
+            }
         }
 
         #[test]
-        fn _or_try_without_none_and_closure_produce_some() {
-            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
-                .into_iter()
-                .first_none_or_try(|iter| iter.nth(1));
+        fn _or_else_with_poll_result() {
+            // The residual of `Poll<Result<T, E>>` is `Result<!, E>`, so the items of the
+            // argument iterator are `Poll<T>`, and the result is `Result<O, E>`.
+            let ans = [
+                Poll::Ready(Ok::<u8, u8>(0)),
+                Poll::Pending,
+                Poll::Ready(Err(2)),
+            ]
+            .into_iter()
+            .first_residual_or_else(|iter| iter.filter(Poll::is_ready).count());
+            assert_eq!(ans, Err(2));
 
-            assert_eq!(ans, Some(1));
+            let ans = [
+                Poll::Ready(Ok::<u8, u8>(0)),
+                Poll::Pending,
+                Poll::Ready(Ok(2)),
+            ]
+            .into_iter()
+            .first_residual_or_else(|iter| iter.filter(Poll::is_ready).count());
+            assert_eq!(ans, Ok(2));
         }
 
         #[test]
-        fn _or_try_without_none_and_closure_produce_none() {
-            let ans = [Some(0u8), Some(1), Some(2), Some(3), Some(4)]
+        fn _or_else_with_2_layer_data() {
+            let ans = [Ok::<Option<u8>, u8>(Some(0)), Ok(None), Err(2)]
                 .into_iter()
-                .first_none_or_try(|iter| iter.nth(100));
+                .first_residual_or_else(|iter1| {
+                    iter1.first_residual_or_else(|iter2| iter2.sum::<u8>())
+                });
 
-            assert_eq!(ans, None);
+            assert_eq!(ans, Err(2));
         }
 
         #[test]
-        fn _or_try_with_none_and_closure_produce_some() {
-            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+        fn _or_else_not_consume_iter_manually() {
+            let ans = [Some::<u8>(0), None, Some(2)]
                 .into_iter()
-                .first_none_or_try(|iter| iter.nth(1));
+                .first_residual_or_else(|_| 42);
 
             assert_eq!(ans, None);
         }
 
         #[test]
-        fn _or_try_with_none_and_closure_produce_none() {
-            let ans = [Some(0u8), Some(1), None, Some(3), Some(4)]
+        fn _or_else_iter_stop_on_first_residual() {
+            let mut count = 0;
+
+            let ans = [Ok::<u8, u8>(0), Err(1), Ok(2), Err(3)]
                 .into_iter()
-                .first_none_or_try(|iter| iter.nth(100));
+                .inspect(|_| count += 1)
+                .first_residual_or_else(|iter| iter.count());
 
-            assert_eq!(ans, None);
+            assert_eq!(ans, Err(1));
+            assert_eq!(count, 2);
         }
 
         #[test]
         fn _methods_can_call_through_trait_object() {
-            let mut array_iter = [Some(0u8), None, None].into_iter();
+            let mut array_iter = [Ok::<u8, u8>(0), Err(1), Err(2)].into_iter();
 
-            fn take_dyn(iter: &mut dyn Iterator<Item = Option<u8>>) {
-                iter.first_none_or_else(|iter| iter.sum::<u8>());
-                iter.first_none_or(0);
-                iter.first_none_or_try(|iter| Some(iter.sum::<u8>()));
+            fn take_dyn(iter: &mut dyn Iterator<Item = Result<u8, u8>>) {
+                let _ = iter.first_residual_or_else(|iter| iter.sum::<u8>());
             }
 
             take_dyn(&mut array_iter);